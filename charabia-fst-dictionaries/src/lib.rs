@@ -0,0 +1,11 @@
+//! Word dictionaries encoded as FSTs and used by charabia to segment the scripts that don't separate words.
+//!
+//! Each dictionary is embedded only if its feature is enabled.
+
+/// Thai words, the dictionary source is the PyThaiNLP project on https://github.com/PyThaiNLP/nlpo3.
+#[cfg(feature = "thai")]
+pub static THAI_WORDS_FST: &[u8] = include_bytes!("../dictionaries/thai/words.fst");
+
+/// Khmer words, the dictionary source is https://github.com/unicode-org/icu/blob/main/icu4c/source/data/brkitr/dictionaries/khmerdict.txt.
+#[cfg(feature = "khmer")]
+pub static KHMER_WORDS_FST: &[u8] = include_bytes!("../dictionaries/khmer/words.fst");
@@ -0,0 +1,139 @@
+//! Python bindings of charabia, exposing a `tokenize(text, options)` function
+//! that returns the tokens as dicts with their offsets.
+
+use charabia::{AllowList, Language, SeparatorKind, Token, TokenKind, TokenizerBuilder};
+use fst::Set;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+/// Options of [`tokenize`], read from the Python dict.
+struct Options {
+    stop_words: Option<Vec<String>>,
+    separators: Option<Vec<String>>,
+    words_dict: Option<Vec<String>>,
+    locales: Option<Vec<String>>,
+    lossy: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        // the lossy normalization is enabled by default, like in Meilisearch.
+        Self { stop_words: None, separators: None, words_dict: None, locales: None, lossy: true }
+    }
+}
+
+impl Options {
+    fn extract(options: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let mut extracted = Self::default();
+        for (key, value) in options {
+            match key.extract::<&str>()? {
+                "stop_words" => extracted.stop_words = Some(value.extract()?),
+                "separators" => extracted.separators = Some(value.extract()?),
+                "words_dict" => extracted.words_dict = Some(value.extract()?),
+                "locales" => extracted.locales = Some(value.extract()?),
+                "lossy" => extracted.lossy = value.extract()?,
+                key => return Err(PyValueError::new_err(format!("unknown option `{key}`"))),
+            }
+        }
+
+        Ok(extracted)
+    }
+}
+
+fn kind_name(kind: TokenKind) -> &'static str {
+    match kind {
+        TokenKind::Word => "word",
+        TokenKind::StopWord => "stop_word",
+        TokenKind::Separator(SeparatorKind::Soft) => "soft_separator",
+        TokenKind::Separator(SeparatorKind::Hard) => "hard_separator",
+        TokenKind::DateTime => "date_time",
+        TokenKind::Currency => "currency",
+        TokenKind::Unit => "unit",
+        TokenKind::Emoji => "emoji",
+        TokenKind::Hashtag => "hashtag",
+        TokenKind::Mention => "mention",
+        TokenKind::Unknown => "unknown",
+    }
+}
+
+fn token_dict<'py>(py: Python<'py>, token: &Token) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("kind", kind_name(token.kind))?;
+    dict.set_item("lemma", token.lemma())?;
+    dict.set_item("char_start", token.char_start)?;
+    dict.set_item("char_end", token.char_end)?;
+    dict.set_item("byte_start", token.byte_start)?;
+    dict.set_item("byte_end", token.byte_end)?;
+    dict.set_item("script", token.script.name())?;
+    dict.set_item("language", token.language.map(|language| language.name()))?;
+    Ok(dict)
+}
+
+/// Tokenizes the text like Meilisearch, returning a list of token dicts.
+///
+/// The `char_start` and `char_end` offsets of the tokens index the Python string,
+/// the `byte_start` and `byte_end` offsets index its UTF-8 encoding.
+#[pyfunction]
+#[pyo3(signature = (text, options = None))]
+fn tokenize<'py>(
+    py: Python<'py>,
+    text: &str,
+    options: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Bound<'py, PyList>> {
+    let options = options.map(Options::extract).transpose()?.unwrap_or_default();
+
+    let stop_words = match options.stop_words {
+        Some(mut stop_words) => {
+            stop_words.sort_unstable();
+            stop_words.dedup();
+            Some(Set::from_iter(stop_words).map_err(|e| PyValueError::new_err(e.to_string()))?)
+        }
+        None => None,
+    };
+    let separators: Option<Vec<_>> =
+        options.separators.as_ref().map(|s| s.iter().map(String::as_str).collect());
+    let words_dict: Option<Vec<_>> =
+        options.words_dict.as_ref().map(|w| w.iter().map(String::as_str).collect());
+    let allow_list = match &options.locales {
+        Some(locales) => {
+            let languages = locales
+                .iter()
+                .map(|locale| locale.parse::<Language>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            Some(AllowList::new().languages(languages))
+        }
+        None => None,
+    };
+
+    let mut builder = TokenizerBuilder::new();
+    if let Some(stop_words) = &stop_words {
+        builder.stop_words(stop_words);
+    }
+    if let Some(separators) = &separators {
+        builder.separators(separators);
+    }
+    if let Some(words_dict) = &words_dict {
+        builder.words_dict(words_dict);
+    }
+    if let Some(allow_list) = &allow_list {
+        builder.allow_list(allow_list);
+    }
+    builder.lossy_normalization(options.lossy);
+    let tokenizer = builder.build();
+
+    let tokens = PyList::empty(py);
+    for token in tokenizer.tokenize(text) {
+        tokens.append(token_dict(py, &token)?)?;
+    }
+    Ok(tokens)
+}
+
+/// Python module of charabia, the Meilisearch tokenizer.
+#[pymodule]
+#[pyo3(name = "charabia")]
+fn charabia_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(tokenize, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,447 @@
+//! Compact binary encoding of the tokens, to cache the tokenization of the documents between indexing runs.
+//!
+//! The encoding keeps everything the tokenizer computes, the offsets and the `char_map` included,
+//! and is stamped with the [`Tokenizer::fingerprint`](crate::Tokenizer::fingerprint) of the tokenizer that produced the tokens,
+//! so a cache built with another configuration, version or set of features is refused instead of silently reused.
+//! The scripts and languages are stored by their names, so the encoding doesn't depend on the order of the enums.
+//!
+//! # Example
+//!
+//! ```
+//! use charabia::TokenizerBuilder;
+//!
+//! let mut builder = TokenizerBuilder::default();
+//! builder.create_char_map(true);
+//! let tokenizer = builder.build();
+//!
+//! let tokens: Vec<_> = tokenizer.tokenize("Thé quick fox").collect();
+//! let bytes = tokenizer.encode_tokens(&tokens);
+//! assert_eq!(tokenizer.decode_tokens(&bytes), Ok(tokens));
+//!
+//! // the tokens cached with another configuration are refused.
+//! let other = TokenizerBuilder::default().build();
+//! assert!(other.decode_tokens(&bytes).is_err());
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{Boundary, Language, Script, SeparatorKind, Token, TokenForms, TokenKind};
+
+const MAGIC: &[u8; 4] = b"CHRB";
+/// Version of the encoding, incremented on every change of the layout.
+const VERSION: u8 = 1;
+/// Length of the magic number, the version and the fingerprint preceding the tokens.
+const HEADER_LEN: usize = MAGIC.len() + 1 + 8;
+
+// flags of the optional fields of a token.
+const CHAR_MAP: u16 = 1 << 0;
+const LANGUAGE: u16 = 1 << 1;
+const IS_PREFIX: u16 = 1 << 2;
+const PHRASE: u16 = 1 << 3;
+const SKELETON: u16 = 1 << 4;
+const ROMANIZED: u16 = 1 << 5;
+const NUMERIC: u16 = 1 << 6;
+const SYNONYM: u16 = 1 << 7;
+const BOUNDARY: u16 = 1 << 8;
+const ORIGINAL: u16 = 1 << 9;
+const GUESSED: u16 = 1 << 10;
+const COST: u16 = 1 << 11;
+const PHONETIC_KEYS: u16 = 1 << 12;
+
+/// Error returned by [`decode`] when the bytes can't be decoded into tokens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// the bytes don't start with the magic number of the encoding.
+    InvalidMagic,
+    /// the bytes have been encoded with another version of the encoding.
+    UnsupportedVersion(u8),
+    /// the tokens have been produced by a tokenizer with another configuration.
+    FingerprintMismatch { expected: u64, found: u64 },
+    /// the bytes end in the middle of a token.
+    UnexpectedEnd,
+    /// a lemma or another text of a token is not valid UTF-8.
+    InvalidUtf8,
+    /// an integer is longer than the integers of the platform.
+    IntegerOverflow,
+    /// a tag doesn't correspond to any value of the field.
+    InvalidTag { field: &'static str, tag: u8 },
+    /// the flags of a token don't fit in the flags of the encoding.
+    InvalidFlags(usize),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "the bytes are not encoded tokens"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "encoding version {version} is not supported, expected {VERSION}")
+            }
+            Self::FingerprintMismatch { expected, found } => write!(
+                f,
+                "tokens produced by the tokenizer {found:#018x}, expected {expected:#018x}"
+            ),
+            Self::UnexpectedEnd => write!(f, "the encoded tokens are truncated"),
+            Self::InvalidUtf8 => write!(f, "an encoded text is not valid UTF-8"),
+            Self::IntegerOverflow => write!(f, "an encoded integer overflows"),
+            Self::InvalidTag { field, tag } => write!(f, "tag {tag} is invalid for the {field}"),
+            Self::InvalidFlags(flags) => write!(f, "flags {flags:#x} are invalid for a token"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Encodes the tokens produced by the tokenizer of the provided fingerprint.
+pub fn encode(tokens: &[Token], fingerprint: u64) -> Vec<u8> {
+    let mut encoder = Encoder(Vec::with_capacity(HEADER_LEN + tokens.len() * 16));
+    encoder.0.extend_from_slice(MAGIC);
+    encoder.0.push(VERSION);
+    encoder.0.extend_from_slice(&fingerprint.to_le_bytes());
+    encoder.write_usize(tokens.len());
+    for token in tokens {
+        encoder.write_token(token);
+    }
+    encoder.0
+}
+
+/// Decodes the tokens encoded by [`encode`],
+/// failing if they have not been produced by the tokenizer of the provided fingerprint.
+pub fn decode(bytes: &[u8], fingerprint: u64) -> Result<Vec<Token<'static>>, DecodeError> {
+    let found = self::fingerprint(bytes)?;
+    if found != fingerprint {
+        return Err(DecodeError::FingerprintMismatch { expected: fingerprint, found });
+    }
+
+    let mut decoder = Decoder(&bytes[HEADER_LEN..]);
+    let len = decoder.read_usize()?;
+    // the capacity is bounded by the remaining bytes to not trust a corrupted length.
+    let mut tokens = Vec::with_capacity(len.min(decoder.0.len()));
+    for _ in 0..len {
+        tokens.push(decoder.read_token()?);
+    }
+    Ok(tokens)
+}
+
+/// Returns the fingerprint of the tokenizer which produced the encoded tokens, without decoding them.
+pub fn fingerprint(bytes: &[u8]) -> Result<u64, DecodeError> {
+    let mut decoder = Decoder(bytes);
+    if decoder.read_bytes(MAGIC.len()).map_or(true, |magic| magic != MAGIC) {
+        return Err(DecodeError::InvalidMagic);
+    }
+    match decoder.read_u8()? {
+        VERSION => (),
+        version => return Err(DecodeError::UnsupportedVersion(version)),
+    }
+    let fingerprint = decoder.read_bytes(8)?;
+    Ok(u64::from_le_bytes(fingerprint.try_into().unwrap()))
+}
+
+struct Encoder(Vec<u8>);
+
+impl Encoder {
+    /// Writes the integer as a LEB128 varint, the offsets and the lengths being mostly small.
+    fn write_usize(&mut self, mut n: usize) {
+        while n >= 0x80 {
+            self.0.push(n as u8 | 0x80);
+            n >>= 7;
+        }
+        self.0.push(n as u8);
+    }
+
+    fn write_str(&mut self, s: &str) {
+        self.write_usize(s.len());
+        self.0.extend_from_slice(s.as_bytes());
+    }
+
+    fn write_token(&mut self, token: &Token) {
+        let flags = [
+            (CHAR_MAP, token.char_map.is_some()),
+            (LANGUAGE, token.language.is_some()),
+            (IS_PREFIX, token.is_prefix),
+            (PHRASE, token.phrase.is_some()),
+            (SKELETON, token.skeleton().is_some()),
+            (ROMANIZED, token.romanized().is_some()),
+            (NUMERIC, token.numeric().is_some()),
+            (SYNONYM, token.synonym.is_some()),
+            (BOUNDARY, token.boundary.is_some()),
+            (ORIGINAL, token.original.is_some()),
+            (GUESSED, token.guessed),
+            (COST, token.cost.is_some()),
+            (PHONETIC_KEYS, !token.phonetic_keys().is_empty()),
+        ];
+        let flags = flags.iter().filter(|(_, set)| *set).fold(0, |flags, (flag, _)| flags | flag);
+        self.write_usize(flags as usize);
+
+        self.0.push(match token.kind {
+            TokenKind::Word => 0,
+            TokenKind::StopWord => 1,
+            TokenKind::Separator(SeparatorKind::Hard) => 2,
+            TokenKind::Separator(SeparatorKind::Soft) => 3,
+            TokenKind::DateTime => 4,
+            TokenKind::Currency => 5,
+            TokenKind::Unit => 6,
+            TokenKind::Emoji => 7,
+            TokenKind::Hashtag => 8,
+            TokenKind::Mention => 9,
+            TokenKind::Unknown => 10,
+        });
+        self.write_str(token.lemma());
+        // the ends are encoded relatively to the starts to keep them small.
+        self.write_usize(token.char_start);
+        self.write_usize(token.char_end - token.char_start);
+        self.write_usize(token.byte_start);
+        self.write_usize(token.byte_end - token.byte_start);
+        self.write_str(token.script.name());
+
+        if let Some(char_map) = &token.char_map {
+            self.write_usize(char_map.len());
+            self.0
+                .extend(char_map.iter().flat_map(|&(original, normalized)| [original, normalized]));
+        }
+        if let Some(language) = token.language {
+            self.write_str(language.name());
+        }
+        if let Some(phrase) = token.phrase {
+            self.write_usize(phrase);
+        }
+        for s in [token.skeleton(), token.romanized(), token.numeric()].into_iter().flatten() {
+            self.write_str(s);
+        }
+        if let Some((index, len)) = token.synonym {
+            self.write_usize(index);
+            self.write_usize(len);
+        }
+        if let Some(boundary) = token.boundary {
+            self.0.push(match boundary {
+                Boundary::Sentence => 0,
+                Boundary::Paragraph => 1,
+            });
+        }
+        if let Some(original) = &token.original {
+            self.write_str(original);
+        }
+        if let Some(cost) = token.cost {
+            self.0.extend_from_slice(&cost.to_le_bytes());
+        }
+        if !token.phonetic_keys().is_empty() {
+            self.write_usize(token.phonetic_keys().len());
+            for key in token.phonetic_keys() {
+                self.write_str(key);
+            }
+        }
+    }
+}
+
+struct Decoder<'b>(&'b [u8]);
+
+impl<'b> Decoder<'b> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'b [u8], DecodeError> {
+        if self.0.len() < len {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        self.read_bytes(1).map(|bytes| bytes[0])
+    }
+
+    fn read_usize(&mut self) -> Result<usize, DecodeError> {
+        let mut n = 0;
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = self.read_u8()?;
+            n |= ((byte & 0x7f) as usize) << shift;
+            if byte < 0x80 {
+                return Ok(n);
+            }
+        }
+        Err(DecodeError::IntegerOverflow)
+    }
+
+    fn read_str(&mut self) -> Result<&'b str, DecodeError> {
+        let len = self.read_usize()?;
+        std::str::from_utf8(self.read_bytes(len)?).map_err(|_| DecodeError::InvalidUtf8)
+    }
+
+    fn read_string(&mut self) -> Result<String, DecodeError> {
+        self.read_str().map(str::to_string)
+    }
+
+    fn read_token(&mut self) -> Result<Token<'static>, DecodeError> {
+        let flags = self.read_usize()?;
+        let flags = u16::try_from(flags).map_err(|_| DecodeError::InvalidFlags(flags))?;
+        let has = |flag| flags & flag != 0;
+
+        let kind = match self.read_u8()? {
+            0 => TokenKind::Word,
+            1 => TokenKind::StopWord,
+            2 => TokenKind::Separator(SeparatorKind::Hard),
+            3 => TokenKind::Separator(SeparatorKind::Soft),
+            4 => TokenKind::DateTime,
+            5 => TokenKind::Currency,
+            6 => TokenKind::Unit,
+            7 => TokenKind::Emoji,
+            8 => TokenKind::Hashtag,
+            9 => TokenKind::Mention,
+            10 => TokenKind::Unknown,
+            tag => return Err(DecodeError::InvalidTag { field: "kind", tag }),
+        };
+        let lemma = Cow::Owned(self.read_string()?);
+        let char_start = self.read_usize()?;
+        let char_end =
+            char_start.checked_add(self.read_usize()?).ok_or(DecodeError::IntegerOverflow)?;
+        let byte_start = self.read_usize()?;
+        let byte_end =
+            byte_start.checked_add(self.read_usize()?).ok_or(DecodeError::IntegerOverflow)?;
+        let script = Script::from_name(self.read_str()?);
+
+        let char_map = match has(CHAR_MAP) {
+            true => {
+                let len = self.read_usize()?;
+                let bytes =
+                    self.read_bytes(len.checked_mul(2).ok_or(DecodeError::UnexpectedEnd)?)?;
+                Some(bytes.chunks_exact(2).map(|pair| (pair[0], pair[1])).collect())
+            }
+            false => None,
+        };
+        let language =
+            has(LANGUAGE).then(|| self.read_str().map(Language::from_name)).transpose()?;
+        let phrase = has(PHRASE).then(|| self.read_usize()).transpose()?;
+        let skeleton = has(SKELETON).then(|| self.read_string()).transpose()?;
+        let romanized = has(ROMANIZED).then(|| self.read_string()).transpose()?;
+        let numeric = has(NUMERIC).then(|| self.read_string()).transpose()?;
+        let synonym = match has(SYNONYM) {
+            true => Some((self.read_usize()?, self.read_usize()?)),
+            false => None,
+        };
+        let boundary = match has(BOUNDARY) {
+            true => match self.read_u8()? {
+                0 => Some(Boundary::Sentence),
+                1 => Some(Boundary::Paragraph),
+                tag => return Err(DecodeError::InvalidTag { field: "boundary", tag }),
+            },
+            false => None,
+        };
+        let original = has(ORIGINAL).then(|| self.read_string().map(Cow::Owned)).transpose()?;
+        let cost = has(COST)
+            .then(|| self.read_bytes(4).map(|bytes| i32::from_le_bytes(bytes.try_into().unwrap())))
+            .transpose()?;
+        let phonetic_keys = match has(PHONETIC_KEYS) {
+            true => {
+                (0..self.read_usize()?).map(|_| self.read_string()).collect::<Result<_, _>>()?
+            }
+            false => Vec::new(),
+        };
+
+        Ok(Token {
+            kind,
+            lemma,
+            char_start,
+            char_end,
+            byte_start,
+            byte_end,
+            char_map,
+            script,
+            language,
+            is_prefix: has(IS_PREFIX),
+            guessed: has(GUESSED),
+            cost,
+            phrase,
+            forms: (skeleton.is_some()
+                || romanized.is_some()
+                || numeric.is_some()
+                || !phonetic_keys.is_empty())
+            .then(|| Box::new(TokenForms { skeleton, romanized, numeric, phonetic_keys })),
+            synonym,
+            boundary,
+            original,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use super::{decode, encode, fingerprint, DecodeError, Encoder, HEADER_LEN};
+    use crate::{Boundary, Language, Script, Token, TokenForms, TokenKind, TokenizerBuilder};
+
+    #[test]
+    fn round_trip() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true).keep_original(true).romanize(true).skeleton(true);
+        let tokenizer = builder.build();
+
+        let text = "Thé quick (\"brown\") fox, Москва 42! Ελλάδα";
+        let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+        let bytes = encode(&tokens, 42);
+        assert_eq!(fingerprint(&bytes), Ok(42));
+        assert_eq!(decode(&bytes, 42), Ok(tokens));
+
+        // the fields only set by the filters and the query modes.
+        let token = Token {
+            kind: TokenKind::Separator(crate::SeparatorKind::Hard),
+            lemma: Cow::Borrowed(". "),
+            char_start: 300,
+            char_end: 302,
+            byte_start: 1000,
+            byte_end: 1002,
+            script: Script::Latin,
+            language: Some(Language::Ckb),
+            is_prefix: true,
+            guessed: true,
+            cost: Some(-1200),
+            phrase: Some(3),
+            forms: Some(Box::new(TokenForms {
+                numeric: Some("20".to_string()),
+                phonetic_keys: vec!["XMT".to_string(), "SMT".to_string()],
+                ..Default::default()
+            })),
+            synonym: Some((1, 2)),
+            boundary: Some(Boundary::Paragraph),
+            ..Default::default()
+        };
+        let bytes = encode(std::slice::from_ref(&token), 42);
+        assert_eq!(decode(&bytes, 42), Ok(vec![token]));
+    }
+
+    #[test]
+    fn invalid() {
+        let bytes = encode(&[Token::default()], 42);
+        assert_eq!(
+            decode(&bytes, 7),
+            Err(DecodeError::FingerprintMismatch { expected: 7, found: 42 })
+        );
+        assert_eq!(decode(&bytes[..bytes.len() - 1], 42), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode(b"nope", 42), Err(DecodeError::InvalidMagic));
+
+        let mut bytes = bytes;
+        bytes[4] = 0;
+        assert_eq!(decode(&bytes, 42), Err(DecodeError::UnsupportedVersion(0)));
+    }
+
+    #[test]
+    fn corrupted() {
+        // encodes a single token starting with the provided flags and offsets.
+        let token = |flags: usize, char_start: usize, char_len: usize| {
+            let mut encoder = Encoder(encode(&[], 42)[..HEADER_LEN].to_vec());
+            encoder.write_usize(1);
+            encoder.write_usize(flags);
+            encoder.0.push(0);
+            encoder.write_str("");
+            encoder.write_usize(char_start);
+            encoder.write_usize(char_len);
+            encoder.write_usize(0);
+            encoder.write_usize(0);
+            encoder.write_str(Script::Latin.name());
+            encoder.0
+        };
+
+        assert!(decode(&token(0, 1, 1), 42).is_ok());
+        assert_eq!(decode(&token(0, usize::MAX, 1), 42), Err(DecodeError::IntegerOverflow));
+        assert_eq!(decode(&token(1 << 16, 0, 0), 42), Err(DecodeError::InvalidFlags(1 << 16)));
+    }
+}
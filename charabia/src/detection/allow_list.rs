@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use super::{Language, Script};
+
+/// Builder of the allowed [`Language`]s for each [`Script`], inferring the scripts from the languages.
+///
+/// An `AllowList` dereferences to the `HashMap<Script, Vec<Language>>` expected by [`TokenizerBuilder::allow_list`].
+///
+/// [`TokenizerBuilder::allow_list`]: crate::TokenizerBuilder::allow_list
+///
+/// # Example
+///
+/// ```
+/// use charabia::{AllowList, Language, Script, TokenizerBuilder};
+///
+/// let allow_list = AllowList::new().languages([Language::Jpn, Language::Fra]);
+/// assert_eq!(allow_list.get(&Script::Cj), Some(&vec![Language::Jpn]));
+/// assert_eq!(allow_list.get(&Script::Latin), Some(&vec![Language::Fra]));
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.allow_list(&allow_list);
+/// let tokenizer = builder.build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllowList {
+    inner: HashMap<Script, Vec<Language>>,
+}
+
+impl AllowList {
+    /// Creates an empty `AllowList`, where no `Script` is restricted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows the provided languages for each of the scripts they are written in.
+    pub fn languages(mut self, languages: impl IntoIterator<Item = Language>) -> Self {
+        for language in languages {
+            for script in language.scripts() {
+                let allowed = self.inner.entry(*script).or_default();
+                if !allowed.contains(&language) {
+                    allowed.push(language);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Denies the provided languages for each of the scripts they are written in.
+    ///
+    /// If a `Script` was not restricted yet, all the other languages written in this `Script` stay allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{AllowList, Language, Script};
+    ///
+    /// let allow_list = AllowList::new().deny_languages([Language::Cmn]);
+    /// assert_eq!(allow_list.get(&Script::Cj), Some(&vec![Language::Jpn]));
+    /// ```
+    pub fn deny_languages(mut self, languages: impl IntoIterator<Item = Language>) -> Self {
+        for language in languages {
+            for script in language.scripts() {
+                self.inner
+                    .entry(*script)
+                    .or_insert_with(|| {
+                        Language::all()
+                            .iter()
+                            .filter(|l| l.scripts().contains(script))
+                            .copied()
+                            .collect()
+                    })
+                    .retain(|l| *l != language);
+            }
+        }
+
+        self
+    }
+
+    /// Returns the inner map of the allowed languages for each script.
+    pub fn into_inner(self) -> HashMap<Script, Vec<Language>> {
+        self.inner
+    }
+}
+
+impl Deref for AllowList {
+    type Target = HashMap<Script, Vec<Language>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl From<AllowList> for HashMap<Script, Vec<Language>> {
+    fn from(allow_list: AllowList) -> Self {
+        allow_list.into_inner()
+    }
+}
+
+impl FromIterator<Language> for AllowList {
+    fn from_iter<I: IntoIterator<Item = Language>>(languages: I) -> Self {
+        AllowList::new().languages(languages)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn languages_infer_scripts() {
+        let allow_list = AllowList::new().languages([Language::Jpn, Language::Fra, Language::Eng]);
+        assert_eq!(allow_list.len(), 2);
+        assert_eq!(allow_list[&Script::Cj], [Language::Jpn]);
+        assert_eq!(allow_list[&Script::Latin], [Language::Fra, Language::Eng]);
+    }
+
+    #[test]
+    fn multi_script_languages() {
+        let allow_list = AllowList::new().languages([Language::Srp, Language::Rus, Language::Srp]);
+        assert_eq!(allow_list[&Script::Cyrillic], [Language::Srp, Language::Rus]);
+        assert_eq!(allow_list[&Script::Latin], [Language::Srp]);
+    }
+
+    #[test]
+    fn deny_languages() {
+        let allow_list = AllowList::new().deny_languages([Language::Srp]);
+        assert!(!allow_list[&Script::Latin].contains(&Language::Srp));
+        assert!(allow_list[&Script::Latin].contains(&Language::Hrv));
+        assert!(!allow_list[&Script::Cyrillic].contains(&Language::Srp));
+        assert!(allow_list[&Script::Cyrillic].contains(&Language::Rus));
+
+        let allow_list = AllowList::new()
+            .languages([Language::Eng, Language::Fra])
+            .deny_languages([Language::Fra]);
+        assert_eq!(allow_list[&Script::Latin], [Language::Eng]);
+    }
+
+    #[test]
+    fn collect_languages() {
+        let allow_list: AllowList = [Language::Kor, Language::Cmn].into_iter().collect();
+        let allow_list: HashMap<_, _> = allow_list.into();
+        assert_eq!(allow_list[&Script::Hangul], [Language::Kor]);
+        assert_eq!(allow_list[&Script::Cj], [Language::Cmn]);
+    }
+}
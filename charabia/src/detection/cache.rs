@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::mem;
+use std::sync::Mutex;
+
+use super::{Language, Script, StrDetection};
+
+/// Maximum length in bytes of the texts whose detected language is cached,
+/// the longer texts being less likely to be repeated.
+const MAX_CACHED_TEXT_LEN: usize = 64;
+
+type Generation = HashMap<(Script, Box<str>), Language>;
+
+/// Cache of the languages detected in short texts, shared by all the tokenizations of a tokenizer,
+/// see [`crate::TokenizerBuilder::detection_cache`].
+///
+/// Indexers often tokenize millions of short and similar fields, like tags or SKUs,
+/// for which the language detection dominates the cost of the tokenization.
+///
+/// The cache keeps approximately the `capacity` most recently used texts:
+/// the texts are stored in a recent generation which becomes the old one once full,
+/// the texts of the old generation being moved back to the recent one when they are used again.
+#[derive(Debug)]
+pub struct DetectionCache {
+    capacity: usize,
+    /// recent and old generations.
+    generations: Mutex<(Generation, Generation)>,
+}
+
+impl DetectionCache {
+    /// Creates a cache of approximately `capacity` texts.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, generations: Mutex::new(Default::default()) }
+    }
+
+    /// Returns the number of cached texts.
+    pub fn len(&self) -> usize {
+        let generations = self.generations.lock().unwrap();
+        generations.0.len() + generations.1.len()
+    }
+
+    /// Returns true if no text is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the language of the text of the detector, detecting it only if it's not cached.
+    pub(crate) fn language(&self, detector: &mut StrDetection) -> Language {
+        if detector.language.is_some() || detector.inner.len() > MAX_CACHED_TEXT_LEN {
+            return detector.language();
+        }
+
+        let key = (detector.script(), Box::from(detector.inner));
+        let cached = {
+            let mut generations = self.generations.lock().unwrap();
+            let (recent, old) = &mut *generations;
+            match recent.get(&key) {
+                Some(language) => Some(*language),
+                None => old.remove(&key).inspect(|language| {
+                    recent.insert(key.clone(), *language);
+                }),
+            }
+        };
+
+        match cached {
+            Some(language) => {
+                detector.language = Some(language);
+                language
+            }
+            None => {
+                // the detection is done without locking the cache.
+                let language = detector.language();
+                self.insert(key, language);
+                language
+            }
+        }
+    }
+
+    fn insert(&self, key: (Script, Box<str>), language: Language) {
+        let mut generations = self.generations.lock().unwrap();
+        let (recent, old) = &mut *generations;
+        if recent.len() >= self.capacity.div_ceil(2) {
+            *old = mem::take(recent);
+        }
+        recent.insert(key, language);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DetectionCache;
+    use crate::detection::Detect;
+    use crate::{Language, Script};
+
+    #[test]
+    fn detection_cache() {
+        let cache = DetectionCache::new(4);
+        let text = "Le chat est sur la table";
+        let mut detector = text.detect(None);
+        let language = cache.language(&mut detector);
+        assert_eq!(cache.len(), 1);
+
+        // the cached language is used.
+        let mut detector = text.detect(None);
+        assert_eq!(cache.language(&mut detector), language);
+        assert_eq!(detector.script, Some(Script::Latin));
+        assert_eq!(cache.len(), 1);
+
+        // the least recently used texts are evicted.
+        for text in ["a", "b", "c", "d", "e"] {
+            cache.language(&mut text.detect(None));
+        }
+        assert!(cache.len() <= 4);
+
+        // the already detected languages and the long texts are not cached.
+        let mut detector = "x".detect(None);
+        detector.language = Some(Language::Eng);
+        assert_eq!(cache.language(&mut detector), Language::Eng);
+        let long = "word ".repeat(20);
+        let len = cache.len();
+        cache.language(&mut long.as_str().detect(None));
+        assert_eq!(cache.len(), len);
+    }
+}
@@ -57,8 +57,14 @@ pub(crate) fn is_hebrew(ch: char) -> bool {
     matches!(ch, '\u{0590}'..='\u{05FF}')
 }
 
+// Based on https://en.wikipedia.org/wiki/Armenian_(Unicode_block), including the Armenian ligatures.
+pub(crate) fn is_armenian(ch: char) -> bool {
+    matches!(ch, '\u{0530}'..='\u{058F}' | '\u{FB13}'..='\u{FB17}')
+}
+
+// Based on https://en.wikipedia.org/wiki/Georgian_scripts#Unicode, including the Mtavruli capitals.
 pub(crate) fn is_georgian(ch: char) -> bool {
-    matches!(ch, '\u{10A0}'..='\u{10FF}')
+    matches!(ch, '\u{10A0}'..='\u{10FF}' | '\u{1C90}'..='\u{1CBF}' | '\u{2D00}'..='\u{2D2F}')
 }
 
 pub(crate) fn is_mandarin(ch: char) -> bool {
@@ -162,6 +168,21 @@ pub(crate) fn is_khmer(ch: char) -> bool {
     matches!(ch, '\u{1780}'..='\u{17FF}' | '\u{19E0}'..='\u{19FF}')
 }
 
+// Based on: https://en.wikipedia.org/wiki/Cherokee_(Unicode_block), including the lowercase letters.
+pub(crate) fn is_cherokee(ch: char) -> bool {
+    matches!(ch, '\u{13A0}'..='\u{13FF}' | '\u{AB70}'..='\u{ABBF}')
+}
+
+// Based on: https://en.wikipedia.org/wiki/Mongolian_(Unicode_block)
+pub(crate) fn is_mongolian(ch: char) -> bool {
+    matches!(ch, '\u{1800}'..='\u{18AF}' | '\u{11660}'..='\u{1167F}')
+}
+
+// Based on: https://en.wikipedia.org/wiki/Tifinagh_(Unicode_block)
+pub(crate) fn is_tifinagh(ch: char) -> bool {
+    matches!(ch, '\u{2D30}'..='\u{2D7F}')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,9 +218,17 @@ mod tests {
         assert!(!is_ethiopic('L'));
     }
 
+    #[test]
+    fn test_is_armenian() {
+        assert!(is_armenian('Հ'));
+        assert!(is_armenian('և'));
+        assert!(!is_armenian('რ'));
+    }
+
     #[test]
     fn test_is_georgian() {
         assert!(is_georgian('რ'));
+        assert!(is_georgian('Რ'));
         assert!(!is_georgian('ж'));
     }
 
@@ -1,8 +1,12 @@
 use std::collections::HashMap;
 
-pub use script_language::{Language, Script};
+pub use allow_list::AllowList;
+pub use cache::DetectionCache;
+pub use script_language::{CjScript, Language, ParseCodeError, Script};
 use whatlang::Detector;
 
+mod allow_list;
+mod cache;
 // file copy pasted from whatlang.
 #[allow(dead_code)]
 mod chars;
@@ -31,26 +35,46 @@ impl<'o, 'al> StrDetection<'o, 'al> {
         *self.language.get_or_insert_with(|| Self::detect_lang(inner, script, self.allow_list))
     }
 
-    /// detect script with whatlang,
+    /// detect script with whatlang, falling back on the scripts unknown by whatlang, like Mongolian,
     /// if no script is detected, return Script::Other
+    #[cfg(not(feature = "icu"))]
     fn detect_script(text: &str) -> Script {
-        whatlang::detect_script(text).map(Script::from).unwrap_or_default()
+        whatlang::detect_script(text).map(Script::from).unwrap_or_else(|| {
+            text.chars()
+                .map(Script::from)
+                .find(|script| *script != Script::Other)
+                .unwrap_or_default()
+        })
     }
 
-    /// detect lang with whatlang
-    /// if no language is detected, return Language::Other
+    /// detect script with the Unicode Script property of the first character having a known script,
+    /// the text being already grouped by script, if no script is detected, return Script::Other
+    #[cfg(feature = "icu")]
+    fn detect_script(text: &str) -> Script {
+        text.chars().map(Script::from).find(|script| *script != Script::Other).unwrap_or_default()
+    }
+
+    /// detect lang with whatlang,
+    /// if no language is detected, return the first allowed language unknown by whatlang, like Kurdish,
+    /// or Language::Other
     fn detect_lang(
         text: &str,
         script: Script,
         allow_list: Option<&HashMap<Script, Vec<Language>>>,
     ) -> Language {
-        let detector = allow_list
-            .and_then(|allow_list| allow_list.get(&script))
-            .map(|allow_list| allow_list.iter().map(|lang| (*lang).into()).collect())
-            .map(Detector::with_allowlist)
+        let allowed = allow_list.and_then(|allow_list| allow_list.get(&script));
+        let detector = allowed
+            .map(|allowed| {
+                allowed.iter().filter(|lang| lang.is_detectable()).map(|lang| (*lang).into())
+            })
+            .map(|allowed| Detector::with_allowlist(allowed.collect()))
             .unwrap_or_default();
 
-        detector.detect_lang(text).map(Language::from).unwrap_or_default()
+        detector
+            .detect_lang(text)
+            .map(Language::from)
+            .or_else(|| allowed?.iter().find(|lang| !lang.is_detectable()).copied())
+            .unwrap_or_default()
     }
 }
 
@@ -1,3 +1,4 @@
+use core::fmt;
 use core::str::FromStr;
 
 #[cfg(test)]
@@ -6,10 +7,14 @@ use quickcheck::{Arbitrary, Gen};
 use super::chars;
 
 macro_rules! make_language {
-    ($($language:tt), +) => {
+    (
+        $($language:tt => $iso_639_1:literal), +;
+        $($extra_language:tt => ($extra_iso_639_3:literal, $extra_iso_639_1:expr)), +
+    ) => {
         #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
         pub enum Language {
             $($language),+,
+            $($extra_language),+,
             Other,
         }
         impl From<whatlang::Lang> for Language {
@@ -39,94 +44,148 @@ macro_rules! make_language {
             pub fn name(&self) -> &'static str {
                 match self {
                     $(Language::$language => whatlang::Lang::$language.code()), +,
+                    $(Language::$extra_language => $extra_iso_639_3), +,
                     _other => "other",
                 }
             }
 
             pub fn from_name<S: AsRef<str>>(code: S) -> Language {
-                whatlang::Lang::from_code(code.as_ref()).map(Language::from).unwrap_or_default()
+                let code = code.as_ref();
+                whatlang::Lang::from_code(code)
+                    .map(Language::from)
+                    .or_else(|| Language::from_extra_code(code))
+                    .unwrap_or_default()
+            }
+
+            /// Returns the language unknown by whatlang corresponding to the provided ISO 639-3 code.
+            fn from_extra_code(code: &str) -> Option<Language> {
+                $(
+                    if code == $extra_iso_639_3 {
+                        return Some(Language::$extra_language);
+                    }
+                )+
+                None
+            }
+
+            /// Returns true if the language can be detected by whatlang,
+            /// the other languages are only assigned when they are the allowed ones, see [`crate::AllowList`].
+            pub fn is_detectable(&self) -> bool {
+                !matches!(self, $(Language::$extra_language)|+ | Language::Other)
+            }
+
+            /// Returns all the languages except `Language::Other`.
+            pub fn all() -> &'static [Language] {
+                &[$(Language::$language), +, $(Language::$extra_language), +]
+            }
+
+            /// Returns the ISO 639-1 code of the language, `None` for `Language::Other`.
+            pub fn iso_639_1(&self) -> Option<&'static str> {
+                match self {
+                    $(Language::$language => Some($iso_639_1)), +,
+                    $(Language::$extra_language => $extra_iso_639_1), +,
+                    _other => None,
+                }
+            }
+
+            /// Returns the language corresponding to the provided ISO 639-1 code, ignoring case.
+            pub fn from_iso_639_1<S: AsRef<str>>(code: S) -> Option<Language> {
+                match code.as_ref().to_ascii_lowercase().as_str() {
+                    $($iso_639_1 => Some(Language::$language)), +,
+                    // Norwegian macrolanguage, only Bokmål is supported.
+                    "no" => Some(Language::Nob),
+                    code => Language::all().iter().copied().find(|l| l.iso_639_1() == Some(code)),
+                }
             }
         }
     };
 }
 
 make_language! {
-    Epo,
-    Eng,
-    Rus,
-    Cmn,
-    Spa,
-    Por,
-    Ita,
-    Ben,
-    Fra,
-    Deu,
-    Ukr,
-    Kat,
-    Ara,
-    Hin,
-    Jpn,
-    Heb,
-    Yid,
-    Pol,
-    Amh,
-    Jav,
-    Kor,
-    Nob,
-    Dan,
-    Swe,
-    Fin,
-    Tur,
-    Nld,
-    Hun,
-    Ces,
-    Ell,
-    Bul,
-    Bel,
-    Mar,
-    Kan,
-    Ron,
-    Slv,
-    Hrv,
-    Srp,
-    Mkd,
-    Lit,
-    Lav,
-    Est,
-    Tam,
-    Vie,
-    Urd,
-    Tha,
-    Guj,
-    Uzb,
-    Pan,
-    Aze,
-    Ind,
-    Tel,
-    Pes,
-    Mal,
-    Ori,
-    Mya,
-    Nep,
-    Sin,
-    Khm,
-    Tuk,
-    Aka,
-    Zul,
-    Sna,
-    Afr,
-    Lat,
-    Slk,
-    Cat,
-    Tgl,
-    Hye
+    Epo => "eo",
+    Eng => "en",
+    Rus => "ru",
+    Cmn => "zh",
+    Spa => "es",
+    Por => "pt",
+    Ita => "it",
+    Ben => "bn",
+    Fra => "fr",
+    Deu => "de",
+    Ukr => "uk",
+    Kat => "ka",
+    Ara => "ar",
+    Hin => "hi",
+    Jpn => "ja",
+    Heb => "he",
+    Yid => "yi",
+    Pol => "pl",
+    Amh => "am",
+    Jav => "jv",
+    Kor => "ko",
+    Nob => "nb",
+    Dan => "da",
+    Swe => "sv",
+    Fin => "fi",
+    Tur => "tr",
+    Nld => "nl",
+    Hun => "hu",
+    Ces => "cs",
+    Ell => "el",
+    Bul => "bg",
+    Bel => "be",
+    Mar => "mr",
+    Kan => "kn",
+    Ron => "ro",
+    Slv => "sl",
+    Hrv => "hr",
+    Srp => "sr",
+    Mkd => "mk",
+    Lit => "lt",
+    Lav => "lv",
+    Est => "et",
+    Tam => "ta",
+    Vie => "vi",
+    Urd => "ur",
+    Tha => "th",
+    Guj => "gu",
+    Uzb => "uz",
+    Pan => "pa",
+    Aze => "az",
+    Ind => "id",
+    Tel => "te",
+    Pes => "fa",
+    Mal => "ml",
+    Ori => "or",
+    Mya => "my",
+    Nep => "ne",
+    Sin => "si",
+    Khm => "km",
+    Tuk => "tk",
+    Aka => "ak",
+    Zul => "zu",
+    Sna => "sn",
+    Afr => "af",
+    Lat => "la",
+    Slk => "sk",
+    Cat => "ca",
+    Tgl => "tl",
+    Hye => "hy";
+    // languages unknown by whatlang, with their ISO 639-3 code and their ISO 639-1 code if any.
+    Ckb => ("ckb", None),
+    Kaz => ("kaz", Some("kk")),
+    Pus => ("pus", Some("ps")),
+    Uig => ("uig", Some("ug"))
 }
 
 macro_rules! make_script {
-    ($($script:tt), +) => {
+    (
+        $($script:tt => $iso_15924:literal), +;
+        $($extra_script:tt => ($extra_name:literal, $extra_iso_15924:literal)), +
+    ) => {
         #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
         pub enum Script {
             $($script),+,
+            $($extra_script),+,
             Cj,
             Other,
         }
@@ -147,43 +206,118 @@ macro_rules! make_script {
             pub fn name(&self) -> &'static str {
                 match self {
                     $(Script::$script => whatlang::Script::$script.name()), +,
+                    $(Script::$extra_script => $extra_name), +,
                     Script::Cj => whatlang::Script::Mandarin.name(),
                     _other => "other",
                 }
             }
 
             pub fn from_name<S: AsRef<str>>(code: S) -> Script {
-                whatlang::Script::from_str(code.as_ref()).map(Script::from).unwrap_or_default()
+                let code = code.as_ref();
+                whatlang::Script::from_str(code)
+                    .map(Script::from)
+                    .ok()
+                    .or_else(|| Script::from_extra_name(code))
+                    .unwrap_or_default()
+            }
+
+            /// Returns the script unknown by whatlang corresponding to the provided name, ignoring case.
+            fn from_extra_name(name: &str) -> Option<Script> {
+                $(
+                    if name.eq_ignore_ascii_case($extra_name) {
+                        return Some(Script::$extra_script);
+                    }
+                )+
+                None
+            }
+
+            /// Returns all the scripts except `Script::Other`.
+            pub fn all() -> &'static [Script] {
+                &[$(Script::$script), +, $(Script::$extra_script), +, Script::Cj]
+            }
+
+            /// Returns the ISO 15924 code of the script.
+            ///
+            /// `Script::Cj` is returned as `Hani` (Han) and `Script::Other` as `Zzzz` (Unknown).
+            pub fn iso_15924(&self) -> &'static str {
+                match self {
+                    $(Script::$script => $iso_15924), +,
+                    $(Script::$extra_script => $extra_iso_15924), +,
+                    Script::Cj => "Hani",
+                    _other => "Zzzz",
+                }
+            }
+
+            /// Returns the script corresponding to the provided ISO 15924 code, ignoring case.
+            ///
+            /// Han, Hiragana and Katakana codes, and their combinations, are all returned as `Script::Cj`.
+            pub fn from_iso_15924<S: AsRef<str>>(code: S) -> Option<Script> {
+                let code = code.as_ref();
+                $(
+                    if code.eq_ignore_ascii_case($iso_15924) {
+                        return Some(Script::$script);
+                    }
+                )+
+                $(
+                    if code.eq_ignore_ascii_case($extra_iso_15924) {
+                        return Some(Script::$extra_script);
+                    }
+                )+
+                match code.to_ascii_lowercase().as_str() {
+                    "hani" | "hans" | "hant" | "hira" | "kana" | "hrkt" | "jpan" => Some(Script::Cj),
+                    "zzzz" | "zyyy" => Some(Script::Other),
+                    _otherwise => None,
+                }
             }
         }
     };
 }
 
 make_script! {
-    Arabic,
-    Armenian,
-    Bengali,
-    Cyrillic,
-    Devanagari,
-    Ethiopic,
-    Georgian,
-    Greek,
-    Gujarati,
-    Gurmukhi,
-    Hangul,
-    Hebrew,
-    Kannada,
-    Khmer,
-    Latin,
-    Malayalam,
-    Myanmar,
-    Oriya,
-    Sinhala,
-    Tamil,
-    Telugu,
-    Thai
+    Arabic => "Arab",
+    Armenian => "Armn",
+    Bengali => "Beng",
+    Cyrillic => "Cyrl",
+    Devanagari => "Deva",
+    Ethiopic => "Ethi",
+    Georgian => "Geor",
+    Greek => "Grek",
+    Gujarati => "Gujr",
+    Gurmukhi => "Guru",
+    Hangul => "Hang",
+    Hebrew => "Hebr",
+    Kannada => "Knda",
+    Khmer => "Khmr",
+    Latin => "Latn",
+    Malayalam => "Mlym",
+    Myanmar => "Mymr",
+    Oriya => "Orya",
+    Sinhala => "Sinh",
+    Tamil => "Taml",
+    Telugu => "Telu",
+    Thai => "Thai";
+    // scripts unknown by whatlang, detected by charabia only, with their name and ISO 15924 code.
+    Cherokee => ("Cherokee", "Cher"),
+    Mongolian => ("Mongolian", "Mong"),
+    Tifinagh => ("Tifinagh", "Tfng")
+}
+
+#[cfg(feature = "icu")]
+impl From<char> for Script {
+    /// Uses the Unicode Script property of the character, the scripts unknown by charabia being [`Script::Other`].
+    fn from(other: char) -> Script {
+        use icu_properties::props::Script as IcuScript;
+        use icu_properties::{CodePointMapData, PropertyNamesShort};
+
+        let script = CodePointMapData::<IcuScript>::new().get(other);
+        PropertyNamesShort::<IcuScript>::new()
+            .get(script)
+            .and_then(Script::from_iso_15924)
+            .unwrap_or_default()
+    }
 }
 
+#[cfg(not(feature = "icu"))]
 impl From<char> for Script {
     fn from(other: char) -> Script {
         if chars::is_latin(other) {
@@ -194,6 +328,8 @@ impl From<char> for Script {
             Script::Arabic
         } else if chars::is_devanagari(other) {
             Script::Devanagari
+        } else if chars::is_armenian(other) {
+            Script::Armenian
         } else if chars::is_hebrew(other) {
             Script::Hebrew
         } else if chars::is_ethiopic(other) {
@@ -233,6 +369,12 @@ impl From<char> for Script {
             Script::Sinhala
         } else if chars::is_khmer(other) {
             Script::Khmer
+        } else if chars::is_cherokee(other) {
+            Script::Cherokee
+        } else if chars::is_mongolian(other) {
+            Script::Mongolian
+        } else if chars::is_tifinagh(other) {
+            Script::Tifinagh
         } else {
             Script::Other
         }
@@ -245,6 +387,196 @@ impl Default for Script {
     }
 }
 
+/// Sub-script of the [`Script::Cj`] characters.
+///
+/// The kana are only used in Japanese,
+/// so their presence is a reliable hint where the language detection isn't, like in short texts.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum CjScript {
+    Han,
+    Hiragana,
+    Katakana,
+}
+
+impl CjScript {
+    /// Returns the sub-script of the character, or `None` if it's not a [`Script::Cj`] character.
+    pub fn from_char(c: char) -> Option<CjScript> {
+        if chars::is_hiragana(c) {
+            Some(CjScript::Hiragana)
+        } else if chars::is_katakana(c) {
+            Some(CjScript::Katakana)
+        } else if chars::is_mandarin(c) {
+            Some(CjScript::Han)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the sub-script of the text, or `None` if it doesn't contain any [`Script::Cj`] character.
+    ///
+    /// The kana take precedence over the Han characters, and the hiragana over the katakana,
+    /// so a Japanese word mixing them, like "食べる", is returned as [`CjScript::Hiragana`].
+    pub fn detect(text: &str) -> Option<CjScript> {
+        text.chars().filter_map(CjScript::from_char).reduce(|a, b| match (a, b) {
+            (CjScript::Hiragana, _) | (_, CjScript::Hiragana) => CjScript::Hiragana,
+            (CjScript::Katakana, _) | (_, CjScript::Katakana) => CjScript::Katakana,
+            _ => CjScript::Han,
+        })
+    }
+
+    /// Returns true if the sub-script is hiragana or katakana.
+    pub fn is_kana(&self) -> bool {
+        matches!(self, CjScript::Hiragana | CjScript::Katakana)
+    }
+}
+
+impl Language {
+    /// Returns the scripts the language is commonly written in, the most common first.
+    pub fn scripts(&self) -> &'static [Script] {
+        use Language::*;
+        match self {
+            Epo | Eng | Spa | Por | Ita | Fra | Deu | Pol | Jav | Nob | Dan | Swe | Fin | Tur
+            | Nld | Hun | Ces | Ron | Slv | Hrv | Lit | Lav | Est | Vie | Aze | Ind | Tuk | Aka
+            | Zul | Sna | Afr | Lat | Slk | Cat | Tgl => &[Script::Latin],
+            Rus | Ukr | Bul | Bel | Mkd => &[Script::Cyrillic],
+            Srp => &[Script::Cyrillic, Script::Latin],
+            Uzb => &[Script::Latin, Script::Cyrillic],
+            Kaz => &[Script::Cyrillic, Script::Latin],
+            Cmn | Jpn => &[Script::Cj],
+            Ara | Urd | Pes | Ckb | Pus | Uig => &[Script::Arabic],
+            Pan => &[Script::Gurmukhi, Script::Arabic],
+            Hin | Mar | Nep => &[Script::Devanagari],
+            Heb | Yid => &[Script::Hebrew],
+            Ben => &[Script::Bengali],
+            Kat => &[Script::Georgian],
+            Amh => &[Script::Ethiopic],
+            Kor => &[Script::Hangul],
+            Ell => &[Script::Greek],
+            Kan => &[Script::Kannada],
+            Tam => &[Script::Tamil],
+            Tha => &[Script::Thai],
+            Guj => &[Script::Gujarati],
+            Tel => &[Script::Telugu],
+            Mal => &[Script::Malayalam],
+            Ori => &[Script::Oriya],
+            Mya => &[Script::Myanmar],
+            Sin => &[Script::Sinhala],
+            Khm => &[Script::Khmer],
+            Hye => &[Script::Armenian],
+            Other => &[],
+        }
+    }
+
+    /// Returns the ISO 639-3 code of the language, `other` for `Language::Other`.
+    pub fn iso_639_3(&self) -> &'static str {
+        self.name()
+    }
+
+    /// Returns the language corresponding to the provided ISO 639-3 code, ignoring case.
+    ///
+    /// Macrolanguage codes are returned as their supported individual language,
+    /// for instance `zho` (Chinese) is returned as `Language::Cmn` (Mandarin).
+    pub fn from_iso_639_3<S: AsRef<str>>(code: S) -> Option<Language> {
+        let code = code.as_ref().to_ascii_lowercase();
+        let language = whatlang::Lang::from_code(code.as_str()).map(Language::from);
+        language.or_else(|| Language::from_extra_code(&code)).or(match code.as_str() {
+            "zho" | "chi" => Some(Language::Cmn),
+            "nor" => Some(Language::Nob),
+            "fas" | "per" => Some(Language::Pes),
+            _otherwise => None,
+        })
+    }
+
+    /// Returns the shortest BCP-47 language tag of the language, `und` for `Language::Other`.
+    pub fn to_bcp47(&self) -> &'static str {
+        match self {
+            Language::Other => "und",
+            language => language.iso_639_1().unwrap_or_else(|| language.iso_639_3()),
+        }
+    }
+
+    /// Returns the language of the provided BCP-47 tag, like `fr`, `pt-BR` or `zh-Hant-TW`.
+    ///
+    /// Only the primary language subtag is considered, it can be an ISO 639-1 or an ISO 639-3 code.
+    pub fn from_bcp47<S: AsRef<str>>(tag: S) -> Option<Language> {
+        let primary = tag.as_ref().split(['-', '_']).next()?;
+        match primary.len() {
+            2 => Language::from_iso_639_1(primary),
+            3 => Language::from_iso_639_3(primary),
+            _otherwise => None,
+        }
+    }
+}
+
+impl Script {
+    /// Returns the script subtag of the provided BCP-47 tag if any, like `Latn` in `sr-Latn-RS`.
+    pub fn from_bcp47<S: AsRef<str>>(tag: S) -> Option<Script> {
+        tag.as_ref()
+            .split(['-', '_'])
+            .skip(1)
+            .take_while(|subtag| subtag.len() != 1) // extensions and private use start with a singleton.
+            .find(|subtag| subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+            .and_then(Script::from_iso_15924)
+    }
+}
+
+/// Error returned when parsing an unknown [`Language`] or [`Script`] code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCodeError(String);
+
+impl fmt::Display for ParseCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown language or script code `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseCodeError {}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Language {
+    type Err = ParseCodeError;
+
+    /// Parses an ISO 639-3 code, an ISO 639-1 code or a BCP-47 tag, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case(Language::Other.name()) {
+            return Ok(Language::Other);
+        }
+
+        Language::from_bcp47(s).ok_or_else(|| ParseCodeError(s.to_string()))
+    }
+}
+
+impl fmt::Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Script {
+    type Err = ParseCodeError;
+
+    /// Parses a script name, like `Latin`, or an ISO 15924 code, like `Latn`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case(Script::Other.name()) {
+            return Ok(Script::Other);
+        }
+
+        whatlang::Script::from_str(s)
+            .map(Script::from)
+            .ok()
+            .or_else(|| Script::from_extra_name(s))
+            .or_else(|| Script::from_iso_15924(s))
+            .ok_or_else(|| ParseCodeError(s.to_string()))
+    }
+}
+
 // impl Arbitrary for Script {
 #[cfg(test)]
 impl Arbitrary for Script {
@@ -253,6 +585,7 @@ impl Arbitrary for Script {
             Script::Arabic,
             Script::Armenian,
             Script::Bengali,
+            Script::Cherokee,
             Script::Cyrillic,
             Script::Devanagari,
             Script::Ethiopic,
@@ -266,12 +599,14 @@ impl Arbitrary for Script {
             Script::Khmer,
             Script::Latin,
             Script::Malayalam,
+            Script::Mongolian,
             Script::Myanmar,
             Script::Oriya,
             Script::Sinhala,
             Script::Tamil,
             Script::Telugu,
             Script::Thai,
+            Script::Tifinagh,
         ])
         .unwrap()
     }
@@ -350,6 +685,10 @@ impl Arbitrary for Language {
             Language::Cat,
             Language::Tgl,
             Language::Hye,
+            Language::Ckb,
+            Language::Kaz,
+            Language::Pus,
+            Language::Uig,
         ])
         .unwrap()
     }
@@ -357,8 +696,24 @@ impl Arbitrary for Language {
 
 #[cfg(test)]
 mod test {
+    use super::CjScript;
     use crate::{Language, Script};
 
+    #[test]
+    fn cj_script() {
+        assert_eq!(CjScript::from_char('漢'), Some(CjScript::Han));
+        assert_eq!(CjScript::from_char('あ'), Some(CjScript::Hiragana));
+        assert_eq!(CjScript::from_char('カ'), Some(CjScript::Katakana));
+        assert_eq!(CjScript::from_char('a'), None);
+
+        assert_eq!(CjScript::detect("北京"), Some(CjScript::Han));
+        assert_eq!(CjScript::detect("食べる"), Some(CjScript::Hiragana));
+        assert_eq!(CjScript::detect("東京タワー"), Some(CjScript::Katakana));
+        assert_eq!(CjScript::detect("hello"), None);
+        assert!(CjScript::Katakana.is_kana());
+        assert!(!CjScript::Han.is_kana());
+    }
+
     #[test]
     fn from_into_language() {
         assert_eq!(Language::Eng.name(), "eng");
@@ -375,5 +730,69 @@ mod test {
         assert_eq!(Script::from_name("Latin"), Script::Latin);
         assert_eq!(Script::Cj.name(), "Mandarin");
         assert_eq!(Script::from_name("Mandarin"), Script::Cj);
+        assert_eq!(Script::Cherokee.name(), "Cherokee");
+        assert_eq!(Script::from_name("Cherokee"), Script::Cherokee);
+    }
+
+    #[test]
+    fn language_iso_codes() {
+        assert_eq!(Language::Fra.iso_639_1(), Some("fr"));
+        assert_eq!(Language::Other.iso_639_1(), None);
+        assert_eq!(Language::from_iso_639_1("JA"), Some(Language::Jpn));
+        assert_eq!(Language::from_iso_639_1("no"), Some(Language::Nob));
+        assert_eq!(Language::from_iso_639_1("xx"), None);
+        assert_eq!(Language::Deu.iso_639_3(), "deu");
+        assert_eq!(Language::from_iso_639_3("Deu"), Some(Language::Deu));
+        assert_eq!(Language::from_iso_639_3("zho"), Some(Language::Cmn));
+        assert_eq!(Language::from_iso_639_3("xxx"), None);
+        assert_eq!(Language::Pus.iso_639_1(), Some("ps"));
+        assert_eq!(Language::from_iso_639_1("ps"), Some(Language::Pus));
+        assert_eq!(Language::from_iso_639_3("CKB"), Some(Language::Ckb));
+        assert_eq!(Language::Ckb.to_bcp47(), "ckb");
+    }
+
+    #[test]
+    fn language_bcp47() {
+        assert_eq!(Language::from_bcp47("pt-BR"), Some(Language::Por));
+        assert_eq!(Language::from_bcp47("zh-Hant-TW"), Some(Language::Cmn));
+        assert_eq!(Language::from_bcp47("sr_Latn"), Some(Language::Srp));
+        assert_eq!(Language::from_bcp47("cmn-Hans"), Some(Language::Cmn));
+        assert_eq!(Language::from_bcp47("x-klingon"), None);
+        assert_eq!(Language::Jpn.to_bcp47(), "ja");
+        assert_eq!(Language::Other.to_bcp47(), "und");
+    }
+
+    #[test]
+    fn language_from_str_display() {
+        assert_eq!("eng".parse::<Language>(), Ok(Language::Eng));
+        assert_eq!("en-US".parse::<Language>(), Ok(Language::Eng));
+        assert_eq!("other".parse::<Language>(), Ok(Language::Other));
+        assert!("klingon".parse::<Language>().is_err());
+        assert_eq!(Language::Kor.to_string(), "kor");
+        assert_eq!(Language::Kor.to_string().parse::<Language>(), Ok(Language::Kor));
+    }
+
+    #[test]
+    fn script_iso_codes() {
+        assert_eq!(Script::Latin.iso_15924(), "Latn");
+        assert_eq!(Script::Cj.iso_15924(), "Hani");
+        assert_eq!(Script::from_iso_15924("cyrl"), Some(Script::Cyrillic));
+        assert_eq!(Script::from_iso_15924("Jpan"), Some(Script::Cj));
+        assert_eq!(Script::from_iso_15924("Abcd"), None);
+        assert_eq!(Script::Mongolian.iso_15924(), "Mong");
+        assert_eq!(Script::from_iso_15924("cher"), Some(Script::Cherokee));
+        assert_eq!(Script::from_bcp47("sr-Latn-RS"), Some(Script::Latin));
+        assert_eq!(Script::from_bcp47("zh-Hant"), Some(Script::Cj));
+        assert_eq!(Script::from_bcp47("en-US"), None);
+    }
+
+    #[test]
+    fn script_from_str_display() {
+        assert_eq!("Latin".parse::<Script>(), Ok(Script::Latin));
+        assert_eq!("Latn".parse::<Script>(), Ok(Script::Latin));
+        assert_eq!("hebr".parse::<Script>(), Ok(Script::Hebrew));
+        assert!("Klingon".parse::<Script>().is_err());
+        assert_eq!(Script::Greek.to_string(), "Greek");
+        assert_eq!(Script::Greek.to_string().parse::<Script>(), Ok(Script::Greek));
     }
 }
@@ -0,0 +1,226 @@
+//! C ABI of charabia, allowing non-Rust search engines and plugins to tokenize texts.
+//!
+//! The text is tokenized with the default tokenizer into a [`CharabiaTokens`] array
+//! owned by the caller, which must release it with [`charabia_tokens_free`].
+//!
+//! ```c
+//! CharabiaTokens tokens;
+//! if (charabia_tokenize(text, strlen(text), &tokens) == CHARABIA_OK) {
+//!     for (size_t i = 0; i < tokens.len; i++) {
+//!         CharabiaToken token = tokens.tokens[i];
+//!         fwrite(token.lemma, 1, token.lemma_len, stdout);
+//!     }
+//!     charabia_tokens_free(tokens);
+//! }
+//! ```
+
+use std::{panic, ptr, slice};
+
+use crate::{SeparatorKind, Token, TokenKind, Tokenize};
+
+/// Status returned by [`charabia_tokenize`] when the text has been tokenized.
+pub const CHARABIA_OK: i32 = 0;
+/// Status returned by [`charabia_tokenize`] when the text or the output pointer is null.
+pub const CHARABIA_NULL_POINTER: i32 = 1;
+/// Status returned by [`charabia_tokenize`] when the text is not valid UTF-8.
+pub const CHARABIA_INVALID_UTF8: i32 = 2;
+/// Status returned by [`charabia_tokenize`] when the tokenization panicked,
+/// the panic being caught to not unwind into the caller.
+pub const CHARABIA_PANIC: i32 = 3;
+
+/// Kind of a [`CharabiaToken`], see [`TokenKind`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharabiaTokenKind {
+    Word = 0,
+    StopWord = 1,
+    SoftSeparator = 2,
+    HardSeparator = 3,
+    DateTime = 4,
+    Currency = 5,
+    Unit = 6,
+    Unknown = 7,
+    Emoji = 8,
+    Hashtag = 9,
+    Mention = 10,
+}
+
+impl From<TokenKind> for CharabiaTokenKind {
+    fn from(kind: TokenKind) -> Self {
+        match kind {
+            TokenKind::Word => Self::Word,
+            TokenKind::StopWord => Self::StopWord,
+            TokenKind::Separator(SeparatorKind::Soft) => Self::SoftSeparator,
+            TokenKind::Separator(SeparatorKind::Hard) => Self::HardSeparator,
+            TokenKind::DateTime => Self::DateTime,
+            TokenKind::Currency => Self::Currency,
+            TokenKind::Unit => Self::Unit,
+            TokenKind::Emoji => Self::Emoji,
+            TokenKind::Hashtag => Self::Hashtag,
+            TokenKind::Mention => Self::Mention,
+            TokenKind::Unknown => Self::Unknown,
+        }
+    }
+}
+
+/// Token of a [`CharabiaTokens`] array.
+///
+/// The offsets are UTF-8 byte offsets in the tokenized text,
+/// the lemma is a UTF-8 string of `lemma_len` bytes which is not nul-terminated.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CharabiaToken {
+    pub kind: CharabiaTokenKind,
+    pub lemma: *const u8,
+    pub lemma_len: usize,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+}
+
+/// Array of tokens filled by [`charabia_tokenize`] and released by [`charabia_tokens_free`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct CharabiaTokens {
+    pub tokens: *mut CharabiaToken,
+    pub len: usize,
+    /// buffer holding the lemmas of all the tokens.
+    lemmas: *mut u8,
+    lemmas_len: usize,
+}
+
+impl CharabiaTokens {
+    fn new<'o>(tokens: impl Iterator<Item = Token<'o>>) -> Self {
+        let tokens: Vec<_> = tokens.collect();
+
+        // the lemmas are stored contiguously to do a single allocation.
+        let mut lemmas = String::with_capacity(tokens.iter().map(|t| t.lemma.len()).sum());
+        let mut spans = Vec::with_capacity(tokens.len());
+        for token in &tokens {
+            spans.push(lemmas.len());
+            lemmas.push_str(token.lemma());
+        }
+        let lemmas = Box::into_raw(lemmas.into_bytes().into_boxed_slice());
+        let lemmas_len = lemmas.len();
+        let lemmas = lemmas as *mut u8;
+
+        let tokens: Box<[_]> = tokens
+            .iter()
+            .zip(spans)
+            .map(|(token, start)| CharabiaToken {
+                kind: token.kind.into(),
+                // SAFETY: the lemma is in the bounds of the buffer.
+                lemma: unsafe { lemmas.add(start) },
+                lemma_len: token.lemma.len(),
+                byte_start: token.byte_start,
+                byte_end: token.byte_end,
+                char_start: token.char_start,
+                char_end: token.char_end,
+            })
+            .collect();
+        let len = tokens.len();
+
+        Self { tokens: Box::into_raw(tokens) as *mut _, len, lemmas, lemmas_len }
+    }
+}
+
+/// Tokenizes the UTF-8 `text` of `len` bytes with the default tokenizer, writing the tokens into `out`.
+///
+/// Returns [`CHARABIA_OK`] on success, the tokens must then be released with [`charabia_tokens_free`].
+/// Otherwise, `out` is left untouched.
+///
+/// # Safety
+///
+/// `text` must point to `len` readable bytes and `out` must point to a writable [`CharabiaTokens`].
+#[no_mangle]
+pub unsafe extern "C" fn charabia_tokenize(
+    text: *const u8,
+    len: usize,
+    out: *mut CharabiaTokens,
+) -> i32 {
+    if text.is_null() || out.is_null() {
+        return CHARABIA_NULL_POINTER;
+    }
+
+    let text = slice::from_raw_parts(text, len);
+    let Ok(text) = std::str::from_utf8(text) else {
+        return CHARABIA_INVALID_UTF8;
+    };
+
+    let Ok(tokens) = panic::catch_unwind(|| CharabiaTokens::new(text.tokenize())) else {
+        return CHARABIA_PANIC;
+    };
+
+    ptr::write(out, tokens);
+    CHARABIA_OK
+}
+
+/// Releases the tokens filled by [`charabia_tokenize`].
+///
+/// # Safety
+///
+/// `tokens` must have been filled by [`charabia_tokenize`] and not already been released.
+#[no_mangle]
+pub unsafe extern "C" fn charabia_tokens_free(tokens: CharabiaTokens) {
+    if !tokens.tokens.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(tokens.tokens, tokens.len)));
+    }
+    if !tokens.lemmas.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(tokens.lemmas, tokens.lemmas_len)));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::mem::MaybeUninit;
+    use std::slice;
+
+    use super::*;
+
+    #[test]
+    fn tokenize() {
+        let text = "Thé chat, 42";
+        let mut out = MaybeUninit::uninit();
+        let status = unsafe { charabia_tokenize(text.as_ptr(), text.len(), out.as_mut_ptr()) };
+        assert_eq!(status, CHARABIA_OK);
+
+        let tokens = unsafe { out.assume_init() };
+        let slice = unsafe { slice::from_raw_parts(tokens.tokens, tokens.len) };
+        let lemmas: Vec<_> = slice
+            .iter()
+            .map(|t| {
+                let lemma = unsafe { slice::from_raw_parts(t.lemma, t.lemma_len) };
+                (std::str::from_utf8(lemma).unwrap(), t.kind, t.byte_start, t.byte_end)
+            })
+            .collect();
+        assert_eq!(
+            lemmas,
+            [
+                ("the", CharabiaTokenKind::Word, 0, 4),
+                (" ", CharabiaTokenKind::SoftSeparator, 4, 5),
+                ("chat", CharabiaTokenKind::Word, 5, 9),
+                (", ", CharabiaTokenKind::HardSeparator, 9, 11),
+                ("42", CharabiaTokenKind::Word, 11, 13),
+            ]
+        );
+        unsafe { charabia_tokens_free(tokens) };
+    }
+
+    #[test]
+    fn invalid_input() {
+        let mut out = MaybeUninit::uninit();
+        let text = [0xff, 0xfe];
+        let status = unsafe { charabia_tokenize(text.as_ptr(), text.len(), out.as_mut_ptr()) };
+        assert_eq!(status, CHARABIA_INVALID_UTF8);
+        let status = unsafe { charabia_tokenize(std::ptr::null(), 0, out.as_mut_ptr()) };
+        assert_eq!(status, CHARABIA_NULL_POINTER);
+
+        // an empty text gives an empty array.
+        let status = unsafe { charabia_tokenize("".as_ptr(), 0, out.as_mut_ptr()) };
+        assert_eq!(status, CHARABIA_OK);
+        let tokens = unsafe { out.assume_init() };
+        assert_eq!(tokens.len, 0);
+        unsafe { charabia_tokens_free(tokens) };
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{Boundary, Script, Token};
+
+/// Iterator over [`Token`]s marking the ends of sentences and paragraphs,
+/// see [`TokenFilter::mark_boundaries`](super::TokenFilter::mark_boundaries).
+pub struct BoundaryIter<'o, I> {
+    inner: I,
+    /// token read ahead to know if a terminal punctuation is followed by a whitespace.
+    lookahead: Option<Token<'o>>,
+    /// number of line breaks in the current run of separators.
+    line_breaks: usize,
+}
+
+impl<'o, I> BoundaryIter<'o, I> {
+    pub(super) fn new(inner: I) -> Self {
+        Self { inner, lookahead: None, line_breaks: 0 }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for BoundaryIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut token = self.lookahead.take().or_else(|| self.inner.next())?;
+        if !token.is_separator() {
+            self.line_breaks = 0;
+            return Some(token);
+        }
+
+        let lemma = token.lemma();
+        let line_breaks = self.line_breaks;
+        self.line_breaks += lemma.matches('\n').count();
+        if lemma.contains('\u{2029}') || (line_breaks < 2 && self.line_breaks >= 2) {
+            token.boundary = Some(Boundary::Paragraph);
+        } else if lemma.contains(is_terminal_punctuation) {
+            let ends_sentence = token.script == Script::Cj
+                || lemma.contains(is_cj_terminal_punctuation)
+                || lemma.ends_with(char::is_whitespace)
+                || {
+                    self.lookahead = self.inner.next();
+                    self.lookahead.as_ref().is_none_or(|next| {
+                        next.byte_start != token.byte_end
+                            || next.lemma().starts_with(char::is_whitespace)
+                    })
+                };
+            if ends_sentence {
+                token.boundary = Some(Boundary::Sentence);
+            }
+        }
+
+        Some(token)
+    }
+}
+
+fn is_terminal_punctuation(c: char) -> bool {
+    matches!(c, '.' | '!' | '?' | '…' | '؟' | '।' | '॥' | '።' | '։')
+        || is_cj_terminal_punctuation(c)
+}
+
+/// Returns true for the terminal punctuations of the languages written without spaces.
+fn is_cj_terminal_punctuation(c: char) -> bool {
+    matches!(c, '。' | '｡' | '！' | '？')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::TokenFilter;
+    use crate::{Boundary, Tokenize};
+
+    #[test]
+    fn mark_boundaries() {
+        let text = "One. Two!\n\nThree? Pi is 3.14, ok\r\n\r\nsix.\n世界。你好";
+        let boundaries: Vec<_> = text
+            .tokenize()
+            .mark_boundaries()
+            .filter_map(|t| Some((&text[t.byte_start..t.byte_end], t.boundary?)))
+            .collect();
+        assert_eq!(
+            boundaries,
+            [
+                (". ", Boundary::Sentence),
+                ("!", Boundary::Sentence),
+                ("\n", Boundary::Paragraph),
+                ("?", Boundary::Sentence),
+                ("\n", Boundary::Paragraph),
+                (".", Boundary::Sentence),
+                ("。", Boundary::Sentence),
+            ]
+        );
+    }
+}
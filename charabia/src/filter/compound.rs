@@ -0,0 +1,205 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use fst::Set;
+
+use crate::{Language, Token};
+
+/// Minimum number of characters of a compound part, avoiding splits on short words like "is" or "on".
+const MIN_PART_CHARS: usize = 3;
+
+/// Iterator over [`Token`]s and the parts of the compound words,
+/// see [`TokenFilter::split_compounds`](super::TokenFilter::split_compounds).
+pub struct CompoundSplitIter<'o, 'd, I, A> {
+    inner: I,
+    dictionary: &'d Set<A>,
+    /// parts to emit before reading the next token.
+    pending: VecDeque<Token<'o>>,
+}
+
+impl<'o, 'd, I, A> CompoundSplitIter<'o, 'd, I, A> {
+    pub(super) fn new(inner: I, dictionary: &'d Set<A>) -> Self {
+        Self { inner, dictionary, pending: VecDeque::new() }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>, A: AsRef<[u8]>> Iterator
+    for CompoundSplitIter<'o, '_, I, A>
+{
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(part) = self.pending.pop_front() {
+            return Some(part);
+        }
+
+        let token = self.inner.next()?;
+        let linking = token.language.and_then(linking_morphemes);
+        if let (true, Some(linking)) = (token.is_word(), linking) {
+            let mut parts = Vec::new();
+            if split(token.lemma(), 0, self.dictionary, linking, &mut parts) && parts.len() > 1 {
+                self.pending.extend(parts.into_iter().map(|(start, end)| part(&token, start, end)));
+            }
+        }
+
+        Some(token)
+    }
+}
+
+/// Returns the linking morphemes allowed between the parts of the compounds of the provided language,
+/// if its compound splitting is enabled.
+fn linking_morphemes(language: Language) -> Option<&'static [&'static str]> {
+    match language {
+        // the Dutch compounds may join their parts with an "s" or an "en", like in "stationsstraat" or "boekenkast".
+        #[cfg(feature = "compound-dutch")]
+        Language::Nld => Some(&["s", "en", "e"]),
+        // the Finnish compounds join their parts directly, the first one being possibly inflected like in "auringonlasku".
+        #[cfg(feature = "compound-finnish")]
+        Language::Fin => Some(&[]),
+        // the Swedish compounds may join their parts with an "s", like in "kvällsmat".
+        #[cfg(feature = "compound-swedish")]
+        Language::Swe => Some(&["s"]),
+        _ => None,
+    }
+}
+
+/// Splits `word[start..]` into dictionary words, pushing their byte ranges in `parts`,
+/// the longest parts being tried first to keep the known compounds, like "fotboll" in "fotbollsmatch", whole.
+fn split<A: AsRef<[u8]>>(
+    word: &str,
+    start: usize,
+    dictionary: &Set<A>,
+    linking: &[&str],
+    parts: &mut Vec<(usize, usize)>,
+) -> bool {
+    let rest = &word[start..];
+    let ends = rest.char_indices().map(|(i, _)| i).skip(MIN_PART_CHARS).chain(Some(rest.len()));
+    let ends: Vec<_> = ends.map(|end| start + end).collect();
+
+    for &end in ends.iter().rev() {
+        // the whole word is not a part of itself.
+        if (start == 0 && end == word.len()) || !dictionary.contains(&word[start..end]) {
+            continue;
+        }
+
+        parts.push((start, end));
+        if end == word.len() {
+            return true;
+        }
+        for link in Some(&"").into_iter().chain(linking) {
+            if word[end..].starts_with(link)
+                && split(word, end + link.len(), dictionary, linking, parts)
+            {
+                return true;
+            }
+        }
+        parts.pop();
+    }
+
+    false
+}
+
+/// Creates a token containing the bytes `start..end` of the normalized lemma of the provided token.
+fn part<'o>(token: &Token<'o>, start: usize, end: usize) -> Token<'o> {
+    let (char_start, byte_start) = token.original_lengths(start);
+    let (char_end, byte_end) = token.original_lengths(end);
+    let lemma = match &token.lemma {
+        Cow::Borrowed(lemma) => Cow::Borrowed(&lemma[start..end]),
+        Cow::Owned(lemma) => Cow::Owned(lemma[start..end].to_string()),
+    };
+
+    Token {
+        lemma,
+        char_start: token.char_start + char_start,
+        char_end: token.char_start + char_end,
+        byte_start: token.byte_start + byte_start,
+        byte_end: token.byte_start + byte_end,
+        char_map: token.char_map.as_ref().map(|char_map| char_map[char_start..char_end].to_vec()),
+        original: None,
+        ..token.clone()
+    }
+}
+
+#[cfg(all(
+    test,
+    feature = "compound-dutch",
+    feature = "compound-finnish",
+    feature = "compound-swedish"
+))]
+mod test {
+    use fst::Set;
+
+    use crate::filter::TokenFilter;
+    use crate::{AllowList, Language, TokenizerBuilder};
+
+    fn split(text: &str, language: Language, words: &[&str]) -> Vec<(String, String)> {
+        let dictionary = Set::from_iter(words).unwrap();
+        let allow_list = AllowList::new().languages([language]);
+        let mut builder = TokenizerBuilder::default();
+        // the stemmed lemmas, like "kvallsm", are not made of the words of the dictionary.
+        builder.allow_list(&allow_list).create_char_map(true).stemming(false);
+        let tokenizer = builder.build();
+
+        tokenizer
+            .tokenize(text)
+            .split_compounds(&dictionary)
+            .filter(|t| t.is_word())
+            .map(|t| (t.lemma.into_owned(), text[t.byte_start..t.byte_end].to_string()))
+            .collect()
+    }
+
+    fn owned(parts: &[(&str, &str)]) -> Vec<(String, String)> {
+        parts.iter().map(|(lemma, original)| (lemma.to_string(), original.to_string())).collect()
+    }
+
+    #[test]
+    fn swedish_compounds() {
+        let words = ["boll", "fot", "fotboll", "kvall", "mat", "match"];
+        assert_eq!(
+            split("Fotbollsmatch på kvällsmat", Language::Swe, &words),
+            owned(&[
+                ("fotbollsmatch", "Fotbollsmatch"),
+                ("fotboll", "Fotboll"),
+                ("match", "match"),
+                ("pa", "på"),
+                ("kvallsmat", "kvällsmat"),
+                ("kvall", "kväll"),
+                ("mat", "mat"),
+            ])
+        );
+    }
+
+    #[test]
+    fn dutch_compounds() {
+        let words = ["boek", "kast", "station", "straat"];
+        assert_eq!(
+            split("Stationsstraat boekenkast", Language::Nld, &words),
+            owned(&[
+                ("stationsstraat", "Stationsstraat"),
+                ("station", "Station"),
+                ("straat", "straat"),
+                ("boekenkast", "boekenkast"),
+                ("boek", "boek"),
+                ("kast", "kast"),
+            ])
+        );
+    }
+
+    #[test]
+    fn finnish_compounds() {
+        let words = ["kirja", "kirjasto", "kortti", "sto"];
+        assert_eq!(
+            split("Kirjastokortti", Language::Fin, &words),
+            owned(&[
+                ("kirjastokortti", "Kirjastokortti"),
+                ("kirjasto", "Kirjasto"),
+                ("kortti", "kortti"),
+            ])
+        );
+        // the Swedish linking morpheme is not used in Finnish.
+        assert_eq!(
+            split("kirjastoskortti", Language::Fin, &words),
+            owned(&[("kirjastoskortti", "kirjastoskortti")])
+        );
+    }
+}
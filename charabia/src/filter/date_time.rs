@@ -0,0 +1,160 @@
+use std::collections::VecDeque;
+
+use super::merge::merge;
+use crate::{Token, TokenKind};
+
+/// Maximum number of tokens of a date or a time, like "10", ":", "30", ":", "15", " ", "pm".
+const MAX_TOKENS: usize = 7;
+
+/// Iterator over [`Token`]s merging the dates and the times in single tokens,
+/// see [`TokenFilter::recognize_date_times`](super::TokenFilter::recognize_date_times).
+pub struct DateTimeIter<'o, I> {
+    inner: I,
+    /// tokens read ahead to find the end of a date or a time.
+    lookahead: VecDeque<Token<'o>>,
+}
+
+impl<'o, I> DateTimeIter<'o, I> {
+    pub(super) fn new(inner: I) -> Self {
+        Self { inner, lookahead: VecDeque::new() }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for DateTimeIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.lookahead.pop_front().or_else(|| self.inner.next())?;
+        if token.is_separator() || !token.lemma().starts_with(|c: char| c.is_ascii_digit()) {
+            return Some(token);
+        }
+
+        while self.lookahead.len() < MAX_TOKENS - 1 {
+            match self.inner.next() {
+                Some(token) => self.lookahead.push_back(token),
+                None => break,
+            }
+        }
+
+        // find the longest run of adjacent tokens forming a date or a time.
+        let mut text = token.lemma().to_string();
+        let mut byte_end = token.byte_end;
+        let mut len = is_date_time(&text).then_some(0);
+        for (i, next) in self.lookahead.iter().enumerate() {
+            let is_date_time_separator =
+                matches!(next.lemma(), "-" | "/" | "." | ":" | " ") && next.is_separator();
+            if next.byte_start != byte_end || (next.is_separator() && !is_date_time_separator) {
+                break;
+            }
+
+            text.push_str(next.lemma());
+            byte_end = next.byte_end;
+            if !next.is_separator() && is_date_time(&text) {
+                len = Some(i + 1);
+            }
+        }
+
+        match len {
+            Some(len) => {
+                let following: Vec<_> = self.lookahead.drain(..len).collect();
+                let mut token = merge(token, following);
+                token.kind = TokenKind::DateTime;
+                Some(token)
+            }
+            None => Some(token),
+        }
+    }
+}
+
+fn is_date_time(text: &str) -> bool {
+    is_date(text) || is_time(text)
+}
+
+/// Returns the numbers of the text if it's only made of numbers split by `separator`.
+fn numbers(text: &str, separator: char) -> Option<Vec<(u32, usize)>> {
+    text.split(separator)
+        .map(|part| {
+            let valid =
+                !part.is_empty() && part.len() <= 4 && part.bytes().all(|b| b.is_ascii_digit());
+            valid.then(|| (part.parse().unwrap(), part.len()))
+        })
+        .collect()
+}
+
+/// Recognizes the year-month-day dates, like "2024-05-01",
+/// and the day-month-year or month-day-year dates, like "01/05/2024".
+fn is_date(text: &str) -> bool {
+    let Some(separator) = text.chars().find(|c| matches!(c, '-' | '/' | '.')) else {
+        return false;
+    };
+
+    match numbers(text, separator).as_deref() {
+        Some(&[(_, 4), (month, 1..=2), (day, 1..=2)]) => is_month(month) && is_day(day),
+        Some(&[(first, 1..=2), (second, 1..=2), (_, 4)]) => {
+            (is_day(first) && is_month(second)) || (is_month(first) && is_day(second))
+        }
+        _ => false,
+    }
+}
+
+/// Recognizes the times, like "10:30", "10:30:15", "10:30pm" or "3 pm".
+fn is_time(text: &str) -> bool {
+    let (text, meridiem) = match text.strip_suffix("am").or_else(|| text.strip_suffix("pm")) {
+        Some(text) => (text.strip_suffix(' ').unwrap_or(text), true),
+        None => (text, false),
+    };
+
+    let max_hour = if meridiem { 12 } else { 23 };
+    match numbers(text, ':').as_deref() {
+        Some(&[(hour, 1..=2)]) => meridiem && (1..=max_hour).contains(&hour),
+        Some(&[(hour, 1..=2), (minute, 2)]) => hour <= max_hour && minute < 60,
+        Some(&[(hour, 1..=2), (minute, 2), (second, 2)]) => {
+            hour <= max_hour && minute < 60 && second < 60
+        }
+        _ => false,
+    }
+}
+
+fn is_month(month: u32) -> bool {
+    (1..=12).contains(&month)
+}
+
+fn is_day(day: u32) -> bool {
+    (1..=31).contains(&day)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::TokenFilter;
+    use crate::{TokenKind, Tokenize};
+
+    #[test]
+    fn recognize_date_times() {
+        let text = "On 2024-05-01 at 3pm, 01/05/2024 10:30 PM or 10:30:15 and 1.5.2024";
+        let date_times: Vec<_> = text
+            .tokenize()
+            .recognize_date_times()
+            .filter(|t| t.is_date_time())
+            .map(|t| (t.lemma.into_owned(), t.byte_start, t.byte_end))
+            .collect();
+        assert_eq!(
+            date_times,
+            [
+                ("2024-05-01".to_string(), 3, 13),
+                ("3pm".to_string(), 17, 20),
+                ("01/05/2024".to_string(), 22, 32),
+                ("10:30 pm".to_string(), 33, 41),
+                ("10:30:15".to_string(), 45, 53),
+                ("1.5.2024".to_string(), 58, 66),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_date_times() {
+        let text = "2024-13-01 1.5 25:00 13pm 10:3 3 apples 1,000";
+        let kinds: Vec<_> = text.tokenize().recognize_date_times().map(|t| t.kind).collect();
+        assert!(!kinds.contains(&TokenKind::DateTime));
+        assert_eq!(kinds.len(), text.tokenize().count());
+    }
+}
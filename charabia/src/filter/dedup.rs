@@ -0,0 +1,117 @@
+use std::collections::VecDeque;
+
+use crate::{Script, Token};
+
+/// Iterator over [`Token`]s removing the tokens contained in an other token,
+/// see [`TokenFilter::dedup_overlapping`](super::TokenFilter::dedup_overlapping).
+pub struct DedupOverlappingIter<'o, I> {
+    inner: I,
+    scripts: Vec<Script>,
+    /// tokens read since the last separator that are not deduplicated yet.
+    words: Vec<Token<'o>>,
+    /// deduplicated tokens to emit before reading the next token.
+    pending: VecDeque<Token<'o>>,
+}
+
+impl<'o, I> DedupOverlappingIter<'o, I> {
+    pub(super) fn new(inner: I, scripts: &[Script]) -> Self {
+        Self { inner, scripts: scripts.to_vec(), words: Vec::new(), pending: VecDeque::new() }
+    }
+
+    /// Moves the read tokens that are not contained in an other token to the pending ones.
+    fn flush(&mut self) {
+        let contained: Vec<_> = self
+            .words
+            .iter()
+            .enumerate()
+            .map(|(i, token)| {
+                self.scripts.contains(&token.script)
+                    && self.words.iter().enumerate().any(|(j, other)| {
+                        let len = token.byte_end - token.byte_start;
+                        let other_len = other.byte_end - other.byte_start;
+                        other.byte_start <= token.byte_start
+                            && token.byte_end <= other.byte_end
+                            // keep the first of the tokens having the same position.
+                            && (other_len > len || (other_len == len && j < i))
+                    })
+            })
+            .collect();
+
+        let words = self.words.drain(..).zip(contained);
+        self.pending.extend(words.filter(|(_, contained)| !contained).map(|(token, _)| token));
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for DedupOverlappingIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(token) = self.pending.pop_front() {
+                return Some(token);
+            }
+
+            // the overlapping tokens are not necessarily consecutive, e.g. the sub-words may be emitted before the whole word,
+            // so the tokens are deduplicated between two separators.
+            match self.inner.next() {
+                Some(token) if token.is_separator() => {
+                    self.flush();
+                    self.pending.push_back(token);
+                }
+                Some(token) => self.words.push(token),
+                None if self.words.is_empty() => return None,
+                None => self.flush(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use crate::filter::TokenFilter;
+    use crate::{Script, Token, TokenKind, Tokenize};
+
+    fn token(lemma: &'static str, byte_start: usize, script: Script) -> Token<'static> {
+        Token {
+            kind: TokenKind::Word,
+            lemma: Cow::Borrowed(lemma),
+            byte_start,
+            byte_end: byte_start + lemma.len(),
+            script,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn dedup_overlapping() {
+        // tokens as segmented for search, the sub-words are followed by the whole word.
+        let tokens = vec![
+            token("中国", 0, Script::Cj),
+            token("科学", 6, Script::Cj),
+            token("学院", 9, Script::Cj),
+            token("科学院", 6, Script::Cj),
+            token("中国科学院", 0, Script::Cj),
+            token("很", 15, Script::Cj),
+            token("大", 18, Script::Cj),
+        ];
+
+        let deduplicated: Vec<_> =
+            tokens.clone().into_iter().dedup_overlapping(&[Script::Cj]).map(|t| t.lemma).collect();
+        assert_eq!(deduplicated, ["中国科学院", "很", "大"]);
+
+        // only the tokens of the provided scripts are deduplicated.
+        let deduplicated: Vec<_> =
+            tokens.clone().into_iter().dedup_overlapping(&[Script::Latin]).collect();
+        assert_eq!(deduplicated, tokens);
+    }
+
+    #[test]
+    fn dedup_edge_ngrams() {
+        let text = "The quick brown fox";
+        let deduplicated: Vec<_> =
+            text.tokenize().edge_ngrams(1, 3).dedup_overlapping(&[Script::Latin]).collect();
+        assert_eq!(deduplicated, text.tokenize().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::VecDeque;
+
+use unicode_normalization::char::is_combining_mark;
+
+use crate::normalizer::shrink_cow;
+use crate::Token;
+
+/// Iterator over [`Token`]s and the prefixes of the words,
+/// see [`TokenFilter::edge_ngrams`](super::TokenFilter::edge_ngrams).
+pub struct EdgeNgramIter<'o, I> {
+    inner: I,
+    min_len: usize,
+    max_len: usize,
+    /// prefixes to emit before reading the next token.
+    pending: VecDeque<Token<'o>>,
+}
+
+impl<'o, I> EdgeNgramIter<'o, I> {
+    pub(super) fn new(inner: I, min_len: usize, max_len: usize) -> Self {
+        Self { inner, min_len: min_len.max(1), max_len, pending: VecDeque::new() }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for EdgeNgramIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(prefix) = self.pending.pop_front() {
+            return Some(prefix);
+        }
+
+        let token = self.inner.next()?;
+        if token.is_word() {
+            // the prefix as long as the word is the word itself,
+            // and a prefix never ends before a combining mark, like a vowel sign in Tamil.
+            let prefixes = token
+                .lemma
+                .char_indices()
+                .filter(|(_, c)| !is_combining_mark(*c))
+                .map(|(i, _)| i)
+                .skip(self.min_len)
+                .take((self.max_len + 1).saturating_sub(self.min_len));
+            for byte_len in prefixes {
+                self.pending.push_back(prefix(&token, byte_len));
+            }
+        }
+
+        Some(token)
+    }
+}
+
+/// Creates a token containing the first `byte_len` bytes of the normalized lemma of the provided token.
+fn prefix<'o>(token: &Token<'o>, byte_len: usize) -> Token<'o> {
+    let (char_count, original_byte_len) = token.original_lengths(byte_len);
+    let char_map = token.char_map.as_ref().map(|char_map| {
+        let mut remaining = byte_len;
+        char_map[..char_count]
+            .iter()
+            .map(|&(original_len, normalized_len)| {
+                let len = remaining.min(normalized_len as usize);
+                remaining -= len;
+                (original_len, len as u8)
+            })
+            .collect()
+    });
+
+    Token {
+        lemma: shrink_cow(&token.lemma, byte_len),
+        char_end: token.char_start + char_count,
+        byte_end: token.byte_start + original_byte_len,
+        char_map,
+        ..token.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::TokenFilter;
+    use crate::{Tokenize, TokenizerBuilder};
+
+    #[test]
+    fn edge_ngrams() {
+        let text = "Über the hill";
+        let tokens: Vec<_> = text.tokenize().edge_ngrams(2, 3).collect();
+        let lemmas: Vec<_> = tokens.iter().map(|t| t.lemma()).collect();
+        assert_eq!(lemmas, ["uber", "ub", "ube", " ", "the", "th", " ", "hill", "hi", "hil"]);
+
+        let hil = &tokens[9];
+        assert_eq!(&text[hil.byte_start..hil.byte_end], "hil");
+        assert_eq!((hil.char_start, hil.char_end), (9, 12));
+    }
+
+    #[test]
+    fn edge_ngrams_char_map() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "Ĳssel";
+        let tokens: Vec<_> = tokenizer.tokenize(text).edge_ngrams(1, 1).collect();
+        assert_eq!(tokens[0].lemma(), "ijssel");
+        assert_eq!(tokens[0].char_map, Some(vec![(2, 2), (1, 1), (1, 1), (1, 1), (1, 1)]));
+
+        // the prefix cuts the normalization of the first character.
+        let prefix = &tokens[1];
+        assert_eq!(prefix.lemma(), "i");
+        assert_eq!(prefix.char_map, Some(vec![(2, 1)]));
+        assert_eq!(&text[prefix.byte_start..prefix.byte_end], "Ĳ");
+    }
+}
@@ -0,0 +1,72 @@
+use super::shingle::shingle;
+use crate::{Script, Token};
+
+/// Iterator over [`Token`]s and the Hangul words joined across whitespaces,
+/// see [`TokenFilter::join_hangul_words`](super::TokenFilter::join_hangul_words).
+pub struct HangulJoinIter<'o, I> {
+    inner: I,
+    /// last Hangul word and the whitespaces following it.
+    window: Vec<Token<'o>>,
+    /// joined word to emit before reading the next token.
+    pending: Option<Token<'o>>,
+}
+
+impl<'o, I> HangulJoinIter<'o, I> {
+    pub(super) fn new(inner: I) -> Self {
+        Self { inner, window: Vec::new(), pending: None }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for HangulJoinIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(joined) = self.pending.take() {
+            return Some(joined);
+        }
+
+        let token = self.inner.next()?;
+        if token.is_separator() {
+            if token.lemma().trim().is_empty() && !self.window.is_empty() {
+                self.window.push(token.clone());
+            } else {
+                self.window.clear();
+            }
+        } else if token.script == Script::Hangul {
+            // only the words separated by whitespaces are joined, the adjacent ones are already segmented together.
+            if self.window.len() > 1 {
+                self.window.push(token.clone());
+                self.pending = Some(shingle(&self.window, ""));
+            }
+            self.window.clear();
+            self.window.push(token.clone());
+        } else {
+            self.window.clear();
+        }
+
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::TokenFilter;
+    use crate::Tokenize;
+
+    #[test]
+    fn join_hangul_words() {
+        let text = "서울 부산. 서울 hello 부산";
+        let tokens: Vec<_> = text.tokenize().join_hangul_words().collect();
+        let words: Vec<_> = tokens
+            .iter()
+            .filter(|t| t.is_word())
+            .map(|t| &text[t.byte_start..t.byte_end])
+            .collect();
+        assert_eq!(words, ["서울", "부산", "서울 부산", "서울", "hello", "부산"]);
+
+        let joined = &tokens[3];
+        assert_eq!(joined.lemma(), format!("{}{}", tokens[0].lemma(), tokens[2].lemma()));
+        assert_eq!(joined.char_start, 0);
+        assert_eq!(joined.char_end, 5);
+    }
+}
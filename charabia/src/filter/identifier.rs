@@ -0,0 +1,126 @@
+use std::collections::VecDeque;
+
+use super::merge::merge;
+use crate::Token;
+
+/// Iterator over [`Token`]s and the code identifiers made of several adjacent words,
+/// see [`TokenFilter::code_identifiers`](super::TokenFilter::code_identifiers).
+pub struct CodeIdentifierIter<'o, I> {
+    inner: I,
+    /// words and connectors of the current identifier.
+    window: Vec<Token<'o>>,
+    /// tokens read ahead to find the end of the current identifier.
+    lookahead: VecDeque<Token<'o>>,
+    /// identifier to emit before reading the next token.
+    pending: Option<Token<'o>>,
+}
+
+impl<'o, I> CodeIdentifierIter<'o, I> {
+    pub(super) fn new(inner: I) -> Self {
+        Self { inner, window: Vec::new(), lookahead: VecDeque::new(), pending: None }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> CodeIdentifierIter<'o, I> {
+    /// Returns true if the identifier doesn't continue after the provided word,
+    /// the next word not being adjacent or separated by connectors only.
+    fn ends_after(&mut self, word: &Token<'o>) -> bool {
+        let mut byte_end = word.byte_end;
+        for i in 0.. {
+            if self.lookahead.len() <= i {
+                match self.inner.next() {
+                    Some(token) => self.lookahead.push_back(token),
+                    None => return true,
+                }
+            }
+
+            let next = &self.lookahead[i];
+            if next.byte_start != byte_end || (next.is_separator() && !is_connector(next)) {
+                return true;
+            } else if !next.is_separator() {
+                return false;
+            }
+            byte_end = next.byte_end;
+        }
+
+        true
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for CodeIdentifierIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(identifier) = self.pending.take() {
+            return Some(identifier);
+        }
+
+        let token = self.lookahead.pop_front().or_else(|| self.inner.next())?;
+        let continues = self.window.last().is_some_and(|last| {
+            last.byte_end == token.byte_start && (!token.is_separator() || is_connector(&token))
+        });
+        if !continues {
+            self.window.clear();
+        }
+        if continues || !token.is_separator() {
+            self.window.push(token.clone());
+        }
+
+        if !token.is_separator() && self.ends_after(&token) {
+            let mut window = std::mem::take(&mut self.window);
+            if window.iter().filter(|t| !t.is_separator()).count() > 1 {
+                let following = window.split_off(1);
+                let first = window.pop().unwrap();
+                self.pending = Some(merge(first, following));
+            }
+        }
+
+        Some(token)
+    }
+}
+
+/// Returns true for the separators joining the parts of an identifier,
+/// like in "snake_case", "kebab-case", "foo::bar", "a.b.c" or "src/main.rs".
+fn is_connector(token: &Token) -> bool {
+    token.is_separator()
+        && !token.lemma().is_empty()
+        && token.lemma().chars().all(|c| matches!(c, '_' | '-' | '.' | ':' | '/' | '\\'))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::TokenFilter;
+    use crate::Tokenize;
+
+    #[test]
+    fn code_identifiers() {
+        let text = "call foo::bar_baz(camelCase, src/main.rs) at kebab-case. Done";
+        let tokens: Vec<_> = text.tokenize().code_identifiers().collect();
+        let words: Vec<_> = tokens.iter().filter(|t| t.is_word()).map(|t| t.lemma()).collect();
+        assert_eq!(
+            words,
+            [
+                "call",
+                "foo",
+                "bar",
+                "baz",
+                "foo::bar_baz",
+                "camel",
+                "case",
+                "camelcase",
+                "src",
+                "main",
+                "rs",
+                "src/main.rs",
+                "at",
+                "kebab",
+                "case",
+                "kebab-case",
+                "done",
+            ]
+        );
+
+        let identifier = tokens.iter().find(|t| t.lemma() == "src/main.rs").unwrap();
+        assert_eq!(&text[identifier.byte_start..identifier.byte_end], "src/main.rs");
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::Token;
+
+/// Interner sharing a single allocation between the equal lemmas,
+/// see [`TokenFilter::intern_lemmas`](super::TokenFilter::intern_lemmas).
+///
+/// The same interner can be used for several documents, so the lemmas are shared across all of them.
+#[derive(Debug, Clone, Default)]
+pub struct LemmaInterner {
+    lemmas: HashSet<Arc<str>>,
+}
+
+impl LemmaInterner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared lemma equal to the provided one, allocating it on its first occurrence.
+    pub fn intern(&mut self, lemma: &str) -> Arc<str> {
+        match self.lemmas.get(lemma) {
+            Some(interned) => interned.clone(),
+            None => {
+                let interned: Arc<str> = Arc::from(lemma);
+                self.lemmas.insert(interned.clone());
+                interned
+            }
+        }
+    }
+
+    /// Returns the number of distinct lemmas interned.
+    pub fn len(&self) -> usize {
+        self.lemmas.len()
+    }
+
+    /// Returns true if no lemma has been interned.
+    pub fn is_empty(&self) -> bool {
+        self.lemmas.is_empty()
+    }
+
+    /// Removes the lemmas which are no longer used outside of the interner.
+    pub fn shrink(&mut self) {
+        self.lemmas.retain(|lemma| Arc::strong_count(lemma) > 1);
+    }
+}
+
+/// Token whose lemma is shared with the other equal lemmas,
+/// see [`TokenFilter::intern_lemmas`](super::TokenFilter::intern_lemmas).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InternedToken<'o> {
+    pub lemma: Arc<str>,
+    /// token whose lemma has been moved to the interned lemma, leaving it empty.
+    pub token: Token<'o>,
+}
+
+/// Iterator over [`Token`]s with interned lemmas,
+/// see [`TokenFilter::intern_lemmas`](super::TokenFilter::intern_lemmas).
+pub struct InternedTokenIter<'i, I> {
+    inner: I,
+    interner: &'i mut LemmaInterner,
+}
+
+impl<'i, I> InternedTokenIter<'i, I> {
+    pub(super) fn new(inner: I, interner: &'i mut LemmaInterner) -> Self {
+        Self { inner, interner }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for InternedTokenIter<'_, I> {
+    type Item = InternedToken<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut token = self.inner.next()?;
+        let lemma = self.interner.intern(token.lemma());
+        token.lemma = Default::default();
+        Some(InternedToken { lemma, token })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::LemmaInterner;
+    use crate::filter::TokenFilter;
+    use crate::Tokenize;
+
+    #[test]
+    fn intern_lemmas() {
+        let mut interner = LemmaInterner::new();
+        let tokens: Vec<_> =
+            "The cat and the dog".tokenize().intern_lemmas(&mut interner).collect();
+        assert!(Arc::ptr_eq(&tokens[0].lemma, &tokens[6].lemma));
+        assert_eq!(&*tokens[6].lemma, "the");
+        assert_eq!(tokens[6].token.byte_start, 12);
+        assert!(tokens[6].token.lemma.is_empty());
+        // "the", "cat", "and", "dog" and the whitespace.
+        assert_eq!(interner.len(), 5);
+
+        drop(tokens);
+        interner.shrink();
+        assert!(interner.is_empty());
+    }
+}
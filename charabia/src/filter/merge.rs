@@ -0,0 +1,193 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::normalizer::identity_char_map;
+use crate::{Token, TokenKind};
+
+type TokenPredicate = Box<dyn Fn(&Token) -> bool + Send + Sync>;
+
+/// Pattern of two adjacent words to merge in a single token,
+/// see [`TokenFilter::merge_adjacent`](super::TokenFilter::merge_adjacent).
+///
+/// The words can be directly adjacent or separated by a single separator whose normalized lemma is one of the pattern separators.
+pub struct MergePattern {
+    left: TokenPredicate,
+    separators: Vec<String>,
+    right: TokenPredicate,
+}
+
+impl MergePattern {
+    /// Creates a pattern merging the words matching `left` with the following words matching `right`.
+    pub fn new(
+        left: impl Fn(&Token) -> bool + Send + Sync + 'static,
+        separators: &[&str],
+        right: impl Fn(&Token) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            left: Box::new(left),
+            separators: separators.iter().map(|s| s.to_string()).collect(),
+            right: Box::new(right),
+        }
+    }
+
+    /// Merges a number with the following unit, like "10" and "km" in "10 km".
+    ///
+    /// The units are compared with the normalized lemmas, so they must be provided normalized.
+    pub fn number_unit(units: &[&str]) -> Self {
+        let units: Vec<String> = units.iter().map(|s| s.to_string()).collect();
+        Self::new(
+            |token| token.lemma().ends_with(|c: char| c.is_ascii_digit()),
+            &[" "],
+            move |token| units.iter().any(|unit| unit == token.lemma()),
+        )
+    }
+
+    /// Merges the runs of digits split by dots, like "192.168.0.1" or "29.3°F".
+    pub fn dotted_digits() -> Self {
+        Self::new(
+            |token| token.lemma().ends_with(|c: char| c.is_ascii_digit()),
+            &["."],
+            |token| token.lemma().starts_with(|c: char| c.is_ascii_digit()),
+        )
+    }
+
+    fn matches(&self, left: &Token, separator: Option<&Token>, right: &Token) -> bool {
+        separator.is_none_or(|separator| self.separators.iter().any(|s| s == separator.lemma()))
+            && (self.left)(left)
+            && (self.right)(right)
+    }
+}
+
+impl fmt::Debug for MergePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergePattern").field("separators", &self.separators).finish_non_exhaustive()
+    }
+}
+
+/// Iterator over [`Token`]s merging the adjacent words matching a pattern,
+/// see [`TokenFilter::merge_adjacent`](super::TokenFilter::merge_adjacent).
+pub struct MergeAdjacentIter<'o, I> {
+    inner: I,
+    patterns: Vec<MergePattern>,
+    /// tokens read ahead to find the next word of a pattern.
+    lookahead: VecDeque<Token<'o>>,
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> MergeAdjacentIter<'o, I> {
+    pub(super) fn new(inner: I, patterns: Vec<MergePattern>) -> Self {
+        Self { inner, patterns, lookahead: VecDeque::new() }
+    }
+
+    /// Returns the number of tokens following `token` to merge with it, if any.
+    fn merge_len(&mut self, token: &Token<'o>) -> Option<usize> {
+        while self.lookahead.len() < 2 {
+            match self.inner.next() {
+                Some(token) => self.lookahead.push_back(token),
+                None => break,
+            }
+        }
+
+        let next = self.lookahead.front()?;
+        if !next.is_separator() {
+            self.patterns.iter().any(|p| p.matches(token, None, next)).then_some(1)
+        } else {
+            let right = self.lookahead.get(1).filter(|t| !t.is_separator())?;
+            self.patterns.iter().any(|p| p.matches(token, Some(next), right)).then_some(2)
+        }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for MergeAdjacentIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut token = self.lookahead.pop_front().or_else(|| self.inner.next())?;
+        if token.is_separator() {
+            return Some(token);
+        }
+
+        // the merged token may match a pattern again, like the next run of digits of an IP address.
+        while let Some(len) = self.merge_len(&token) {
+            let following: Vec<_> = self.lookahead.drain(..len).collect();
+            token = merge(token, following);
+        }
+
+        Some(token)
+    }
+}
+
+/// Merges the provided tokens into the first one.
+pub(super) fn merge<'o>(mut first: Token<'o>, following: Vec<Token<'o>>) -> Token<'o> {
+    let create_char_map =
+        first.char_map.is_some() || following.iter().any(|t| t.char_map.is_some());
+    if create_char_map && first.char_map.is_none() {
+        first.char_map = Some(identity_char_map(first.lemma()));
+    }
+
+    for token in following {
+        if let Some(char_map) = first.char_map.as_mut() {
+            char_map.extend(token.char_map.unwrap_or_else(|| identity_char_map(&token.lemma)));
+        }
+        if let Some(original) = first.original.as_mut() {
+            original.to_mut().push_str(token.original.as_ref().unwrap_or(&token.lemma));
+        }
+        first.lemma.to_mut().push_str(&token.lemma);
+        first.char_end = token.char_end;
+        first.byte_end = token.byte_end;
+        first.is_prefix = token.is_prefix;
+        first.guessed |= token.guessed;
+        first.cost = first.cost.zip(token.cost).map(|(first, cost)| first.saturating_add(cost));
+    }
+
+    first.kind = TokenKind::Word;
+    first
+}
+
+#[cfg(test)]
+mod test {
+    use super::MergePattern;
+    use crate::filter::TokenFilter;
+    use crate::{Tokenize, TokenizerBuilder};
+
+    #[test]
+    fn merge_adjacent() {
+        let text = "It's 29.3°F, 10 km from 192.168.0.1 and 3 apples";
+        let patterns = vec![MergePattern::dotted_digits(), MergePattern::number_unit(&["km"])];
+        let words: Vec<_> = text
+            .tokenize()
+            .merge_adjacent(patterns)
+            .filter(|t| t.is_word())
+            .map(|t| (t.lemma.into_owned(), t.byte_start, t.byte_end))
+            .collect();
+        assert_eq!(
+            words,
+            [
+                ("it".to_string(), 0, 2),
+                ("s".to_string(), 3, 4),
+                ("29.3°f".to_string(), 5, 12),
+                ("10 km".to_string(), 14, 19),
+                ("from".to_string(), 20, 24),
+                ("192.168.0.1".to_string(), 25, 36),
+                ("and".to_string(), 37, 40),
+                ("3".to_string(), 41, 42),
+                ("apples".to_string(), 43, 49),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_adjacent_char_map() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true).keep_original(true);
+        let tokenizer = builder.build();
+
+        let text = "1.５ km";
+        let tokens: Vec<_> =
+            tokenizer.tokenize(text).merge_adjacent(vec![MergePattern::dotted_digits()]).collect();
+        let merged = &tokens[0];
+        assert_eq!(merged.lemma(), "1.5");
+        assert_eq!(merged.original_lemma(), Some("1.５"));
+        assert_eq!(merged.char_map, Some(vec![(1, 1), (1, 1), (3, 1)]));
+        assert_eq!(merged.validate(text), Ok(()));
+    }
+}
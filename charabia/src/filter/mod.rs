@@ -0,0 +1,516 @@
+//! Filters applied on the normalized [`Token`]s, adding or removing tokens in the stream,
+//! and adapters grouping them, like the words with their context.
+//!
+//! The filters are opt-in and can be chained on any iterator of tokens,
+//! like the one returned by [`crate::Tokenizer::tokenize`].
+
+pub use self::boundary::BoundaryIter;
+#[cfg(feature = "compound")]
+pub use self::compound::CompoundSplitIter;
+pub use self::date_time::DateTimeIter;
+pub use self::dedup::DedupOverlappingIter;
+pub use self::edge_ngram::EdgeNgramIter;
+pub use self::hangul::HangulJoinIter;
+pub use self::identifier::CodeIdentifierIter;
+pub use self::intern::{InternedToken, InternedTokenIter, LemmaInterner};
+pub use self::merge::{MergeAdjacentIter, MergePattern};
+pub use self::number::{CanonicalNumberIter, DecimalSeparator};
+pub use self::ordinal::OrdinalIter;
+pub use self::phone::PhoneNumberIter;
+pub use self::shingle::ShingleIter;
+pub use self::social::SocialTagIter;
+pub use self::synonym::{SynonymIter, Synonyms};
+pub use self::unit::UnitIter;
+pub use self::window::{WordWindow, WordWindowIter};
+use crate::{Language, Script, Token};
+
+mod boundary;
+#[cfg(feature = "compound")]
+mod compound;
+mod date_time;
+mod dedup;
+mod edge_ngram;
+mod hangul;
+mod identifier;
+mod intern;
+mod merge;
+mod number;
+mod ordinal;
+mod phone;
+mod shingle;
+mod social;
+mod synonym;
+mod unit;
+mod window;
+
+/// Trait defining the filters that can be applied on an iterator of [`Token`]s.
+pub trait TokenFilter<'o>: Iterator<Item = Token<'o>> + Sized {
+    /// Emits word n-grams, called shingles, in addition to the tokens.
+    ///
+    /// For each word, the shingles of 2 up to `max_size` words ending with this word are emitted right after it,
+    /// their lemma being the normalized words joined by `separator`
+    /// and their offsets covering the whole shingle in the original text.
+    /// A shingle never crosses a hard separator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::Tokenize;
+    ///
+    /// let shingles: Vec<_> = "New York city. Big apple"
+    ///     .tokenize()
+    ///     .shingles(3, " ")
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(
+    ///     shingles,
+    ///     ["new", "york", "new york", "city", "york city", "new york city", "big", "apple", "big apple"]
+    /// );
+    /// ```
+    fn shingles(self, max_size: usize, separator: impl Into<String>) -> ShingleIter<'o, Self> {
+        ShingleIter::new(self, max_size, separator.into())
+    }
+
+    /// Emits the prefixes of each word, called edge n-grams, in addition to the tokens.
+    ///
+    /// For each word, the prefixes of `min_len` up to `max_len` characters of the normalized lemma
+    /// that are shorter than the word are emitted right after it,
+    /// the combining marks, like the vowel signs of the Indic scripts, being kept with their base character,
+    /// their offsets covering the part of the original text they come from,
+    /// which requires the `char_map` if the normalization changed the length of the lemma.
+    /// This allows building autocomplete indexes directly from the tokens.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::Tokenize;
+    ///
+    /// let prefixes: Vec<_> = "Hello".tokenize().edge_ngrams(1, 3).map(|t| t.lemma).collect();
+    /// assert_eq!(prefixes, ["hello", "h", "he", "hel"]);
+    /// ```
+    fn edge_ngrams(self, min_len: usize, max_len: usize) -> EdgeNgramIter<'o, Self> {
+        EdgeNgramIter::new(self, min_len, max_len)
+    }
+
+    /// Removes the tokens of the provided scripts that are fully contained in an other token.
+    ///
+    /// This is useful for indexes that don't want both the sub-words and the whole words of a segmentation for search,
+    /// only the longest of the overlapping tokens are kept, in their original order.
+    /// The tokens covering exactly the same part of the original text are deduplicated, keeping the first one.
+    /// Because the overlapping tokens may not be consecutive, the tokens are buffered up to the next separator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::{Script, Tokenize};
+    ///
+    /// let tokens: Vec<_> = "Hello"
+    ///     .tokenize()
+    ///     .edge_ngrams(1, 3)
+    ///     .dedup_overlapping(&[Script::Latin])
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(tokens, ["hello"]);
+    /// ```
+    fn dedup_overlapping(self, scripts: &[Script]) -> DedupOverlappingIter<'o, Self> {
+        DedupOverlappingIter::new(self, scripts)
+    }
+
+    /// Merges the adjacent words matching one of the provided patterns in a single word.
+    ///
+    /// Two words are merged when they are directly adjacent or separated by one of the separators of a pattern,
+    /// the merged token covering both words and the separator in the original text, its `char_map` included.
+    /// A merged token can be merged again with the following words,
+    /// which allows consumers to get coarser tokens, like numbers with their unit, for specific patterns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::{MergePattern, TokenFilter};
+    /// use charabia::Tokenize;
+    ///
+    /// let words: Vec<_> = "It's 29.3°F"
+    ///     .tokenize()
+    ///     .merge_adjacent(vec![MergePattern::dotted_digits()])
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(words, ["it", "s", "29.3°f"]);
+    /// ```
+    fn merge_adjacent(self, patterns: Vec<MergePattern>) -> MergeAdjacentIter<'o, Self> {
+        MergeAdjacentIter::new(self, patterns)
+    }
+
+    /// Merges the numbers split by their grouping and decimal separators in a single word with a canonical lemma.
+    ///
+    /// The grouping separators are removed and the decimal separator is replaced by a point,
+    /// so "1,000.5" and "1000.5" give the same lemma, the grouping separator being the other one of "." and ","
+    /// depending on the provided [`DecimalSeparator`].
+    /// The groups of digits following a grouping separator must be made of 3 digits,
+    /// otherwise the number is not merged further.
+    /// The merged token covers the whole number in the original text, its `char_map` included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::{DecimalSeparator, TokenFilter};
+    /// use charabia::Tokenize;
+    ///
+    /// let words: Vec<_> = "1,000.5 or 1000.5"
+    ///     .tokenize()
+    ///     .canonical_numbers(DecimalSeparator::Point)
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(words, ["1000.5", "or", "1000.5"]);
+    /// ```
+    fn canonical_numbers(
+        self,
+        decimal_separator: DecimalSeparator,
+    ) -> CanonicalNumberIter<'o, Self> {
+        CanonicalNumberIter::new(self, decimal_separator)
+    }
+
+    /// Keeps the ordinals, like "1st", "2ème" or "3.", in single tokens whose lemma is the number alone.
+    ///
+    /// The suffixes of the ordinals depend on the provided `language`:
+    /// "st", "nd", "rd" and "th" in English, "er", "e" or "ème" in French, "º" and "ª" in Spanish, Portuguese and Italian,
+    /// and a trailing dot, not followed by digits, in German and the other languages writing ordinals this way.
+    /// When the language is unknown, all the suffixes are recognized but not the ambiguous trailing dot.
+    /// The offsets of the ordinal still cover its suffix in the original text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::{Language, Tokenize};
+    ///
+    /// let lemmas: Vec<_> = "the 21st century"
+    ///     .tokenize()
+    ///     .recognize_ordinals(Some(Language::Eng))
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(lemmas, ["the", "21", "century"]);
+    /// ```
+    fn recognize_ordinals(self, language: Option<Language>) -> OrdinalIter<'o, Self> {
+        OrdinalIter::new(self, language)
+    }
+
+    /// Merges the dates and the times in single tokens of kind [`TokenKind::DateTime`](crate::TokenKind::DateTime)
+    /// instead of splitting them into numbers and separators.
+    ///
+    /// The recognized formats are the year-month-day dates, like "2024-05-01",
+    /// the day-month-year and month-day-year dates, like "01/05/2024" or "1.5.2024",
+    /// and the times, like "10:30", "10:30:15", "3pm" or "10:30 pm".
+    /// The lemma of a merged token is made of the normalized lemmas of its parts, separators included,
+    /// and its offsets cover the whole date or time in the original text, its `char_map` included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::Tokenize;
+    ///
+    /// let date_times: Vec<_> = "See you on 2024-05-01 at 3pm"
+    ///     .tokenize()
+    ///     .recognize_date_times()
+    ///     .filter(|t| t.is_date_time())
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(date_times, ["2024-05-01", "3pm"]);
+    /// ```
+    fn recognize_date_times(self) -> DateTimeIter<'o, Self> {
+        DateTimeIter::new(self)
+    }
+
+    /// Emits the canonical digits of the phone numbers in addition to their parts, so they can be looked up as a whole.
+    ///
+    /// A phone number is a run of groups of digits separated by spaces, dashes, dots, slashes or parentheses,
+    /// like "+33 6 12 34 56 78" or "(555) 123-4567", having between 9 and 15 digits.
+    /// The phone number is emitted right after its last group of digits, its lemma being the digits only,
+    /// without the separators and the leading "+", and its offsets covering the digits in the original text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::Tokenize;
+    ///
+    /// let words: Vec<_> = "06 12 34 56 78"
+    ///     .tokenize()
+    ///     .recognize_phone_numbers()
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(words, ["06", "12", "34", "56", "78", "0612345678"]);
+    /// ```
+    fn recognize_phone_numbers(self) -> PhoneNumberIter<'o, Self> {
+        PhoneNumberIter::new(self)
+    }
+
+    /// Emits the Hangul words separated by whitespaces joined together, in addition to the tokens.
+    ///
+    /// Spacing mistakes are common in Korean, like "아버지가 방에" written "아버지 가방에",
+    /// so joining the adjacent words of a query allows matching the documents spaced differently.
+    /// The joined word is emitted right after its second word, its offsets covering both words and the whitespaces between them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::Tokenize;
+    ///
+    /// let text = "서울 부산";
+    /// let words: Vec<_> = text
+    ///     .tokenize()
+    ///     .join_hangul_words()
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| &text[t.byte_start..t.byte_end])
+    ///     .collect();
+    /// assert_eq!(words, ["서울", "부산", "서울 부산"]);
+    /// ```
+    fn join_hangul_words(self) -> HangulJoinIter<'o, Self> {
+        HangulJoinIter::new(self)
+    }
+
+    /// Classifies the currency symbols, like "$" or "€", as [`TokenKind::Currency`](crate::TokenKind::Currency),
+    /// and the unit symbols following a number, like "%", "°F" or "km²", as [`TokenKind::Unit`](crate::TokenKind::Unit).
+    ///
+    /// The symbols written against their number in a single word, like "$10" or "20€", are split from it.
+    /// When `attach` is true, the symbols are instead kept attached to their number in a single token of the symbol kind,
+    /// the number and its unit being possibly separated by a whitespace, like "3 km".
+    /// The units are compared with the normalized lemmas, so "km²" is recognized as "km2".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::{TokenKind, Tokenize};
+    ///
+    /// let tokens: Vec<_> = "$10 or 20%"
+    ///     .tokenize()
+    ///     .classify_units(true)
+    ///     .filter(|t| !t.is_separator())
+    ///     .map(|t| (t.lemma, t.kind))
+    ///     .collect();
+    /// assert_eq!(
+    ///     tokens,
+    ///     [("$10".into(), TokenKind::Currency), ("or".into(), TokenKind::Word), ("20%".into(), TokenKind::Unit)]
+    /// );
+    /// ```
+    fn classify_units(self, attach: bool) -> UnitIter<'o, Self> {
+        UnitIter::new(self, attach)
+    }
+
+    /// Emits the code identifiers made of several words in addition to their parts, for code and log search.
+    ///
+    /// The words of snake_case, kebab-case or camelCase identifiers, file paths and namespaced identifiers,
+    /// like "foo::bar" or "a.b.c", are already split by the segmentation,
+    /// this filter joins back the adjacent words and the connectors between them, like "_", "-", "::", "." or "/".
+    /// The identifier is emitted right after its last word, its lemma being the normalized words and connectors
+    /// and its offsets covering the whole identifier in the original text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::Tokenize;
+    ///
+    /// let words: Vec<_> = "std::mem::take(snake_case)"
+    ///     .tokenize()
+    ///     .code_identifiers()
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(words, ["std", "mem", "take", "std::mem::take", "snake", "case", "snake_case"]);
+    /// ```
+    fn code_identifiers(self) -> CodeIdentifierIter<'o, Self> {
+        CodeIdentifierIter::new(self)
+    }
+
+    /// Emits the hashtags and the mentions, like "#HelloWorld" or "@john_doe", in addition to their words,
+    /// so both the exact tag and its words can be searched.
+    ///
+    /// A tag is a "#" or "@" sigil, which doesn't directly follow a word like in an email address,
+    /// followed by adjacent words, possibly joined by underscores.
+    /// Its words are already split by the segmentation, like the camelCase parts of "HelloWorld",
+    /// this filter emits the tag right after its last word, as a [`TokenKind::Hashtag`](crate::TokenKind::Hashtag)
+    /// or a [`TokenKind::Mention`](crate::TokenKind::Mention) whose offsets cover the whole tag.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::{TokenKind, Tokenize};
+    ///
+    /// let tokens: Vec<_> = "#HelloWorld @john_doe"
+    ///     .tokenize()
+    ///     .recognize_social_tags()
+    ///     .filter(|t| !t.is_separator())
+    ///     .map(|t| (t.lemma, t.kind))
+    ///     .collect();
+    /// assert_eq!(
+    ///     tokens,
+    ///     [
+    ///         ("hello".into(), TokenKind::Word),
+    ///         ("world".into(), TokenKind::Word),
+    ///         ("#helloworld".into(), TokenKind::Hashtag),
+    ///         ("john".into(), TokenKind::Word),
+    ///         ("doe".into(), TokenKind::Word),
+    ///         ("@john_doe".into(), TokenKind::Mention),
+    ///     ]
+    /// );
+    /// ```
+    fn recognize_social_tags(self) -> SocialTagIter<'o, Self> {
+        SocialTagIter::new(self)
+    }
+
+    /// Emits the parts of the compound words right after them, like "fotboll" and "match" for "fotbollsmatch".
+    ///
+    /// A word is split when it's made of several words of the `dictionary`, of 3 characters at least,
+    /// possibly joined by the linking morphemes of its language, like the "s" in Swedish.
+    /// The dictionary contains normalized lemmas, and the longest parts are preferred,
+    /// so a known compound like "fotboll" isn't split further.
+    /// Only the words detected as one of the languages enabled by the `compound-dutch`, `compound-finnish`,
+    /// and `compound-swedish` features are split, their parts covering their own range of the original text,
+    /// which requires the `char_map` if the normalization changed the length of the lemma.
+    /// The stemmed words are not split, a stem like "stationsstrat" not being made of the words of the dictionary,
+    /// so the stemming must stay disabled, see [`TokenizerBuilder::stemming`](crate::TokenizerBuilder::stemming).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "compound-swedish")] {
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::{AllowList, Language, TokenizerBuilder};
+    /// use fst::Set;
+    ///
+    /// let dictionary = Set::from_iter(["boll", "fot", "fotboll", "match"]).unwrap();
+    /// let allow_list = AllowList::new().languages([Language::Swe]);
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.allow_list(&allow_list);
+    /// let tokenizer = builder.build();
+    ///
+    /// let words: Vec<_> = tokenizer
+    ///     .tokenize("Fotbollsmatch")
+    ///     .split_compounds(&dictionary)
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(words, ["fotbollsmatch", "fotboll", "match"]);
+    /// # }
+    /// ```
+    #[cfg(feature = "compound")]
+    fn split_compounds<A: AsRef<[u8]>>(
+        self,
+        dictionary: &fst::Set<A>,
+    ) -> CompoundSplitIter<'o, '_, Self, A> {
+        CompoundSplitIter::new(self, dictionary)
+    }
+
+    /// Emits the synonyms of the words right after them, at the same position.
+    ///
+    /// The words of a synonym share the offsets of their original word
+    /// and have [`Token::synonym`] set to their index in the synonym and the number of words of the synonym,
+    /// so a synonym made of several words, like "new york" for "nyc", forms a graph with the original token.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::{Synonyms, TokenFilter};
+    /// use charabia::Tokenize;
+    ///
+    /// let mut synonyms = Synonyms::new();
+    /// synonyms.insert("nyc", "new york");
+    ///
+    /// let words: Vec<_> = "NYC subway"
+    ///     .tokenize()
+    ///     .synonyms(&synonyms)
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| (t.lemma, t.byte_start))
+    ///     .collect();
+    /// assert_eq!(words, [("nyc".into(), 0), ("new".into(), 0), ("york".into(), 0), ("subway".into(), 4)]);
+    /// ```
+    fn synonyms(self, synonyms: &Synonyms) -> SynonymIter<'o, '_, Self> {
+        SynonymIter::new(self, synonyms)
+    }
+
+    /// Yields each word with up to `size` previous and `size` next words, skipping the separators.
+    ///
+    /// The tokens keep their offsets in the original text, so the window can be used to build snippets
+    /// or keyword-in-context displays, see [`WordWindow::byte_range`].
+    /// The stop words are considered as words to keep the context readable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::Tokenize;
+    ///
+    /// let text = "The quick brown fox";
+    /// let window = text.tokenize().word_windows(1).nth(2).unwrap();
+    /// assert_eq!(window.word.lemma(), "brown");
+    /// assert_eq!(&text[window.byte_range()], "quick brown fox");
+    /// ```
+    fn word_windows(self, size: usize) -> WordWindowIter<'o, Self> {
+        WordWindowIter::new(self, size)
+    }
+
+    /// Marks the separators ending a sentence or a paragraph with [`Token::boundary`],
+    /// so snippets and proximity scores can respect the boundaries without parsing the original text again.
+    ///
+    /// A sentence ends with a separator containing a terminal punctuation, like ".", "!", "?" or "。",
+    /// which is followed by a whitespace or ends the text, so "3.5" or "a.b" don't end a sentence;
+    /// the Chinese and Japanese punctuations always end a sentence.
+    /// A paragraph ends with the separator completing a blank line,
+    /// like the second "\n" of "\n\n", or with a paragraph separator "\u{2029}".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::filter::TokenFilter;
+    /// use charabia::{Boundary, Tokenize};
+    ///
+    /// let boundaries: Vec<_> = "Hello world. Version 3.5!\n\nBye"
+    ///     .tokenize()
+    ///     .mark_boundaries()
+    ///     .filter_map(|t| Some((t.byte_start, t.boundary?)))
+    ///     .collect();
+    /// assert_eq!(boundaries, [(11, Boundary::Sentence), (24, Boundary::Sentence), (26, Boundary::Paragraph)]);
+    /// ```
+    fn mark_boundaries(self) -> BoundaryIter<'o, Self> {
+        BoundaryIter::new(self)
+    }
+
+    /// Yields the tokens with their lemma interned, the equal lemmas sharing a single allocation,
+    /// which reduces the memory used by large token streams made of many repeated words.
+    ///
+    /// The lemma of each token is moved to [`InternedToken::lemma`], leaving the lemma of the token empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use charabia::filter::{LemmaInterner, TokenFilter};
+    /// use charabia::Tokenize;
+    ///
+    /// let mut interner = LemmaInterner::new();
+    /// let words: Vec<_> = "the cat and the dog"
+    ///     .tokenize()
+    ///     .intern_lemmas(&mut interner)
+    ///     .filter(|t| t.token.is_word())
+    ///     .collect();
+    /// assert_eq!(&*words[3].lemma, "the");
+    /// assert!(Arc::ptr_eq(&words[0].lemma, &words[3].lemma));
+    /// ```
+    fn intern_lemmas(self, interner: &mut LemmaInterner) -> InternedTokenIter<'_, Self> {
+        InternedTokenIter::new(self, interner)
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> TokenFilter<'o> for I {}
@@ -0,0 +1,194 @@
+use std::collections::VecDeque;
+
+use crate::normalizer::identity_char_map;
+use crate::{Language, Token, TokenKind};
+
+/// Decimal separator of the numbers, the other one of "." and "," being the grouping separator,
+/// see [`TokenFilter::canonical_numbers`](super::TokenFilter::canonical_numbers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalSeparator {
+    /// "1,000.5", the English notation.
+    Point,
+    /// "1.000,5", the notation of most of the continental European languages.
+    Comma,
+}
+
+impl DecimalSeparator {
+    /// Returns the decimal separator usually used in the provided language, [`DecimalSeparator::Point`] if unknown.
+    pub fn from_language(language: Option<Language>) -> Self {
+        use Language::*;
+
+        match language {
+            Some(
+                Deu | Fra | Spa | Por | Ita | Rus | Ukr | Pol | Nld | Dan | Nob | Swe | Fin | Tur
+                | Hun | Ces | Ell | Bul | Bel | Ron | Slv | Hrv | Srp | Mkd | Lit | Lav | Est | Ind
+                | Vie | Aze | Uzb,
+            ) => Self::Comma,
+            _ => Self::Point,
+        }
+    }
+
+    fn decimal(&self) -> &'static str {
+        match self {
+            Self::Point => ".",
+            Self::Comma => ",",
+        }
+    }
+
+    fn grouping(&self) -> &'static str {
+        match self {
+            Self::Point => ",",
+            Self::Comma => ".",
+        }
+    }
+}
+
+/// Iterator over [`Token`]s merging the numbers split by their grouping and decimal separators,
+/// see [`TokenFilter::canonical_numbers`](super::TokenFilter::canonical_numbers).
+pub struct CanonicalNumberIter<'o, I> {
+    inner: I,
+    decimal_separator: DecimalSeparator,
+    /// tokens read ahead to find the next part of a number.
+    lookahead: VecDeque<Token<'o>>,
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> CanonicalNumberIter<'o, I> {
+    pub(super) fn new(inner: I, decimal_separator: DecimalSeparator) -> Self {
+        Self { inner, decimal_separator, lookahead: VecDeque::new() }
+    }
+
+    /// Returns the next separator and digits if they are adjacent to the number.
+    fn next_part(&mut self, number: &Token<'o>) -> Option<(&Token<'o>, &Token<'o>)> {
+        while self.lookahead.len() < 2 {
+            match self.inner.next() {
+                Some(token) => self.lookahead.push_back(token),
+                None => break,
+            }
+        }
+
+        let separator = self.lookahead.front().filter(|t| t.is_separator())?;
+        let digits = self.lookahead.get(1).filter(|t| is_digits(t))?;
+        let adjacent =
+            number.byte_end == separator.byte_start && separator.byte_end == digits.byte_start;
+        adjacent.then_some((separator, digits))
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for CanonicalNumberIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut number = self.lookahead.pop_front().or_else(|| self.inner.next())?;
+        if !is_digits(&number) {
+            return Some(number);
+        }
+
+        // the groups of digits can only follow a leading group of at most 3 digits, and are never decimals.
+        let mut grouping = number.lemma().len() <= 3;
+        let mut decimal = false;
+        let (grouping_separator, decimal_separator) =
+            (self.decimal_separator.grouping(), self.decimal_separator.decimal());
+        while let Some((separator, digits)) = self.next_part(&number) {
+            let is_group = separator.lemma() == grouping_separator
+                && grouping
+                && !decimal
+                && digits.lemma().len() == 3;
+            let is_decimal = separator.lemma() == decimal_separator && !decimal;
+            if !is_group && !is_decimal {
+                break;
+            }
+
+            grouping &= is_group;
+            decimal |= is_decimal;
+            let separator = self.lookahead.pop_front().unwrap();
+            let digits = self.lookahead.pop_front().unwrap();
+            number = merge(number, separator, is_decimal, digits);
+        }
+
+        Some(number)
+    }
+}
+
+fn is_digits(token: &Token) -> bool {
+    !token.is_separator()
+        && !token.lemma().is_empty()
+        && token.lemma().bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Merges the digits into the number, the separator being removed or replaced by a decimal point.
+fn merge<'o>(
+    mut number: Token<'o>,
+    separator: Token<'o>,
+    is_decimal: bool,
+    digits: Token<'o>,
+) -> Token<'o> {
+    let create_char_map = [&number, &separator, &digits].iter().any(|t| t.char_map.is_some());
+    if create_char_map && number.char_map.is_none() {
+        number.char_map = Some(identity_char_map(number.lemma()));
+    }
+
+    let lemma = number.lemma.to_mut();
+    if is_decimal {
+        lemma.push('.');
+    }
+    lemma.push_str(&digits.lemma);
+
+    if let Some(char_map) = number.char_map.as_mut() {
+        let separator_len = (separator.byte_end - separator.byte_start) as u8;
+        char_map.push((separator_len, if is_decimal { 1 } else { 0 }));
+        char_map.extend(digits.char_map.unwrap_or_else(|| identity_char_map(&digits.lemma)));
+    }
+
+    number.char_end = digits.char_end;
+    number.byte_end = digits.byte_end;
+    number.is_prefix = digits.is_prefix;
+    number.kind = TokenKind::Word;
+    number
+}
+
+#[cfg(test)]
+mod test {
+    use super::DecimalSeparator;
+    use crate::filter::TokenFilter;
+    use crate::{Tokenize, TokenizerBuilder};
+
+    fn numbers(text: &str, decimal_separator: DecimalSeparator) -> Vec<String> {
+        text.tokenize()
+            .canonical_numbers(decimal_separator)
+            .filter(|t| t.is_word())
+            .map(|t| t.lemma.into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn canonical_numbers() {
+        let text = "1,000.5 1000.5 12,345,678 1,23 1234,567 3.14.15";
+        assert_eq!(
+            numbers(text, DecimalSeparator::Point),
+            ["1000.5", "1000.5", "12345678", "1", "23", "1234", "567", "3.14", "15"]
+        );
+
+        let text = "1.000,5 1000,5 12.345.678 1.23";
+        assert_eq!(
+            numbers(text, DecimalSeparator::Comma),
+            ["1000.5", "1000.5", "12345678", "1", "23"]
+        );
+    }
+
+    #[test]
+    fn canonical_numbers_char_map() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+
+        // the fullwidth comma is normalized, so it has a char_map.
+        let text = "1，000.5 km";
+        let tokens: Vec<_> =
+            tokenizer.tokenize(text).canonical_numbers(DecimalSeparator::Point).collect();
+        let number = &tokens[0];
+        assert_eq!(number.lemma(), "1000.5");
+        assert_eq!(&text[number.byte_start..number.byte_end], "1，000.5");
+        assert_eq!(number.original_lengths(4), (5, 7));
+        assert_eq!(number.validate(text), Ok(()));
+    }
+}
@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use crate::{Language, Token, TokenKind};
+
+/// Normalized ordinal suffixes of the English, French, Spanish, Portuguese and Italian languages.
+const ENGLISH_SUFFIXES: &[&str] = &["st", "nd", "rd", "th"];
+const FRENCH_SUFFIXES: &[&str] = &["er", "re", "ere", "e", "eme", "nd", "nde"];
+const ROMANCE_SUFFIXES: &[&str] = &["o", "a", "os", "as"];
+
+/// Iterator over [`Token`]s keeping the ordinals in single tokens with a numeric lemma,
+/// see [`TokenFilter::recognize_ordinals`](super::TokenFilter::recognize_ordinals).
+pub struct OrdinalIter<'o, I> {
+    inner: I,
+    /// normalized suffixes following the digits of an ordinal.
+    suffixes: Vec<&'static str>,
+    /// if the ordinals are written with a trailing dot, like "3." in German.
+    trailing_dot: bool,
+    /// tokens read ahead to find the trailing dot of an ordinal.
+    lookahead: VecDeque<Token<'o>>,
+}
+
+impl<'o, I> OrdinalIter<'o, I> {
+    pub(super) fn new(inner: I, language: Option<Language>) -> Self {
+        use Language::*;
+
+        let (suffixes, trailing_dot) = match language {
+            Some(Eng) => (ENGLISH_SUFFIXES.to_vec(), false),
+            Some(Fra) => (FRENCH_SUFFIXES.to_vec(), false),
+            Some(Spa | Por | Ita) => (ROMANCE_SUFFIXES.to_vec(), false),
+            Some(
+                Deu | Dan | Nob | Fin | Ces | Slk | Pol | Hun | Hrv | Srp | Slv | Est | Lav | Tur,
+            ) => (Vec::new(), true),
+            Some(_) => (Vec::new(), false),
+            None => {
+                let suffixes = [ENGLISH_SUFFIXES, FRENCH_SUFFIXES, ROMANCE_SUFFIXES].concat();
+                (suffixes, false)
+            }
+        };
+
+        Self { inner, suffixes, trailing_dot, lookahead: VecDeque::new() }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> OrdinalIter<'o, I> {
+    /// Returns true if the number is followed by an adjacent dot which is not followed by more digits.
+    fn has_trailing_dot(&mut self, number: &Token<'o>) -> bool {
+        while self.lookahead.len() < 2 {
+            match self.inner.next() {
+                Some(token) => self.lookahead.push_back(token),
+                None => break,
+            }
+        }
+
+        let Some(dot) = self.lookahead.front() else { return false };
+        let followed_by_digits = self.lookahead.get(1).is_some_and(|next| {
+            next.byte_start == dot.byte_end
+                && next.lemma().starts_with(|c: char| c.is_ascii_digit())
+        });
+        let is_dot = dot.lemma().strip_prefix('.').is_some_and(|rest| rest.trim().is_empty());
+        is_dot && dot.byte_start == number.byte_end && !followed_by_digits
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for OrdinalIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut token = self.lookahead.pop_front().or_else(|| self.inner.next())?;
+        if token.is_separator() {
+            return Some(token);
+        }
+
+        let digits_len = token.lemma().bytes().take_while(u8::is_ascii_digit).count();
+        if digits_len == 0 {
+            return Some(token);
+        }
+
+        let suffix = &token.lemma()[digits_len..];
+        if !suffix.is_empty() && self.suffixes.contains(&suffix) {
+            // the suffix is kept in the offsets of the token but removed from its lemma.
+            if let Some(char_map) = token.char_map.as_mut() {
+                let mut normalized_len = 0;
+                for (_, normalized) in char_map.iter_mut() {
+                    if normalized_len >= digits_len {
+                        *normalized = 0;
+                    }
+                    normalized_len += *normalized as usize;
+                }
+            }
+            token.lemma.to_mut().truncate(digits_len);
+        } else if suffix.is_empty() && self.trailing_dot && self.has_trailing_dot(&token) {
+            // the dot is removed from the separator, which may also contain the following whitespaces.
+            let separator = self.lookahead.front_mut().unwrap();
+            if let Some(char_map) = token.char_map.as_mut() {
+                char_map.push((1, 0));
+            }
+            token.char_end += 1;
+            token.byte_end += 1;
+            if separator.lemma() == "." {
+                token.is_prefix = separator.is_prefix;
+                self.lookahead.pop_front();
+            } else {
+                separator.lemma = match &separator.lemma {
+                    Cow::Borrowed(lemma) => Cow::Borrowed(&lemma[1..]),
+                    Cow::Owned(lemma) => Cow::Owned(lemma[1..].to_string()),
+                };
+                if let Some(char_map) = separator.char_map.as_mut() {
+                    char_map.remove(0);
+                }
+                separator.char_start += 1;
+                separator.byte_start += 1;
+            }
+            token.kind = TokenKind::Word;
+        }
+
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::TokenFilter;
+    use crate::{Language, Tokenize};
+
+    fn ordinals(text: &str, language: Option<Language>) -> Vec<(String, &str)> {
+        text.tokenize()
+            .recognize_ordinals(language)
+            .filter(|t| t.is_word())
+            .map(|t| (t.lemma.into_owned(), &text[t.byte_start..t.byte_end]))
+            .collect()
+    }
+
+    #[test]
+    fn recognize_ordinals() {
+        assert_eq!(
+            ordinals("the 1st and 22nd, not 3x", Some(Language::Eng)),
+            [
+                ("the".to_string(), "the"),
+                ("1".to_string(), "1st"),
+                ("and".to_string(), "and"),
+                ("22".to_string(), "22nd"),
+                ("not".to_string(), "not"),
+                ("3x".to_string(), "3x"),
+            ]
+        );
+        assert_eq!(
+            ordinals("le 2ème", Some(Language::Fra)),
+            [("le".to_string(), "le"), ("2".to_string(), "2ème")]
+        );
+        assert_eq!(
+            ordinals("am 3. Mai, 3.5 kg", Some(Language::Deu)),
+            [
+                ("am".to_string(), "am"),
+                ("3".to_string(), "3."),
+                ("mai".to_string(), "Mai"),
+                ("3".to_string(), "3"),
+                ("5".to_string(), "5"),
+                ("kg".to_string(), "kg"),
+            ]
+        );
+        // the suffixes are language specific.
+        assert_eq!(ordinals("1st", Some(Language::Deu)), [("1st".to_string(), "1st")]);
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::VecDeque;
+
+use super::shingle::shingle;
+use crate::Token;
+
+/// Minimum and maximum number of digits of a phone number, the maximum being the one of the E.164 numbers.
+const MIN_DIGITS: usize = 9;
+const MAX_DIGITS: usize = 15;
+/// Maximum number of separators between two groups of digits, like ")" and " " in "(555) 123".
+const MAX_SEPARATORS: usize = 2;
+
+/// Iterator over [`Token`]s and the canonical digits of the phone numbers,
+/// see [`TokenFilter::recognize_phone_numbers`](super::TokenFilter::recognize_phone_numbers).
+pub struct PhoneNumberIter<'o, I> {
+    inner: I,
+    /// groups of digits of the current phone number and the separators between them.
+    window: Vec<Token<'o>>,
+    /// tokens read ahead to find the end of the current phone number.
+    lookahead: VecDeque<Token<'o>>,
+    /// phone number to emit before reading the next token.
+    pending: Option<Token<'o>>,
+}
+
+impl<'o, I> PhoneNumberIter<'o, I> {
+    pub(super) fn new(inner: I) -> Self {
+        Self { inner, window: Vec::new(), lookahead: VecDeque::new(), pending: None }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> PhoneNumberIter<'o, I> {
+    /// Returns true if the phone number doesn't continue after the provided digits,
+    /// the next digits not being separated from them by adjacent phone separators only.
+    fn ends_after(&mut self, digits: &Token<'o>) -> bool {
+        let mut byte_end = digits.byte_end;
+        for i in 0..=MAX_SEPARATORS {
+            if self.lookahead.len() <= i {
+                match self.inner.next() {
+                    Some(token) => self.lookahead.push_back(token),
+                    None => return true,
+                }
+            }
+
+            let next = &self.lookahead[i];
+            if next.byte_start != byte_end {
+                return true;
+            } else if i > 0 && is_digits(next) {
+                return false;
+            } else if !is_phone_separator(next) {
+                return true;
+            }
+            byte_end = next.byte_end;
+        }
+
+        true
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for PhoneNumberIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(phone_number) = self.pending.take() {
+            return Some(phone_number);
+        }
+
+        let token = self.lookahead.pop_front().or_else(|| self.inner.next())?;
+        let continues = self.window.last().is_some_and(|last| {
+            last.byte_end == token.byte_start
+                && (is_phone_separator(&token) || (last.is_separator() && is_digits(&token)))
+        });
+        if !continues {
+            self.window.clear();
+        }
+        if continues || is_digits(&token) || is_international(&token) {
+            self.window.push(token.clone());
+        }
+
+        if (is_digits(&token) || is_international(&token)) && self.ends_after(&token) {
+            let window = std::mem::take(&mut self.window);
+            let groups = window.iter().filter(|t| !t.is_separator()).count();
+            let digits: usize = window
+                .iter()
+                .filter(|t| !t.is_separator())
+                .map(|t| t.lemma().trim_start_matches('+').len())
+                .sum();
+            if groups > 1 && (MIN_DIGITS..=MAX_DIGITS).contains(&digits) {
+                // the separators are removed, so the lemma is made of the digits only.
+                let mut phone_number = shingle(&window, "");
+                if phone_number.lemma().starts_with('+') {
+                    phone_number.lemma.to_mut().remove(0);
+                    if let Some((_, normalized_len)) =
+                        phone_number.char_map.as_mut().and_then(|char_map| char_map.first_mut())
+                    {
+                        *normalized_len = 0;
+                    }
+                }
+                self.pending = Some(phone_number);
+            }
+        }
+
+        Some(token)
+    }
+}
+
+fn is_digits(token: &Token) -> bool {
+    !token.is_separator()
+        && !token.lemma().is_empty()
+        && token.lemma().bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Returns true for the first group of digits of an international phone number, like "+33".
+fn is_international(token: &Token) -> bool {
+    !token.is_separator()
+        && token
+            .lemma()
+            .strip_prefix('+')
+            .is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Returns true for the separators between the groups of digits of a phone number,
+/// like in "06 12 34 56 78", "555-123-4567" or "(555) 123.4567".
+fn is_phone_separator(token: &Token) -> bool {
+    token.is_separator()
+        && token.lemma().len() <= 3
+        && token.lemma().chars().all(|c| matches!(c, ' ' | '-' | '.' | '/' | '(' | ')'))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::TokenFilter;
+    use crate::Tokenize;
+
+    #[test]
+    fn recognize_phone_numbers() {
+        let text = "Call +33 6 12 34 56 78 or (555) 123-4567, not 2024-05-01 or 1 2 3";
+        let tokens: Vec<_> = text.tokenize().recognize_phone_numbers().collect();
+        let phone_numbers: Vec<_> = tokens
+            .iter()
+            .filter(|t| t.is_word() && t.lemma().len() > 4)
+            .map(|t| (t.lemma(), &text[t.byte_start..t.byte_end]))
+            .collect();
+        assert_eq!(
+            phone_numbers,
+            [("33612345678", "+33 6 12 34 56 78"), ("5551234567", "555) 123-4567")]
+        );
+
+        // the parts are kept.
+        let words = tokens.iter().filter(|t| t.is_word()).count();
+        assert_eq!(words, text.tokenize().filter(|t| t.is_word()).count() + 2);
+    }
+}
@@ -0,0 +1,179 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use crate::normalizer::identity_char_map;
+use crate::{SeparatorKind, Token, TokenKind};
+
+/// Iterator over [`Token`]s and the shingles of consecutive words,
+/// see [`TokenFilter::shingles`](super::TokenFilter::shingles).
+pub struct ShingleIter<'o, I> {
+    inner: I,
+    max_size: usize,
+    separator: String,
+    /// last words read and the separators between them.
+    window: VecDeque<Token<'o>>,
+    /// shingles to emit before reading the next token.
+    pending: VecDeque<Token<'o>>,
+}
+
+impl<'o, I> ShingleIter<'o, I> {
+    pub(super) fn new(inner: I, max_size: usize, separator: String) -> Self {
+        Self { inner, max_size, separator, window: VecDeque::new(), pending: VecDeque::new() }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for ShingleIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(shingle) = self.pending.pop_front() {
+            return Some(shingle);
+        }
+
+        let token = self.inner.next()?;
+        match token.kind {
+            TokenKind::Separator(SeparatorKind::Hard) => self.window.clear(),
+            TokenKind::Separator(SeparatorKind::Soft) => {
+                if !self.window.is_empty() {
+                    self.window.push_back(token.clone());
+                }
+            }
+            _ => {
+                self.window.push_back(token.clone());
+                // only keep the last `max_size` words.
+                while self.window.iter().filter(|t| !t.is_separator()).count() > self.max_size {
+                    self.window.pop_front();
+                    while self.window.front().is_some_and(Token::is_separator) {
+                        self.window.pop_front();
+                    }
+                }
+
+                let window = self.window.make_contiguous();
+                let starts = window.iter().enumerate().rev().filter(|(_, t)| !t.is_separator());
+                // skip the current word which is not a shingle.
+                for (start, _) in starts.skip(1) {
+                    self.pending.push_back(shingle(&window[start..], &self.separator));
+                }
+            }
+        }
+
+        Some(token)
+    }
+}
+
+/// Creates a shingle from the provided words and the separators between them.
+pub(super) fn shingle<'o>(tokens: &[Token<'o>], separator: &str) -> Token<'o> {
+    let first = &tokens[0];
+    let last = &tokens[tokens.len() - 1];
+    let create_char_map = tokens.iter().any(|t| t.char_map.is_some());
+
+    let mut lemma = String::new();
+    let mut char_map: Vec<(u8, u8)> = Vec::new();
+    let mut previous_is_word = false;
+    let mut in_gap = false;
+    for token in tokens {
+        let token_char_map = match (&token.char_map, create_char_map) {
+            (Some(char_map), _) => char_map.clone(),
+            (None, true) => identity_char_map(token.lemma()),
+            (None, false) => Vec::new(),
+        };
+
+        if token.is_separator() {
+            // the separators between two words are replaced by a single shingle separator.
+            for (i, (original_len, _)) in token_char_map.into_iter().enumerate() {
+                let normalized_len = if !in_gap && i == 0 { separator.len() as u8 } else { 0 };
+                char_map.push((original_len, normalized_len));
+            }
+            if !in_gap {
+                lemma.push_str(separator);
+            }
+            in_gap = true;
+            previous_is_word = false;
+        } else {
+            // words that are not separated in the original text, like in CJK,
+            // are still joined by the shingle separator.
+            if previous_is_word {
+                lemma.push_str(separator);
+                if let Some((_, normalized_len)) = char_map.last_mut() {
+                    *normalized_len += separator.len() as u8;
+                }
+            }
+            lemma.push_str(token.lemma());
+            char_map.extend(token_char_map);
+            in_gap = false;
+            previous_is_word = true;
+        }
+    }
+
+    Token {
+        kind: TokenKind::Word,
+        lemma: Cow::Owned(lemma),
+        char_start: first.char_start,
+        char_end: last.char_end,
+        byte_start: first.byte_start,
+        byte_end: last.byte_end,
+        char_map: create_char_map.then_some(char_map),
+        script: first.script,
+        language: first.language,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::TokenFilter;
+    use crate::{Tokenize, TokenizerBuilder};
+
+    #[test]
+    fn shingles() {
+        let text = "The quick (brown) fox! Jumps";
+        let tokens: Vec<_> = text.tokenize().shingles(2, "_").filter(|t| t.is_word()).collect();
+        let lemmas: Vec<_> = tokens.iter().map(|t| t.lemma()).collect();
+        assert_eq!(
+            lemmas,
+            ["the", "quick", "the_quick", "brown", "quick_brown", "fox", "brown_fox", "jumps"]
+        );
+
+        let quick_brown = &tokens[4];
+        assert_eq!(&text[quick_brown.byte_start..quick_brown.byte_end], "quick (brown");
+        assert_eq!(quick_brown.char_start, 4);
+        assert_eq!(quick_brown.char_end, 16);
+        assert_eq!(quick_brown.char_map, None);
+
+        // no shingles are created for a size lower than 2.
+        assert_eq!(text.tokenize().shingles(1, " ").count(), text.tokenize().count());
+    }
+
+    #[test]
+    fn shingles_char_map() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "Thé  Café";
+        let tokens: Vec<_> = tokenizer.tokenize(text).shingles(2, " ").collect();
+        let shingle = tokens.last().unwrap();
+        assert_eq!(shingle.lemma(), "the cafe");
+        assert_eq!(
+            shingle.char_map,
+            Some(vec![(1, 1), (1, 1), (2, 1), (1, 1), (1, 0), (1, 1), (1, 1), (1, 1), (2, 1)])
+        );
+        assert_eq!(shingle.original_lengths(shingle.byte_len()), (9, text.len()));
+        assert_eq!(shingle.original_lengths(4), (4, 5));
+    }
+
+    #[cfg(feature = "chinese")]
+    #[test]
+    fn shingles_without_separators() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "我们一起去北京";
+        let tokens: Vec<_> = tokenizer.tokenize(text).shingles(2, " ").collect();
+        let shingle = tokens.last().unwrap();
+        assert_eq!(shingle.lemma(), "去 北京");
+        assert_eq!(&text[shingle.byte_start..shingle.byte_end], "去北京");
+        assert_eq!(shingle.original_lengths(shingle.byte_len()), (3, 9));
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::VecDeque;
+
+use super::merge::merge;
+use crate::{Token, TokenKind};
+
+/// Iterator over [`Token`]s and the hashtags and mentions made of their words,
+/// see [`TokenFilter::recognize_social_tags`](super::TokenFilter::recognize_social_tags).
+pub struct SocialTagIter<'o, I> {
+    inner: I,
+    /// sigil and words of the current tag.
+    window: Vec<Token<'o>>,
+    /// tokens read ahead to find the end of the current tag.
+    lookahead: VecDeque<Token<'o>>,
+    /// tag to emit before reading the next token.
+    pending: Option<Token<'o>>,
+    /// byte end of the last word, a sigil directly following a word not starting a tag, like in "a@b.c".
+    last_word_end: Option<usize>,
+}
+
+impl<'o, I> SocialTagIter<'o, I> {
+    pub(super) fn new(inner: I) -> Self {
+        Self {
+            inner,
+            window: Vec::new(),
+            lookahead: VecDeque::new(),
+            pending: None,
+            last_word_end: None,
+        }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> SocialTagIter<'o, I> {
+    /// Returns true if the tag doesn't continue after the provided word,
+    /// the next word not being adjacent or separated by underscores only.
+    fn ends_after(&mut self, word: &Token<'o>) -> bool {
+        let mut byte_end = word.byte_end;
+        for i in 0.. {
+            if self.lookahead.len() <= i {
+                match self.inner.next() {
+                    Some(token) => self.lookahead.push_back(token),
+                    None => return true,
+                }
+            }
+
+            let next = &self.lookahead[i];
+            if next.byte_start != byte_end || (next.is_separator() && !is_connector(next)) {
+                return true;
+            } else if is_word(next) {
+                return false;
+            }
+            byte_end = next.byte_end;
+        }
+
+        true
+    }
+
+    fn starts_tag(&self, token: &Token) -> bool {
+        sigil_kind(token).is_some() && self.last_word_end != Some(token.byte_start)
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for SocialTagIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(tag) = self.pending.take() {
+            return Some(tag);
+        }
+
+        let token = self.lookahead.pop_front().or_else(|| self.inner.next())?;
+        let continues = self.window.last().is_some_and(|last| {
+            last.byte_end == token.byte_start && (is_word(&token) || is_connector(&token))
+        });
+        if continues {
+            self.window.push(token.clone());
+        } else {
+            self.window.clear();
+            if self.starts_tag(&token) {
+                self.window.push(token.clone());
+            }
+        }
+
+        if is_word(&token) {
+            self.last_word_end = Some(token.byte_end);
+            if self.window.len() > 1 && self.ends_after(&token) {
+                let mut parts = std::mem::take(&mut self.window);
+                let following = parts.split_off(1);
+                let sigil = parts.pop().unwrap();
+                let kind = sigil_kind(&sigil).unwrap();
+                self.pending = Some(Token { kind, ..merge(sigil, following) });
+            }
+        }
+
+        Some(token)
+    }
+}
+
+fn is_word(token: &Token) -> bool {
+    token.is_word() || token.is_stopword()
+}
+
+/// Returns true for the underscores joining the words of a tag, like in "@john_doe".
+fn is_connector(token: &Token) -> bool {
+    token.is_separator() && !token.lemma().is_empty() && token.lemma().chars().all(|c| c == '_')
+}
+
+/// Returns the kind of the tags starting with the provided token, if it's a sigil.
+fn sigil_kind(token: &Token) -> Option<TokenKind> {
+    match token.lemma() {
+        "#" => Some(TokenKind::Hashtag),
+        "@" => Some(TokenKind::Mention),
+        _otherwise => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::TokenFilter;
+    use crate::{TokenKind, Tokenize};
+
+    #[test]
+    fn social_tags() {
+        let text = "Hi @john_doe, #HelloWorld! Mail a@b.c or ##rust2024";
+        let tokens: Vec<_> = text.tokenize().recognize_social_tags().collect();
+        let tags: Vec<_> = tokens
+            .iter()
+            .filter(|t| !t.is_separator())
+            .map(|t| (t.lemma(), t.kind, &text[t.byte_start..t.byte_end]))
+            .collect();
+        assert_eq!(
+            tags,
+            [
+                ("hi", TokenKind::Word, "Hi"),
+                ("john", TokenKind::Word, "john"),
+                ("doe", TokenKind::Word, "doe"),
+                ("@john_doe", TokenKind::Mention, "@john_doe"),
+                ("hello", TokenKind::Word, "Hello"),
+                ("world", TokenKind::Word, "World"),
+                ("#helloworld", TokenKind::Hashtag, "#HelloWorld"),
+                ("mail", TokenKind::Word, "Mail"),
+                ("a", TokenKind::Word, "a"),
+                ("b", TokenKind::Word, "b"),
+                ("c", TokenKind::Word, "c"),
+                ("or", TokenKind::Word, "or"),
+                ("rust2024", TokenKind::Word, "rust2024"),
+                ("#rust2024", TokenKind::Hashtag, "#rust2024"),
+            ]
+        );
+    }
+}
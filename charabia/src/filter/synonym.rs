@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+
+use crate::{Token, TokenKind};
+
+/// Synonyms of the words, emitted by [`TokenFilter::synonyms`](super::TokenFilter::synonyms).
+///
+/// The words and their synonyms are compared with the normalized lemmas, so they must be provided normalized.
+/// A synonym can be made of several words separated by whitespaces, like "new york" for "nyc".
+#[derive(Debug, Clone, Default)]
+pub struct Synonyms {
+    synonyms: HashMap<String, Vec<Vec<String>>>,
+}
+
+impl Synonyms {
+    /// Creates an empty set of synonyms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a synonym of the provided word.
+    pub fn insert(&mut self, word: impl Into<String>, synonym: &str) -> &mut Self {
+        let words = synonym.split_whitespace().map(str::to_string).collect::<Vec<_>>();
+        if !words.is_empty() {
+            self.synonyms.entry(word.into()).or_default().push(words);
+        }
+        self
+    }
+
+    /// Returns the synonyms of the provided word, each synonym being split into its words.
+    pub fn get(&self, word: &str) -> &[Vec<String>] {
+        self.synonyms.get(word).map_or(&[], Vec::as_slice)
+    }
+}
+
+impl<W: Into<String>, S: AsRef<str>> FromIterator<(W, S)> for Synonyms {
+    fn from_iter<T: IntoIterator<Item = (W, S)>>(iter: T) -> Self {
+        let mut synonyms = Self::new();
+        for (word, synonym) in iter {
+            synonyms.insert(word, synonym.as_ref());
+        }
+        synonyms
+    }
+}
+
+/// Iterator over [`Token`]s and the synonyms of the words,
+/// see [`TokenFilter::synonyms`](super::TokenFilter::synonyms).
+pub struct SynonymIter<'o, 's, I> {
+    inner: I,
+    synonyms: &'s Synonyms,
+    /// synonyms to emit before reading the next token.
+    pending: VecDeque<Token<'o>>,
+}
+
+impl<'o, 's, I> SynonymIter<'o, 's, I> {
+    pub(super) fn new(inner: I, synonyms: &'s Synonyms) -> Self {
+        Self { inner, synonyms, pending: VecDeque::new() }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for SynonymIter<'o, '_, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(synonym) = self.pending.pop_front() {
+            return Some(synonym);
+        }
+
+        let token = self.inner.next()?;
+        if token.is_word() {
+            for words in self.synonyms.get(token.lemma()) {
+                for (i, word) in words.iter().enumerate() {
+                    // the words of a synonym share the offsets of the original token.
+                    self.pending.push_back(Token {
+                        kind: TokenKind::Word,
+                        lemma: Cow::Owned(word.clone()),
+                        char_start: token.char_start,
+                        char_end: token.char_end,
+                        byte_start: token.byte_start,
+                        byte_end: token.byte_end,
+                        script: token.script,
+                        language: token.language,
+                        is_prefix: token.is_prefix,
+                        phrase: token.phrase,
+                        synonym: Some((i, words.len())),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Synonyms;
+    use crate::filter::TokenFilter;
+    use crate::Tokenize;
+
+    #[test]
+    fn synonyms() {
+        let synonyms: Synonyms =
+            [("nyc", "new york"), ("nyc", "big apple"), ("car", "auto")].into_iter().collect();
+        let text = "NYC car";
+        let tokens: Vec<_> = text
+            .tokenize()
+            .synonyms(&synonyms)
+            .filter(|t| t.is_word())
+            .map(|t| (t.lemma.into_owned(), t.byte_start, t.synonym))
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                ("nyc".to_string(), 0, None),
+                ("new".to_string(), 0, Some((0, 2))),
+                ("york".to_string(), 0, Some((1, 2))),
+                ("big".to_string(), 0, Some((0, 2))),
+                ("apple".to_string(), 0, Some((1, 2))),
+                ("car".to_string(), 4, None),
+                ("auto".to_string(), 4, Some((0, 1))),
+            ]
+        );
+    }
+}
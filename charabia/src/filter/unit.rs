@@ -0,0 +1,286 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use super::merge::merge;
+use crate::{Token, TokenKind};
+
+/// Normalized unit symbols, only recognized after a number.
+const UNITS: &[&str] = &[
+    "%", "‰", "°", "°c", "°f", "k", "mm", "cm", "m", "km", "mm2", "cm2", "m2", "km2", "cm3", "m3",
+    "mg", "g", "kg", "t", "ml", "cl", "l", "lb", "oz", "in", "ft", "mi", "mph", "s", "ms", "h",
+    "min", "hz", "khz", "mhz", "ghz", "kb", "mb", "gb", "tb", "w", "kw", "kwh", "v",
+];
+
+/// Iterator over [`Token`]s classifying the currency and unit symbols,
+/// see [`TokenFilter::classify_units`](super::TokenFilter::classify_units).
+pub struct UnitIter<'o, I> {
+    inner: I,
+    attach: bool,
+    /// parts of the last split token, not classified yet.
+    parts: VecDeque<Token<'o>>,
+    /// byte end of the last number and if it's followed by a whitespace.
+    last_number: Option<(usize, bool)>,
+    /// classified tokens read ahead to find the symbols to attach.
+    lookahead: VecDeque<Token<'o>>,
+}
+
+impl<'o, I> UnitIter<'o, I> {
+    pub(super) fn new(inner: I, attach: bool) -> Self {
+        Self {
+            inner,
+            attach,
+            parts: VecDeque::new(),
+            last_number: None,
+            lookahead: VecDeque::new(),
+        }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> UnitIter<'o, I> {
+    /// Returns the next token, the currency and unit symbols being split from the numbers and classified.
+    fn next_classified(&mut self) -> Option<Token<'o>> {
+        let mut token = match self.parts.pop_front() {
+            Some(part) => part,
+            None => {
+                let token = self.inner.next()?;
+                self.parts.extend(split_symbols(token));
+                self.parts.pop_front()?
+            }
+        };
+
+        let follows_number = match self.last_number {
+            Some((byte_end, false)) => byte_end == token.byte_start,
+            Some((_, true)) => true,
+            None => false,
+        };
+
+        if is_currency(token.lemma()) {
+            token.kind = TokenKind::Currency;
+        } else if follows_number && UNITS.contains(&token.lemma()) {
+            token.kind = TokenKind::Unit;
+        }
+
+        self.last_number = if is_number(&token) {
+            Some((token.byte_end, false))
+        } else if token.lemma() == " "
+            && self.last_number.is_some_and(|(end, _)| end == token.byte_start)
+        {
+            Some((token.byte_end, true))
+        } else {
+            None
+        };
+
+        Some(token)
+    }
+
+    fn fill_lookahead(&mut self, len: usize) {
+        while self.lookahead.len() < len {
+            match self.next_classified() {
+                Some(token) => self.lookahead.push_back(token),
+                None => break,
+            }
+        }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for UnitIter<'o, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.lookahead.pop_front().or_else(|| self.next_classified())?;
+        if !self.attach {
+            return Some(token);
+        }
+
+        self.fill_lookahead(2);
+        let next = self.lookahead.front();
+        let len = if token.kind == TokenKind::Currency {
+            // a currency symbol is attached to the following number, like "$10".
+            next.filter(|next| is_number(next) && token.byte_end == next.byte_start).map(|_| 1)
+        } else if is_number(&token) {
+            // a number is attached to the following symbol, like "20€" or "3 km".
+            match (next, self.lookahead.get(1)) {
+                (Some(next), _) if is_symbol(next) => Some(1),
+                (Some(next), Some(symbol))
+                    if next.lemma() == " " && symbol.kind == TokenKind::Unit =>
+                {
+                    Some(2)
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        match len {
+            Some(len) => {
+                let following: Vec<_> = self.lookahead.drain(..len).collect();
+                let kind = if token.kind == TokenKind::Currency {
+                    TokenKind::Currency
+                } else {
+                    following.last().map_or(TokenKind::Word, |symbol| symbol.kind)
+                };
+                let mut token = merge(token, following);
+                token.kind = kind;
+                Some(token)
+            }
+            None => Some(token),
+        }
+    }
+}
+
+fn is_number(token: &Token) -> bool {
+    token.is_word()
+        && token.lemma().starts_with(|c: char| c.is_ascii_digit())
+        && token.lemma().ends_with(|c: char| c.is_ascii_digit())
+}
+
+fn is_symbol(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::Currency | TokenKind::Unit)
+}
+
+fn is_currency_char(c: char) -> bool {
+    matches!(c, '$' | '¢'..='¥' | '฿' | '\u{20A0}'..='\u{20CF}' | '﷼')
+}
+
+fn is_currency(lemma: &str) -> bool {
+    !lemma.is_empty() && lemma.chars().all(is_currency_char)
+}
+
+/// Splits the currency symbols preceding the number of the token, like "$10",
+/// and the currency and unit symbols following it, like "20€" or "3°f".
+fn split_symbols(token: Token) -> Vec<Token> {
+    if token.is_separator() {
+        return vec![token];
+    }
+
+    let lemma = token.lemma();
+    let digits_start = lemma.find(|c: char| c.is_ascii_digit());
+    let digits_end = lemma.rfind(|c: char| c.is_ascii_digit()).map(|i| i + 1);
+    let (Some(digits_start), Some(digits_end)) = (digits_start, digits_end) else {
+        return vec![token];
+    };
+
+    let (prefix, suffix) = (&lemma[..digits_start], &lemma[digits_end..]);
+    let split_prefix = !prefix.is_empty() && is_currency(prefix);
+    let split_suffix = !suffix.is_empty() && (is_currency(suffix) || UNITS.contains(&suffix));
+    if (!prefix.is_empty() && !split_prefix) || (!suffix.is_empty() && !split_suffix) {
+        return vec![token];
+    }
+
+    let mut parts = Vec::with_capacity(3);
+    let mut rest = token;
+    if split_prefix {
+        let (prefix, tail) = split_at(rest, digits_start);
+        parts.push(prefix);
+        rest = tail;
+    }
+    if split_suffix {
+        let (number, suffix) = split_at(rest, digits_end - digits_start);
+        parts.push(number);
+        parts.push(suffix);
+    } else {
+        parts.push(rest);
+    }
+
+    parts
+}
+
+/// Splits the token at the provided byte index of its lemma.
+fn split_at(token: Token, at: usize) -> (Token, Token) {
+    let (char_count, byte_len) = token.original_lengths(at);
+    let (head_lemma, tail_lemma) = match token.lemma {
+        Cow::Borrowed(lemma) => (Cow::Borrowed(&lemma[..at]), Cow::Borrowed(&lemma[at..])),
+        Cow::Owned(ref lemma) => {
+            (Cow::Owned(lemma[..at].to_string()), Cow::Owned(lemma[at..].to_string()))
+        }
+    };
+    let (head_char_map, tail_char_map) = match &token.char_map {
+        Some(char_map) => {
+            let (head, tail) = char_map.split_at(char_count);
+            (Some(head.to_vec()), Some(tail.to_vec()))
+        }
+        None => (None, None),
+    };
+
+    let head = Token {
+        lemma: head_lemma,
+        char_end: token.char_start + char_count,
+        byte_end: token.byte_start + byte_len,
+        char_map: head_char_map,
+        is_prefix: false,
+        ..token.clone()
+    };
+    let tail = Token {
+        lemma: tail_lemma,
+        char_start: head.char_end,
+        byte_start: head.byte_end,
+        char_map: tail_char_map,
+        ..token
+    };
+
+    (head, tail)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::filter::TokenFilter;
+    use crate::{TokenKind, Tokenize};
+
+    #[test]
+    fn classify_units() {
+        let text = "I'm paying $10, 20€, 50% of 3 km² in 5m";
+        let tokens: Vec<_> = text
+            .tokenize()
+            .classify_units(false)
+            .filter(|t| !t.is_separator())
+            .map(|t| (&text[t.byte_start..t.byte_end], t.kind))
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                ("I", TokenKind::Word),
+                ("m", TokenKind::Word),
+                ("paying", TokenKind::Word),
+                ("$", TokenKind::Currency),
+                ("10", TokenKind::Word),
+                ("20", TokenKind::Word),
+                ("€", TokenKind::Currency),
+                ("50", TokenKind::Word),
+                ("%", TokenKind::Unit),
+                ("of", TokenKind::Word),
+                ("3", TokenKind::Word),
+                ("km²", TokenKind::Unit),
+                ("in", TokenKind::Word),
+                ("5", TokenKind::Word),
+                ("m", TokenKind::Unit),
+            ]
+        );
+    }
+
+    #[test]
+    fn attach_units() {
+        let text = "I'm paying $10, 20€, 50% of 3 km² in 5m";
+        let tokens: Vec<_> = text
+            .tokenize()
+            .classify_units(true)
+            .filter(|t| !t.is_separator())
+            .map(|t| (t.lemma.into_owned(), t.kind))
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                ("i".to_string(), TokenKind::Word),
+                ("m".to_string(), TokenKind::Word),
+                ("paying".to_string(), TokenKind::Word),
+                ("$10".to_string(), TokenKind::Currency),
+                ("20€".to_string(), TokenKind::Currency),
+                ("50%".to_string(), TokenKind::Unit),
+                ("of".to_string(), TokenKind::Word),
+                ("3 km2".to_string(), TokenKind::Unit),
+                ("in".to_string(), TokenKind::Word),
+                ("5m".to_string(), TokenKind::Unit),
+            ]
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::VecDeque;
+
+use crate::Token;
+
+/// Word with the words surrounding it, see [`TokenFilter::word_windows`](super::TokenFilter::word_windows).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordWindow<'o> {
+    /// words preceding the word, in their original order.
+    pub previous: Vec<Token<'o>>,
+    pub word: Token<'o>,
+    /// words following the word, in their original order.
+    pub next: Vec<Token<'o>>,
+}
+
+impl WordWindow<'_> {
+    /// Returns the range of bytes of the original text covered by the window,
+    /// from the first previous word to the last next word.
+    pub fn byte_range(&self) -> std::ops::Range<usize> {
+        let start = self.previous.first().unwrap_or(&self.word).byte_start;
+        let end = self.next.last().unwrap_or(&self.word).byte_end;
+        start..end
+    }
+
+    /// Returns the range of chars of the original text covered by the window,
+    /// from the first previous word to the last next word.
+    pub fn char_range(&self) -> std::ops::Range<usize> {
+        let start = self.previous.first().unwrap_or(&self.word).char_start;
+        let end = self.next.last().unwrap_or(&self.word).char_end;
+        start..end
+    }
+}
+
+/// Iterator over the words of a [`Token`] iterator and their surrounding words,
+/// see [`TokenFilter::word_windows`](super::TokenFilter::word_windows).
+pub struct WordWindowIter<'o, I> {
+    inner: I,
+    size: usize,
+    /// previous words, the current word, and the following words read in advance.
+    words: VecDeque<Token<'o>>,
+    /// index of the current word in `words`.
+    current: usize,
+}
+
+impl<'o, I> WordWindowIter<'o, I> {
+    pub(super) fn new(inner: I, size: usize) -> Self {
+        Self { inner, size, words: VecDeque::new(), current: 0 }
+    }
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for WordWindowIter<'o, I> {
+    type Item = WordWindow<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // read the words following the current one.
+        while self.words.len() <= self.current + self.size {
+            match self.inner.next() {
+                Some(token) if token.is_separator() => (),
+                Some(token) => self.words.push_back(token),
+                None => break,
+            }
+        }
+
+        let word = self.words.get(self.current)?.clone();
+        let previous = self.words.range(..self.current).cloned().collect();
+        let next = self.words.range(self.current + 1..).cloned().collect();
+
+        // only keep the previous words of the next window.
+        if self.current == self.size {
+            self.words.pop_front();
+        } else {
+            self.current += 1;
+        }
+
+        Some(WordWindow { previous, word, next })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::WordWindow;
+    use crate::filter::TokenFilter;
+    use crate::Tokenize;
+
+    fn lemmas<'a>(window: &'a WordWindow) -> (Vec<&'a str>, &'a str, Vec<&'a str>) {
+        let previous = window.previous.iter().map(|t| t.lemma()).collect();
+        let next = window.next.iter().map(|t| t.lemma()).collect();
+        (previous, window.word.lemma(), next)
+    }
+
+    #[test]
+    fn word_windows() {
+        let text = "The quick (brown) fox jumps";
+        let windows: Vec<_> = text.tokenize().word_windows(1).collect();
+        assert_eq!(
+            windows.iter().map(lemmas).collect::<Vec<_>>(),
+            [
+                (vec![], "the", vec!["quick"]),
+                (vec!["the"], "quick", vec!["brown"]),
+                (vec!["quick"], "brown", vec!["fox"]),
+                (vec!["brown"], "fox", vec!["jumps"]),
+                (vec!["fox"], "jumps", vec![]),
+            ]
+        );
+
+        assert_eq!(&text[windows[2].byte_range()], "quick (brown) fox");
+        assert_eq!(windows[2].char_range(), 4..21);
+        assert_eq!(text.tokenize().word_windows(0).map(|w| w.next.len()).sum::<usize>(), 0);
+    }
+}
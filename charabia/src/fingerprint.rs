@@ -0,0 +1,127 @@
+/// Features of the crate changing the tokenization, hashed in the fingerprint when they are enabled.
+const FEATURES: &[(&str, bool)] = &[
+    ("chinese", cfg!(feature = "chinese")),
+    ("hebrew", cfg!(feature = "hebrew")),
+    ("japanese", cfg!(feature = "japanese")),
+    ("japanese-segmentation-ipadic", cfg!(feature = "japanese-segmentation-ipadic")),
+    ("japanese-segmentation-unidic", cfg!(feature = "japanese-segmentation-unidic")),
+    ("japanese-segmentation-external", cfg!(feature = "japanese-segmentation-external")),
+    ("japanese-transliteration", cfg!(feature = "japanese-transliteration")),
+    ("korean", cfg!(feature = "korean")),
+    ("korean-segmentation-external", cfg!(feature = "korean-segmentation-external")),
+    ("thai", cfg!(feature = "thai")),
+    ("greek", cfg!(feature = "greek")),
+    ("serbian", cfg!(feature = "serbian")),
+    ("armenian", cfg!(feature = "armenian")),
+    ("bengali", cfg!(feature = "bengali")),
+    ("cherokee", cfg!(feature = "cherokee")),
+    ("esperanto", cfg!(feature = "esperanto")),
+    ("georgian", cfg!(feature = "georgian")),
+    ("gujarati", cfg!(feature = "gujarati")),
+    ("gurmukhi", cfg!(feature = "gurmukhi")),
+    ("kannada", cfg!(feature = "kannada")),
+    ("kazakh", cfg!(feature = "kazakh")),
+    ("kurdish", cfg!(feature = "kurdish")),
+    ("malayalam", cfg!(feature = "malayalam")),
+    ("mongolian", cfg!(feature = "mongolian")),
+    ("odia", cfg!(feature = "odia")),
+    ("pashto", cfg!(feature = "pashto")),
+    ("tamil", cfg!(feature = "tamil")),
+    ("telugu", cfg!(feature = "telugu")),
+    ("tifinagh", cfg!(feature = "tifinagh")),
+    ("urdu", cfg!(feature = "urdu")),
+    ("uyghur", cfg!(feature = "uyghur")),
+    ("latin-camelcase", cfg!(feature = "latin-camelcase")),
+    ("khmer", cfg!(feature = "khmer")),
+    ("latin-snakecase", cfg!(feature = "latin-snakecase")),
+    ("latin-word-boundaries", cfg!(feature = "latin-word-boundaries")),
+    ("stemming-danish", cfg!(feature = "stemming-danish")),
+    ("stemming-dutch", cfg!(feature = "stemming-dutch")),
+    ("stemming-english", cfg!(feature = "stemming-english")),
+    ("stemming-finnish", cfg!(feature = "stemming-finnish")),
+    ("stemming-french", cfg!(feature = "stemming-french")),
+    ("stemming-german", cfg!(feature = "stemming-german")),
+    ("stemming-hungarian", cfg!(feature = "stemming-hungarian")),
+    ("stemming-italian", cfg!(feature = "stemming-italian")),
+    ("stemming-norwegian", cfg!(feature = "stemming-norwegian")),
+    ("stemming-portuguese", cfg!(feature = "stemming-portuguese")),
+    ("stemming-romanian", cfg!(feature = "stemming-romanian")),
+    ("stemming-spanish", cfg!(feature = "stemming-spanish")),
+    ("stemming-swedish", cfg!(feature = "stemming-swedish")),
+    ("stemming-turkish", cfg!(feature = "stemming-turkish")),
+    ("phonetic", cfg!(feature = "phonetic")),
+    ("icu", cfg!(feature = "icu")),
+];
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Returns the checksum of the provided bytes, e.g. the content of a dictionary.
+#[cfg(any(feature = "chinese", feature = "thai", feature = "khmer", feature = "sinhala"))]
+pub(crate) fn checksum(bytes: &[u8]) -> u64 {
+    Fingerprinter(FNV_OFFSET_BASIS).write_bytes(bytes).finish()
+}
+
+/// Hasher computing the fingerprint of a tokenizer configuration.
+///
+/// The hash is FNV-1a, unlike the hashers of the standard library,
+/// it gives the same result across processes, platforms, and Rust versions.
+pub(crate) struct Fingerprinter(u64);
+
+impl Fingerprinter {
+    /// Creates a hasher already fed with the version and the enabled features of the crate.
+    pub(crate) fn new() -> Self {
+        let mut fingerprinter = Self(FNV_OFFSET_BASIS);
+        fingerprinter.write_str(env!("CARGO_PKG_VERSION"));
+        for (feature, _) in FEATURES.iter().filter(|(_, enabled)| *enabled) {
+            fingerprinter.write_str(feature);
+        }
+        fingerprinter
+    }
+
+    pub(crate) fn write_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        // prefix the bytes by their length to avoid collisions between consecutive values.
+        for byte in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+        self
+    }
+
+    pub(crate) fn write_str(&mut self, s: &str) -> &mut Self {
+        self.write_bytes(s.as_bytes())
+    }
+
+    pub(crate) fn write_bool(&mut self, b: bool) -> &mut Self {
+        self.write_bytes(&[b as u8])
+    }
+
+    /// Hashes an optional list of strings, `None` and an empty list giving different hashes.
+    pub(crate) fn write_strs(&mut self, strs: Option<&[&str]>) -> &mut Self {
+        self.write_bool(strs.is_some());
+        for s in strs.unwrap_or_default() {
+            self.write_str(s);
+        }
+        self
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Fingerprinter, FNV_OFFSET_BASIS};
+
+    #[test]
+    fn fnv1a() {
+        // the fingerprint must never change for the same input.
+        let fingerprint = Fingerprinter(FNV_OFFSET_BASIS).write_bytes(b"charabia").finish();
+        assert_eq!(fingerprint, 0x0548_8fa2_c7a9_b858);
+
+        let mut other = Fingerprinter(FNV_OFFSET_BASIS);
+        other.write_strs(Some(&["chara", "bia"]));
+        assert_ne!(other.finish(), fingerprint);
+    }
+}
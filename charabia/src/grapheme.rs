@@ -0,0 +1,118 @@
+//! Iteration over the extended grapheme clusters of a text with the offset conventions of the [`Token`]s.
+//!
+//! The graphemes are the user-perceived characters, like "é" written with a combining accent
+//! or an emoji made of several code points, so they are the unit to use when highlighting or truncating a text.
+//!
+//! # Example
+//!
+//! ```
+//! use charabia::grapheme::graphemes;
+//! use charabia::Tokenize;
+//!
+//! let text = "Cafe\u{301} 👍🏽";
+//! let clusters: Vec<_> = graphemes(text).map(|g| (g.text, g.char_start, g.byte_start)).collect();
+//! assert_eq!(clusters, [("C", 0, 0), ("a", 1, 1), ("f", 2, 2), ("e\u{301}", 3, 3), (" ", 5, 6), ("👍🏽", 6, 7)]);
+//!
+//! // the graphemes of a token have offsets in the original text, like the token itself.
+//! let token = text.tokenize().last().unwrap();
+//! let grapheme = token.graphemes(text).next().unwrap();
+//! assert_eq!((grapheme.char_start, grapheme.byte_start), (token.char_start, token.byte_start));
+//! ```
+
+use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
+
+use crate::Token;
+
+/// Extended grapheme cluster of a text, see the [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grapheme<'o> {
+    /// the cluster in the original text.
+    pub text: &'o str,
+    /// index of the first char of the cluster in the original text.
+    pub char_start: usize,
+    /// index of the char following the cluster in the original text.
+    pub char_end: usize,
+    /// index of the first byte of the cluster in the original text.
+    pub byte_start: usize,
+    /// index of the byte following the cluster in the original text.
+    pub byte_end: usize,
+}
+
+/// Iterator over the [`Grapheme`]s of a text,
+/// see [`graphemes`] and [`Token::graphemes`].
+pub struct Graphemes<'o> {
+    inner: GraphemeIndices<'o>,
+    byte_offset: usize,
+    char_index: usize,
+}
+
+impl<'o> Graphemes<'o> {
+    /// Creates an iterator over the graphemes of `text`,
+    /// which starts at the provided offsets in the original text.
+    fn new(text: &'o str, byte_offset: usize, char_offset: usize) -> Self {
+        Self { inner: text.grapheme_indices(true), byte_offset, char_index: char_offset }
+    }
+}
+
+impl<'o> Iterator for Graphemes<'o> {
+    type Item = Grapheme<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (byte_index, text) = self.inner.next()?;
+        let char_start = self.char_index;
+        self.char_index += text.chars().count();
+        let byte_start = self.byte_offset + byte_index;
+
+        Some(Grapheme {
+            text,
+            char_start,
+            char_end: self.char_index,
+            byte_start,
+            byte_end: byte_start + text.len(),
+        })
+    }
+}
+
+/// Returns an iterator over the extended grapheme clusters of the text.
+pub fn graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes::new(text, 0, 0)
+}
+
+impl Token<'_> {
+    /// Returns an iterator over the extended grapheme clusters of the original lemma of the token,
+    /// the `original` text being the one the token comes from.
+    ///
+    /// The offsets of the graphemes are the ones in the `original` text, like the offsets of the token.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte offsets of the token are not in the `original` text.
+    pub fn graphemes<'o>(&self, original: &'o str) -> Graphemes<'o> {
+        Graphemes::new(&original[self.byte_start..self.byte_end], self.byte_start, self.char_start)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::graphemes;
+    use crate::Tokenize;
+
+    #[test]
+    fn token_graphemes() {
+        let text = "नमस्ते la\u{308}ut";
+        let tokens: Vec<_> = text.tokenize().collect();
+        let token = tokens.last().unwrap();
+        let clusters: Vec<_> = token.graphemes(text).collect();
+        assert_eq!(
+            clusters.iter().map(|g| g.text).collect::<Vec<_>>(),
+            ["l", "a\u{308}", "u", "t"]
+        );
+
+        // the offsets are consistent with the ones of the whole text.
+        let all: Vec<_> = graphemes(text).collect();
+        assert_eq!(&all[all.len() - clusters.len()..], clusters.as_slice());
+        assert_eq!(clusters.first().unwrap().char_start, token.char_start);
+        assert_eq!(clusters.last().unwrap().char_end, token.char_end);
+        assert_eq!(clusters.last().unwrap().byte_end, token.byte_end);
+    }
+}
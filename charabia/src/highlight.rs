@@ -0,0 +1,149 @@
+//! Highlighting of the matched terms in the original text.
+//!
+//! The matches are found by comparing the normalized lemmas of the tokens with normalized terms,
+//! then mapped back to the original text using the token offsets and their `char_map`.
+//!
+//! # Example
+//!
+//! ```
+//! use charabia::highlight::Highlighter;
+//! use charabia::TokenizerBuilder;
+//!
+//! let mut builder = TokenizerBuilder::default();
+//! builder.create_char_map(true);
+//! let tokenizer = builder.build();
+//!
+//! let text = "Thé Café is quiet";
+//! let highlighter = Highlighter::new(&["cafe", "qui"]).prefix(true);
+//! assert_eq!(highlighter.highlight(text, tokenizer.tokenize(text)), "Thé <em>Café</em> is <em>qui</em>et");
+//! ```
+
+use std::ops::Range;
+
+use crate::Token;
+
+/// Finds the matched terms in the tokens and highlights them in the original text.
+#[derive(Debug, Clone)]
+pub struct Highlighter<'t> {
+    terms: &'t [&'t str],
+    prefix: bool,
+    pre_tag: &'t str,
+    post_tag: &'t str,
+}
+
+impl<'t> Highlighter<'t> {
+    /// Creates a highlighter matching the provided normalized terms,
+    /// wrapping the matches in `<em>` and `</em>`.
+    pub fn new(terms: &'t [&'t str]) -> Self {
+        Self { terms, prefix: false, pre_tag: "<em>", post_tag: "</em>" }
+    }
+
+    /// Enable or disable the matching of the terms as prefixes of the words,
+    /// only the part of the word matching the term being highlighted.
+    pub fn prefix(mut self, prefix: bool) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Configure the tags wrapping the matches.
+    pub fn tags(mut self, pre_tag: &'t str, post_tag: &'t str) -> Self {
+        self.pre_tag = pre_tag;
+        self.post_tag = post_tag;
+        self
+    }
+
+    /// Returns the number of bytes of the lemma of the token matched by the longest term, if any.
+    fn matching_len(&self, token: &Token) -> Option<usize> {
+        let lemma = token.lemma();
+        self.terms
+            .iter()
+            .filter(|term| !term.is_empty())
+            .filter(|term| **term == lemma || (self.prefix && lemma.starts_with(**term)))
+            .map(|term| term.len())
+            .max()
+    }
+
+    /// Returns the sorted and non-overlapping byte ranges of the original text matching a term.
+    pub fn ranges<'o>(&self, tokens: impl IntoIterator<Item = Token<'o>>) -> Vec<Range<usize>> {
+        let mut ranges: Vec<_> = tokens
+            .into_iter()
+            .filter(|token| !token.is_separator())
+            .filter_map(|token| {
+                let len = self.matching_len(&token)?;
+                let (_, original_len) = token.original_lengths(len);
+                Some(token.byte_start..token.byte_start + original_len)
+            })
+            .collect();
+
+        // tokens may overlap, like the sub-words of a segmentation, so are the matches.
+        ranges.sort_unstable_by_key(|range| (range.start, range.end));
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        merged
+    }
+
+    /// Returns the original text with the matches wrapped in the tags.
+    pub fn highlight<'o>(
+        &self,
+        original: &str,
+        tokens: impl IntoIterator<Item = Token<'o>>,
+    ) -> String {
+        let mut highlighted = String::with_capacity(original.len());
+        let mut last = 0;
+        for range in self.ranges(tokens) {
+            highlighted.push_str(&original[last..range.start]);
+            highlighted.push_str(self.pre_tag);
+            highlighted.push_str(&original[range.clone()]);
+            highlighted.push_str(self.post_tag);
+            last = range.end;
+        }
+        highlighted.push_str(&original[last..]);
+
+        highlighted
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Highlighter;
+    use crate::filter::TokenFilter;
+    use crate::{Tokenize, TokenizerBuilder};
+
+    #[test]
+    fn ranges() {
+        let text = "The quick brown fox, the end";
+        let highlighter = Highlighter::new(&["the", "fox", "qu"]);
+        assert_eq!(highlighter.ranges(text.tokenize()), [0..3, 16..19, 21..24]);
+
+        let highlighter = highlighter.prefix(true);
+        assert_eq!(highlighter.ranges(text.tokenize()), [0..3, 4..6, 16..19, 21..24]);
+    }
+
+    #[test]
+    fn highlight_with_char_map() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "ÉTÉ à Ⅻ Saint-Étienne";
+        let highlighter = Highlighter::new(&["ete", "sai", "eti"]).prefix(true).tags("[", "]");
+        assert_eq!(
+            highlighter.highlight(text, tokenizer.tokenize(text)),
+            "[ÉTÉ] à Ⅻ [Sai]nt-[Éti]enne"
+        );
+    }
+
+    #[test]
+    fn overlapping_tokens() {
+        let text = "Hello world";
+        let highlighter = Highlighter::new(&["hel", "hello"]);
+        let tokens = text.tokenize().edge_ngrams(1, 3);
+        assert_eq!(highlighter.highlight(text, tokens), "<em>Hello</em> world");
+    }
+}
@@ -41,6 +41,13 @@
 //! Charabia comes with default features that can be deactivated at compile time,
 //! this features are additional Language supports that need to download and/or build a specialized dictionary that impact the compilation time.
 //! Theses features are listed in charabia's `cargo.toml` and can be deactivated via [dependency features](https://doc.rust-lang.org/cargo/reference/features.html#dependency-features).
+//!
+//! Without default features, only the Script/Language detection, the Latin and Arabic segmentation,
+//! and the normalizers that don't rely on a dictionary are built, none of the dictionaries nor their dependencies are compiled.
+//!
+//! There is no `no_std` mode, charabia requires `std` even without default features:
+//! the [`Script`] and [`Language`] types and the language detection are built on `whatlang`,
+//! and the stop words and the words dictionaries are `fst` sets, both crates requiring `std`.
 
 #[cfg(test)]
 extern crate quickcheck;
@@ -48,20 +55,36 @@ extern crate quickcheck;
 #[macro_use(quickcheck)]
 extern crate quickcheck_macros;
 
+pub mod binary;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod filter;
+#[cfg(feature = "graphemes")]
+pub mod grapheme;
+pub mod highlight;
+pub mod metrics;
 pub mod normalizer;
 pub mod segmenter;
 pub mod separators;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 mod detection;
+mod fingerprint;
 mod token;
 mod tokenizer;
 
-pub use detection::{Language, Script};
-pub use normalizer::Normalize;
-pub use segmenter::Segment;
-pub use token::{SeparatorKind, Token, TokenKind};
-
+pub use detection::{AllowList, CjScript, DetectionCache, Language, ParseCodeError, Script};
+pub use normalizer::{
+    normalized_eq, normalizers_for, BlankInput, Classify, Normalize, ProlongedSoundMark,
+};
+#[cfg(feature = "phonetic")]
+pub use normalizer::{PhoneticAlgorithm, PhoneticOption};
+pub use segmenter::{segmenter_for, supported_languages, Segment};
 #[cfg(test)]
 pub use token::StaticToken;
+pub use token::{Boundary, OffsetError, SeparatorKind, Token, TokenForms, TokenKind};
 
-pub use crate::tokenizer::{ReconstructedTokenIter, Tokenize, Tokenizer, TokenizerBuilder};
+pub use crate::tokenizer::{
+    BuildError, QueryConsistency, ReconstructedTokenIter, Tokenize, Tokenizer, TokenizerBuilder,
+};
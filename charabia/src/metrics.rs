@@ -0,0 +1,126 @@
+//! Statistics of the tokenization of each document, reported to an observer.
+//!
+//! The observer is set with [`TokenizerBuilder::observer`](crate::TokenizerBuilder::observer)
+//! and is called once per tokenized text, when the iterator returned by
+//! [`Tokenizer::tokenize`](crate::Tokenizer::tokenize) is dropped,
+//! so the indexing services can export the metrics of the tokenizer without wrapping the iterator themselves.
+//! Nothing is measured when no observer is set.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::{Arc, Mutex};
+//!
+//! use charabia::metrics::{DocumentMetrics, TokenizerObserver};
+//! use charabia::{Script, TokenizerBuilder};
+//!
+//! #[derive(Default)]
+//! struct Collector(Mutex<Vec<DocumentMetrics>>);
+//!
+//! impl TokenizerObserver for Collector {
+//!     fn on_document(&self, metrics: &DocumentMetrics) {
+//!         self.0.lock().unwrap().push(metrics.clone());
+//!     }
+//! }
+//!
+//! let collector = Arc::new(Collector::default());
+//! let mut builder = TokenizerBuilder::default();
+//! builder.observer(collector.clone());
+//! let tokenizer = builder.build();
+//!
+//! let tokens: Vec<_> = tokenizer.tokenize("Hello world").collect();
+//! let metrics = collector.0.lock().unwrap().pop().unwrap();
+//! assert_eq!((metrics.tokens, metrics.words, metrics.separators), (3, 2, 1));
+//! assert_eq!(metrics.scripts[&Script::Latin], 3);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use crate::{Script, Token};
+
+/// Receiver of the [`DocumentMetrics`] of each tokenized text.
+pub trait TokenizerObserver: Sync + Send {
+    /// Called once the tokenization of a text is over, even if not all its tokens have been consumed.
+    fn on_document(&self, metrics: &DocumentMetrics);
+}
+
+impl fmt::Debug for dyn TokenizerObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TokenizerObserver")
+    }
+}
+
+/// Statistics of the tokenization of a text, only counting the consumed tokens.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentMetrics {
+    /// number of bytes of the original text covered by the tokens.
+    pub bytes: usize,
+    /// number of tokens.
+    pub tokens: usize,
+    /// number of tokens classified as words.
+    pub words: usize,
+    /// number of tokens classified as stop words.
+    pub stop_words: usize,
+    /// number of tokens classified as separators.
+    pub separators: usize,
+    /// number of tokens of each script.
+    pub scripts: HashMap<Script, usize>,
+    /// time spent segmenting the text.
+    pub segmentation: Duration,
+    /// time spent normalizing and classifying the tokens.
+    pub normalization: Duration,
+}
+
+impl DocumentMetrics {
+    /// Counts the provided token.
+    pub(crate) fn count(&mut self, token: &Token) {
+        self.bytes += token.original_byte_len();
+        self.tokens += 1;
+        if token.is_word() {
+            self.words += 1;
+        } else if token.is_stopword() {
+            self.stop_words += 1;
+        } else if token.is_separator() {
+            self.separators += 1;
+        }
+        *self.scripts.entry(token.script).or_default() += 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use super::{DocumentMetrics, TokenizerObserver};
+    use crate::{Script, TokenizerBuilder};
+
+    #[derive(Default)]
+    struct Collector(Mutex<Vec<DocumentMetrics>>);
+
+    impl TokenizerObserver for Collector {
+        fn on_document(&self, metrics: &DocumentMetrics) {
+            self.0.lock().unwrap().push(metrics.clone());
+        }
+    }
+
+    #[test]
+    fn observer() {
+        let collector = Arc::new(Collector::default());
+        let mut builder = TokenizerBuilder::default();
+        builder.observer(collector.clone()).query_prefix(true);
+        let tokenizer = builder.build();
+
+        tokenizer.tokenize("The cat, Москва").for_each(drop);
+        // only the consumed tokens are counted.
+        tokenizer.tokenize("one two three").take(1).for_each(drop);
+
+        let metrics = collector.0.lock().unwrap();
+        assert_eq!(metrics.len(), 2);
+        assert_eq!((metrics[0].tokens, metrics[0].words, metrics[0].separators), (5, 3, 2));
+        assert_eq!(metrics[0].bytes, "The cat, Москва".len());
+        assert_eq!(metrics[0].scripts[&Script::Cyrillic], 1);
+        assert_eq!((metrics[1].tokens, metrics[1].bytes), (1, 3));
+    }
+}
@@ -1,5 +1,5 @@
 use super::{CharNormalizer, CharOrStr};
-use crate::{Script, Token};
+use crate::{Language, Script, Token};
 
 /// A global [`Normalizer`] for Arabic language.
 /// Arabic alphabet:ا,ب,ت,ث,ج,ح,خ,د,ذ,ر,ز,س,ش,ص,ض,ط,ظ,ع,غ,ف,ق,ك,ل,م,ن,ه,و,ي,ء
@@ -21,8 +21,31 @@ impl CharNormalizer for ArabicNormalizer {
     }
 
     fn should_normalize(&self, token: &Token) -> bool {
-        token.script == Script::Arabic && token.lemma.chars().any(is_shoud_normalize)
+        token.script == Script::Arabic
+            && !token.language.is_some_and(has_own_normalizer)
+            && token.lemma.chars().any(is_shoud_normalize)
     }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Arabic && !has_own_normalizer(language)
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Returns true if the language written in the Arabic script has its own orthography and normalizer,
+/// like Urdu with the `UrduNormalizer`, the languages whose feature is disabled keep the Arabic normalization.
+fn has_own_normalizer(language: Language) -> bool {
+    (cfg!(feature = "urdu") && language == Language::Urd)
+        || (cfg!(feature = "kurdish") && language == Language::Ckb)
+        || (cfg!(feature = "pashto") && language == Language::Pus)
+        || (cfg!(feature = "uyghur") && language == Language::Uig)
 }
 
 fn normalize_arabic_char(c: char) -> Option<CharOrStr> {
@@ -0,0 +1,78 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Armenian script.
+///
+/// The Armenian ligatures are normalized to their letters, like 'և' to "եւ" and 'ﬓ' to "մն",
+/// the 'և' ligature being written "ԵՒ" in capitals.
+///
+/// The Armenian apostrophe ('՚') and emphasis marks ('՛', '՜' and '՞') are part of the default separators.
+pub struct ArmenianNormalizer;
+
+impl CharNormalizer for ArmenianNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            'և' => Some("եւ".to_string().into()),
+            'ﬓ' => Some("մն".to_string().into()),
+            'ﬔ' => Some("մե".to_string().into()),
+            'ﬕ' => Some("մի".to_string().into()),
+            'ﬖ' => Some("վն".to_string().into()),
+            'ﬗ' => Some("մխ".to_string().into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Armenian && token.lemma().chars().any(is_ligature)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Armenian
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_ligature(c: char) -> bool {
+    matches!(c, 'և' | 'ﬓ'..='ﬗ')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, SeparatorKind, TokenKind, Tokenize};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.tokenize().map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn armenian() {
+        assert_eq!(lemmas("Բարև ՀԱՅԱՍՏԱՆ"), ["բարեւ", " ", "հայաստան"]);
+        assert_eq!(lemmas("ԵՒ ﬓաց"), ["եւ", " ", "մնաց"]);
+
+        let token = "Երևան".tokenize().next().unwrap();
+        assert_eq!(token.script, Script::Armenian);
+    }
+
+    #[test]
+    fn armenian_separators() {
+        let kinds: Vec<_> = "Ս՚ ինչո՛ւ".tokenize().map(|t| t.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Word,
+                TokenKind::Separator(SeparatorKind::Soft),
+                TokenKind::Separator(SeparatorKind::Soft),
+                TokenKind::Word,
+                TokenKind::Separator(SeparatorKind::Soft),
+                TokenKind::Word,
+            ]
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Bengali script, used to write Bengali and Assamese.
+///
+/// Bengali-script text is normalized by:
+/// - removing the nukta ('়'), the letters with a nukta, like 'য়' in the ya-phala typed as "্য়", being decomposed beforehand
+/// - removing the zero width joiners and non-joiners, like in "র‍্য" where they select the ya-phala instead of the reph
+/// - normalizing the Assamese ra ('ৰ') to the Bengali ra ('র'), which Assamese texts typed with a Bengali keyboard use,
+///   Assamese not being detected as a distinct language
+/// - normalizing the Bengali digits, like '১', to the ASCII digits
+///
+/// The Assamese wa ('ৱ') is kept, as the Bengali ba ('ব') is a distinct letter in Assamese.
+pub struct BengaliNormalizer;
+
+impl CharNormalizer for BengaliNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            '\u{9BC}' | '\u{200C}' | '\u{200D}' => None,
+            'ৰ' => Some('র'.into()),
+            '\u{9E6}'..='\u{9EF}' => Some(char::from(b'0' + (c as u32 - 0x9E6) as u8).into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Bengali && token.lemma().chars().any(is_variant)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Bengali
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_variant(c: char) -> bool {
+    matches!(c, '\u{9BC}' | '\u{200C}' | '\u{200D}' | 'ৰ' | '\u{9E6}'..='\u{9EF}')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, Tokenize};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.tokenize().map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn bengali() {
+        // the ya-phala with a nukta, and the ya-phala after a ra joined with a ZWJ.
+        assert_eq!(lemmas("ব্য়াংক"), lemmas("ব্যাংক"));
+        assert_eq!(lemmas("র\u{200D}্যাব"), lemmas("র্যাব"));
+        // the precomposed "ড়".
+        assert_eq!(lemmas("\u{9DC}"), lemmas("ড"));
+        // the Assamese and Bengali ra.
+        assert_eq!(lemmas("অসমৰ"), lemmas("অসমর"));
+
+        let token = "১৯৭১".tokenize().next().unwrap();
+        assert_eq!(token.script, Script::Bengali);
+        assert_eq!(token.lemma(), "1971");
+    }
+}
@@ -0,0 +1,59 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Cherokee script.
+///
+/// The Cherokee syllabary was unicameral until Unicode 8 added its lowercase letters,
+/// so the uppercase letters are the canonical form of the syllabary, used by most of the texts.
+/// The lowercase letters, like 'ꭰ', are folded to their uppercase letters, like 'Ꭰ',
+/// the opposite direction of the [`LowercaseNormalizer`](super::LowercaseNormalizer) which ignores this script.
+pub struct CherokeeNormalizer;
+
+impl CharNormalizer for CherokeeNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        let folded = match c {
+            '\u{AB70}'..='\u{ABBF}' => c as u32 - 0xAB70 + 0x13A0,
+            '\u{13F8}'..='\u{13FD}' => c as u32 - 8,
+            _ => return Some(c.into()),
+        };
+        char::from_u32(folded).map(Into::into)
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Cherokee && token.lemma().chars().any(is_lowercase)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Cherokee
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_lowercase(c: char) -> bool {
+    matches!(c, '\u{AB70}'..='\u{ABBF}' | '\u{13F8}'..='\u{13FD}')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, Tokenize};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.tokenize().map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn cherokee() {
+        assert_eq!(lemmas("ꮳꮃꭹ ᏣᎳᎩ"), ["ᏣᎳᎩ", " ", "ᏣᎳᎩ"]);
+        assert_eq!(lemmas("ᏸ"), ["Ᏸ"]);
+
+        let token = "ᏣᎳᎩ".tokenize().next().unwrap();
+        assert_eq!(token.script, Script::Cherokee);
+    }
+}
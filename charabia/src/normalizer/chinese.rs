@@ -37,6 +37,14 @@ impl CharNormalizer for ChineseNormalizer {
     fn should_normalize(&self, token: &Token) -> bool {
         token.script == Script::Cj && matches!(token.language, None | Some(Language::Cmn))
     }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Cj && matches!(language, Language::Other | Language::Cmn)
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -91,7 +99,7 @@ mod test {
                 script: Script::Cj,
                 language: Some(Language::Cmn),
                 ..Default::default()
-            }
+            },
         ]
     }
 
@@ -117,7 +125,7 @@ mod test {
                 language: Some(Language::Cmn),
                 kind: TokenKind::Word,
                 ..Default::default()
-            }
+            },
         ]
     }
 
@@ -0,0 +1,168 @@
+use super::{Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+/// Prefix of the ordinal numbers, like in "第三章" (third chapter).
+const ORDINAL_PREFIX: char = '第';
+
+/// Counters and units following a number, like in "三人" (three people) or "五月" (May).
+const COUNTERS: &[char] = &[
+    '章', '回', '年', '月', '日', '時', '时', '分', '秒', '人', '個', '个', '本', '枚', '歳', '岁',
+    '号', '號', '階', '条', '條', '部', '巻', '卷', '話', '话', '円', '元', '点', '點', '度', '倍',
+    '番', '位', '匹', '台', '冊', '册', '件', '名', '課', '课', '節', '节', '期', '代', '週', '周',
+    '頁', '页', 'つ',
+];
+
+/// Compute the ASCII digits version of the CJK numerals of the words, like "第3章" for "第三章",
+/// so that the numbers written with CJK numerals can be matched with the ones written with digits.
+///
+/// Only the numerals in a numeric context are converted, to keep the words like "統一" (unification) untouched:
+/// the tokens only made of numerals, and the sequences of numerals following '第' or preceding a counter like '章'.
+/// Both the positional, like "二〇二四" (2024), and the multiplicative, like "三千五百" (3500), notations are supported.
+///
+/// The converted lemma is returned by [`Token::numeric`] and doesn't replace the lemma.
+/// Nothing is done if the conversion is not enabled in the [`NormalizerOption`].
+pub struct CjkNumeralNormalizer;
+
+impl Normalizer for CjkNumeralNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        if options.cjk_numerals {
+            if let Some(numeric) = convert_numerals(token.lemma()) {
+                token.forms_mut().numeric = Some(numeric);
+            }
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Cj && token.is_word() && token.lemma().chars().any(is_numeral)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Cj
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the provided lemma with its numerals in a numeric context replaced by ASCII digits,
+/// `None` if no numeral has been replaced.
+fn convert_numerals(lemma: &str) -> Option<String> {
+    let chars: Vec<char> = lemma.chars().collect();
+    let mut converted = String::with_capacity(lemma.len());
+    let mut replaced = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let run = chars[i..].iter().take_while(|c| is_numeral(**c)).count();
+        if run == 0 {
+            converted.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        let end = i + run;
+        let numeric_context = run == chars.len()
+            || (i > 0 && chars[i - 1] == ORDINAL_PREFIX)
+            || chars.get(end).is_some_and(|c| COUNTERS.contains(c));
+        match numeric_context.then(|| numeral_value(&chars[i..end])).flatten() {
+            Some(value) => {
+                converted.push_str(&value.to_string());
+                replaced = true;
+            }
+            None => converted.extend(&chars[i..end]),
+        }
+        i = end;
+    }
+
+    replaced.then_some(converted)
+}
+
+/// Returns the value of the provided numerals, `None` if it overflows.
+fn numeral_value(numerals: &[char]) -> Option<u64> {
+    // positional notation, like "二〇二四".
+    if !numerals.iter().any(|c| multiplier(*c).is_some()) {
+        return numerals
+            .iter()
+            .try_fold(0u64, |value, c| value.checked_mul(10)?.checked_add(digit(*c)?));
+    }
+
+    // multiplicative notation, like "三千五百二十", the sections being separated by '万' and '億'.
+    let (mut total, mut section, mut current) = (0u64, 0u64, None);
+    for &c in numerals {
+        match (digit(c), multiplier(c)) {
+            (Some(digit), _) => current = Some(digit),
+            (None, Some(multiplier)) if multiplier < 10_000 => {
+                section = section.checked_add(current.unwrap_or(1).checked_mul(multiplier)?)?;
+                current = None;
+            }
+            (None, Some(multiplier)) => {
+                let section_value = section + current.unwrap_or(0);
+                let section_value = if section_value == 0 { 1 } else { section_value };
+                total = total.checked_add(section_value.checked_mul(multiplier)?)?;
+                (section, current) = (0, None);
+            }
+            (None, None) => return None,
+        }
+    }
+
+    total.checked_add(section)?.checked_add(current.unwrap_or(0))
+}
+
+fn digit(c: char) -> Option<u64> {
+    match c {
+        '〇' | '零' => Some(0),
+        '一' => Some(1),
+        '二' | '两' | '兩' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        _ => None,
+    }
+}
+
+fn multiplier(c: char) -> Option<u64> {
+    match c {
+        '十' => Some(10),
+        '百' => Some(100),
+        '千' => Some(1_000),
+        '万' | '萬' => Some(10_000),
+        '億' | '亿' => Some(100_000_000),
+        _ => None,
+    }
+}
+
+fn is_numeral(c: char) -> bool {
+    digit(c).is_some() || multiplier(c).is_some()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{convert_numerals, numeral_value};
+
+    #[test]
+    fn numeral_values() {
+        let value = |numerals: &str| numeral_value(&numerals.chars().collect::<Vec<_>>());
+        assert_eq!(value("三"), Some(3));
+        assert_eq!(value("十"), Some(10));
+        assert_eq!(value("二十一"), Some(21));
+        assert_eq!(value("三千五百二十"), Some(3520));
+        assert_eq!(value("一万二千"), Some(12_000));
+        assert_eq!(value("三億五千万"), Some(350_000_000));
+        assert_eq!(value("二〇二四"), Some(2024));
+    }
+
+    #[test]
+    fn numeric_contexts() {
+        assert_eq!(convert_numerals("第三章").as_deref(), Some("第3章"));
+        assert_eq!(convert_numerals("二十人").as_deref(), Some("20人"));
+        assert_eq!(convert_numerals("百").as_deref(), Some("100"));
+        assert_eq!(convert_numerals("統一"), None);
+        assert_eq!(convert_numerals("一緒"), None);
+    }
+}
@@ -3,10 +3,12 @@ use std::collections::HashSet;
 use fst::Set;
 use once_cell::sync::Lazy;
 
+use super::emoji::is_emoji_sequence;
 use super::{Normalizer, NormalizerOption};
+use crate::separators::SeparatorSet;
 use crate::{SeparatorKind, Token, TokenKind};
 
-/// Classify a Token as a word, a stop_word or a separator.
+/// Classify a Token as a word, a stop_word, a separator or an emoji.
 ///
 /// Assign to each [`Token`]s a [`TokenKind`] using provided stop words.
 ///
@@ -15,10 +17,16 @@ use crate::{SeparatorKind, Token, TokenKind};
 /// Any `Token` that is in the stop words [`Set`] is assigned to [`TokenKind::StopWord`].
 ///
 /// [`TokenKind::StopWord`]: crate::TokenKind#StopWord
+///
+/// Any `Token` only made of emoji is assigned to [`TokenKind::Emoji`](crate::TokenKind::Emoji).
 pub struct Classifier;
 
 impl Normalizer for Classifier {
     fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        if options.skip_classification {
+            return token;
+        }
+
         token.kind = TokenKind::Word;
         let lemma = token.lemma();
 
@@ -29,14 +37,21 @@ impl Normalizer for Classifier {
             }
         }
 
-        match options.classifier.separators {
-            Some(separators) if separators.contains(&lemma) => {
-                token.kind = TokenKind::Separator(separator_kind(lemma));
-            }
-            None if DEFAULT_SEPARATOR_SET.contains(lemma) => {
-                token.kind = TokenKind::Separator(separator_kind(lemma));
-            }
-            _otherwise => (),
+        let separator_kind =
+            match (&options.classifier.separator_set, options.classifier.separators) {
+                (Some(separator_set), _) => separator_set.kind(lemma),
+                (None, Some(separators)) => {
+                    separators.contains(&lemma).then(|| separator_kind(lemma))
+                }
+                (None, None) => {
+                    DEFAULT_SEPARATOR_SET.contains(lemma).then(|| separator_kind(lemma))
+                }
+            };
+
+        match separator_kind {
+            Some(kind) => token.kind = TokenKind::Separator(kind),
+            None if is_emoji_sequence(lemma) => token.kind = TokenKind::Emoji,
+            None => (),
         }
 
         token
@@ -52,8 +67,62 @@ impl Normalizer for Classifier {
 pub struct ClassifierOption<'no> {
     pub stop_words: Option<Set<&'no [u8]>>,
     pub separators: Option<&'no [&'no str]>,
+    /// separators with their own soft and hard classification, taking precedence over `separators`.
+    pub separator_set: Option<SeparatorSet>,
+}
+
+/// Iterator over classified [`Token`]s, see [`Classify::classify`].
+pub struct ClassifiedTokenIter<'c, I> {
+    inner: I,
+    options: NormalizerOption<'c>,
+}
+
+impl<'o, I: Iterator<Item = Token<'o>>> Iterator for ClassifiedTokenIter<'_, I> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.inner.next()?;
+        // the tokens are classified again even if they already have a kind.
+        Some(Classifier.normalize(Token { kind: TokenKind::Unknown, ..token }, &self.options))
+    }
+}
+
+/// Trait defining the classification stage of the pipeline as an adapter of any iterator of [`Token`]s.
+///
+/// Combined with [`crate::Segment::segment`] and [`crate::segmenter::SegmentedTokenIter::normalize`],
+/// it allows building custom pipelines, like classifying segmented tokens without normalizing them,
+/// or classifying normalized tokens with a different configuration.
+pub trait Classify<'o>: Iterator<Item = Token<'o>> + Sized {
+    /// Assigns a [`TokenKind`] to each token using the provided stop words and separators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::normalizer::{ClassifierOption, NormalizerOption};
+    /// use charabia::{Classify, Segment};
+    ///
+    /// // segment and classify without normalizing.
+    /// let tokens: Vec<_> = "Hello, World".segment().classify(&ClassifierOption::default()).collect();
+    /// assert_eq!(tokens[0].lemma(), "Hello");
+    /// assert!(tokens[0].is_word());
+    /// assert!(tokens[1].is_separator());
+    ///
+    /// // normalize without classifying, then classify with a custom configuration.
+    /// let options = NormalizerOption { lossy: true, skip_classification: true, ..Default::default() };
+    /// let separators = [" "];
+    /// let classifier = ClassifierOption { separators: Some(&separators), ..Default::default() };
+    /// let tokens: Vec<_> = "Hello, World".segment().normalize(&options).classify(&classifier).collect();
+    /// assert_eq!(tokens[0].lemma(), "hello");
+    /// assert!(tokens[1].is_word());
+    /// ```
+    fn classify<'c>(self, options: &ClassifierOption<'c>) -> ClassifiedTokenIter<'c, Self> {
+        let options = NormalizerOption { classifier: options.clone(), ..Default::default() };
+        ClassifiedTokenIter { inner: self, options }
+    }
 }
 
+impl<'o, I: Iterator<Item = Token<'o>>> Classify<'o> for I {}
+
 fn separator_kind(lemma: &str) -> SeparatorKind {
     if CONTEXT_SEPARATOR_SET.contains(lemma) {
         SeparatorKind::Hard
@@ -173,8 +242,28 @@ mod test {
         let stop_words = Set::new(stop_words).unwrap();
         let options = NormalizerOption {
             create_char_map: true,
-            classifier: ClassifierOption { stop_words: Some(stop_words), separators: None },
+            classifier: ClassifierOption {
+                stop_words: Some(stop_words),
+                separators: None,
+                separator_set: None,
+            },
             lossy: false,
+            query_prefix: false,
+            query_phrase: false,
+            skip_classification: false,
+            skeleton: false,
+            romanize: false,
+            emoji_names: false,
+            cjk_numerals: false,
+            prolonged_sound_mark: crate::ProlongedSoundMark::Keep,
+            keep_original: false,
+            blank_input: crate::BlankInput::Keep,
+            stemming: false,
+            esperanto_h_system: false,
+            #[cfg(feature = "hungarian")]
+            hungarian_keep_umlauts: false,
+            #[cfg(feature = "phonetic")]
+            phonetic: None,
         };
 
         let token = Classifier
@@ -210,8 +299,28 @@ mod test {
         let stop_words = Set::new(stop_words).unwrap();
         let options = NormalizerOption {
             create_char_map,
-            classifier: ClassifierOption { stop_words: Some(stop_words), separators: None },
+            classifier: ClassifierOption {
+                stop_words: Some(stop_words),
+                separators: None,
+                separator_set: None,
+            },
             lossy,
+            query_prefix: false,
+            query_phrase: false,
+            skip_classification: false,
+            skeleton: false,
+            romanize: false,
+            emoji_names: false,
+            cjk_numerals: false,
+            prolonged_sound_mark: crate::ProlongedSoundMark::Keep,
+            keep_original: false,
+            blank_input: crate::BlankInput::Keep,
+            stemming: false,
+            esperanto_h_system: false,
+            #[cfg(feature = "hungarian")]
+            hungarian_keep_umlauts: false,
+            #[cfg(feature = "phonetic")]
+            phonetic: None,
         };
 
         let token = Classifier
@@ -235,8 +344,28 @@ mod test {
         let separators: Vec<&str> = separators.iter().map(|s| s.as_str()).collect();
         let options = NormalizerOption {
             create_char_map,
-            classifier: ClassifierOption { stop_words: None, separators: Some(&separators) },
+            classifier: ClassifierOption {
+                stop_words: None,
+                separators: Some(&separators),
+                separator_set: None,
+            },
             lossy,
+            query_prefix: false,
+            query_phrase: false,
+            skip_classification: false,
+            skeleton: false,
+            romanize: false,
+            emoji_names: false,
+            cjk_numerals: false,
+            prolonged_sound_mark: crate::ProlongedSoundMark::Keep,
+            keep_original: false,
+            blank_input: crate::BlankInput::Keep,
+            stemming: false,
+            esperanto_h_system: false,
+            #[cfg(feature = "hungarian")]
+            hungarian_keep_umlauts: false,
+            #[cfg(feature = "phonetic")]
+            phonetic: None,
         };
 
         let token = Classifier
@@ -265,8 +394,25 @@ mod test {
             classifier: ClassifierOption {
                 stop_words: Some(stop_words),
                 separators: Some(&separators),
+                separator_set: None,
             },
             lossy,
+            query_prefix: false,
+            query_phrase: false,
+            skip_classification: false,
+            skeleton: false,
+            romanize: false,
+            emoji_names: false,
+            cjk_numerals: false,
+            prolonged_sound_mark: crate::ProlongedSoundMark::Keep,
+            keep_original: false,
+            blank_input: crate::BlankInput::Keep,
+            stemming: false,
+            esperanto_h_system: false,
+            #[cfg(feature = "hungarian")]
+            hungarian_keep_umlauts: false,
+            #[cfg(feature = "phonetic")]
+            phonetic: None,
         };
 
         let token = Classifier
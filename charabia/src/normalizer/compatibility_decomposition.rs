@@ -1,5 +1,9 @@
+#[cfg(not(feature = "icu"))]
 use std::iter::once;
 
+#[cfg(feature = "icu")]
+use icu_normalizer::DecomposingNormalizerBorrowed;
+#[cfg(not(feature = "icu"))]
 use unicode_normalization::{is_nfkd_quick, UnicodeNormalization};
 
 use super::{CharNormalizer, CharOrStr};
@@ -7,13 +11,15 @@ use crate::Token;
 
 /// A global [`Normalizer`] normalizing to the Unicode Normalization Form KD.
 ///
-/// This Normalizer uses [`unicode-normalization::nfkd`] internally to normalize the provided token.
+/// This Normalizer uses [`unicode-normalization::nfkd`] internally to normalize the provided token,
+/// or the ICU4X NFKD normalizer when the `icu` feature is enabled.
 ///
 /// The Unicode Normalization Form KD (NFKD) is the Compatibility Decomposition normalization, see
 /// <https://www.unicode.org/reports/tr15/#Norm_Forms> for more information.
 pub struct CompatibilityDecompositionNormalizer;
 
 impl CharNormalizer for CompatibilityDecompositionNormalizer {
+    #[cfg(not(feature = "icu"))]
     fn normalize_char(&self, c: char) -> Option<CharOrStr> {
         let mut normalized = c.nfkd();
 
@@ -30,6 +36,23 @@ impl CharNormalizer for CompatibilityDecompositionNormalizer {
         }
     }
 
+    #[cfg(feature = "icu")]
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        let nfkd = DecomposingNormalizerBorrowed::new_nfkd();
+        let mut buffer = [0; 4];
+        let normalized = nfkd.normalize(c.encode_utf8(&mut buffer));
+        let mut chars = normalized.chars();
+
+        // if the original character is converted in exactly one character,
+        // then we return the character directly instead of creating a string for it.
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c.into()),
+            (Some(_), Some(_)) => Some(normalized.into_owned().into()),
+            (None, _) => None,
+        }
+    }
+
+    #[cfg(not(feature = "icu"))]
     fn should_normalize(&self, token: &Token) -> bool {
         !(token.lemma().is_ascii()
             || matches!(
@@ -37,6 +60,16 @@ impl CharNormalizer for CompatibilityDecompositionNormalizer {
                 unicode_normalization::IsNormalized::Yes
             ))
     }
+
+    #[cfg(feature = "icu")]
+    fn should_normalize(&self, token: &Token) -> bool {
+        let nfkd = DecomposingNormalizerBorrowed::new_nfkd();
+        !(token.lemma().is_ascii() || nfkd.is_normalized(token.lemma()))
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
 }
 
 // Test the normalizer:
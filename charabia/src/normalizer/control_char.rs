@@ -14,6 +14,10 @@ impl CharNormalizer for ControlCharNormalizer {
     fn should_normalize(&self, token: &Token) -> bool {
         token.lemma().chars().any(is_control)
     }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
 }
 
 fn is_control(c: char) -> bool {
@@ -0,0 +1,211 @@
+use std::borrow::Cow;
+
+use super::{Normalizer, NormalizerOption};
+use crate::Token;
+
+/// Replaces the emoji by their name, so the content containing them can be found by text queries,
+/// e.g. "😀" becomes "grinning".
+///
+/// The variants of an emoji are folded to the base emoji before being named:
+/// the skin tone modifiers are removed and only the first emoji of a ZWJ sequence is kept,
+/// e.g. "👍🏽" becomes "+1" and "👩‍💻" becomes "woman".
+/// The flags are named after their region code, e.g. "🇫🇷" becomes "fr".
+/// The names are separated by a space from the surrounding emoji and text of the same token.
+/// Nothing is done if the emoji names are not enabled in the [`NormalizerOption`].
+pub struct EmojiNormalizer;
+
+impl Normalizer for EmojiNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        if !options.emoji_names {
+            return token;
+        }
+
+        // the parts of the lemma normalized together, with the number of bytes of the original text they come from.
+        let parts: Vec<(u8, &str)> = match &token.char_map {
+            Some(char_map) => {
+                let mut tail = token.lemma();
+                char_map
+                    .iter()
+                    .map(|(original_len, normalized_len)| {
+                        let (head, t) = tail.split_at(*normalized_len as usize);
+                        tail = t;
+                        (*original_len, head)
+                    })
+                    .collect()
+            }
+            None => token
+                .lemma()
+                .char_indices()
+                .map(|(i, c)| (c.len_utf8() as u8, &token.lemma()[i..i + c.len_utf8()]))
+                .collect(),
+        };
+
+        let mut lemma = String::new();
+        let mut char_map = Vec::with_capacity(parts.len());
+        let mut state = State::Text;
+        for (original_len, part) in parts {
+            let start = lemma.len();
+            for c in part.chars() {
+                state = push_char(&mut lemma, c, state);
+            }
+            char_map.push((original_len, (lemma.len() - start) as u8));
+        }
+
+        token.lemma = Cow::Owned(lemma);
+        if options.create_char_map {
+            token.char_map = Some(char_map);
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.lemma().chars().any(|c| is_emoji(c) || is_regional_indicator(c))
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Kind of the last char pushed in the lemma.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Text,
+    Emoji,
+    /// a zero width joiner, the following emoji being part of the same sequence.
+    Joiner,
+    Flag,
+}
+
+/// Pushes the normalized char in the lemma, returning the new state.
+fn push_char(lemma: &mut String, c: char, state: State) -> State {
+    if is_modifier(c) {
+        state
+    } else if c == '\u{200D}' {
+        if state == State::Emoji {
+            State::Joiner
+        } else {
+            state
+        }
+    } else if is_emoji(c) {
+        if state == State::Joiner {
+            // only the first emoji of a sequence is kept.
+            return State::Emoji;
+        }
+
+        let name = deunicode::deunicode_char(c).map(str::trim).filter(|name| !name.is_empty());
+        if !lemma.is_empty() {
+            lemma.push(' ');
+        }
+        match name {
+            Some(name) => lemma.extend(name.chars().flat_map(char::to_lowercase)),
+            None => lemma.push(c),
+        }
+        State::Emoji
+    } else if is_regional_indicator(c) {
+        if state != State::Flag && !lemma.is_empty() {
+            lemma.push(' ');
+        }
+        lemma.push((b'a' + (c as u32 - 0x1F1E6) as u8) as char);
+        State::Flag
+    } else {
+        if state != State::Text {
+            lemma.push(' ');
+        }
+        lemma.push(c);
+        State::Text
+    }
+}
+
+/// Returns true if the lemma is only made of emoji, including their modifiers, ZWJ sequences, flags and keycaps.
+pub(crate) fn is_emoji_sequence(lemma: &str) -> bool {
+    let mut chars = lemma.chars().peekable();
+    let mut has_emoji = false;
+    while let Some(c) = chars.next() {
+        if is_emoji(c) || is_regional_indicator(c) || c == KEYCAP {
+            has_emoji = true;
+        } else if matches!(c, '0'..='9' | '#' | '*') {
+            // the base of a keycap, like "1️⃣".
+            if !matches!(chars.peek(), Some(&KEYCAP | &'\u{FE0F}')) {
+                return false;
+            }
+        } else if !is_modifier(c) && c != '\u{200D}' {
+            return false;
+        }
+    }
+
+    has_emoji
+}
+
+/// Combining enclosing keycap, following a digit, `#` or `*` in the keycap emoji.
+const KEYCAP: char = '\u{20E3}';
+
+fn is_emoji(c: char) -> bool {
+    let in_emoji_blocks = matches!(
+        c,
+        '\u{2300}'..='\u{23FF}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2B00}'..='\u{2BFF}'
+            | '\u{1F000}'..='\u{1FAFF}'
+    );
+    in_emoji_blocks && !is_modifier(c) && !is_regional_indicator(c)
+}
+
+/// Returns true for the skin tone modifiers and the emoji variation selector.
+fn is_modifier(c: char) -> bool {
+    matches!(c, '\u{1F3FB}'..='\u{1F3FF}' | '\u{FE0F}')
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Tokenize, TokenizerBuilder};
+
+    #[test]
+    fn emoji_names() {
+        let mut builder = TokenizerBuilder::default();
+        builder.emoji_names(true).create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "😀 👍🏽 👩‍💻 🇫🇷 coffee☕";
+        let tokens: Vec<_> =
+            tokenizer.tokenize(text).filter(|t| t.is_word() || t.is_emoji()).collect();
+        let lemmas: Vec<_> = tokens.iter().map(|t| t.lemma()).collect();
+        assert_eq!(lemmas, ["grinning", "+1", "woman", "fr", "coffee coffee"]);
+        assert!(tokens.iter().all(|t| t.validate(text).is_ok()));
+
+        // the emoji are kept when the names are disabled.
+        assert_eq!("😀".tokenize().next().unwrap().lemma(), "😀");
+    }
+
+    #[test]
+    fn emoji_kind() {
+        let kinds: Vec<_> = "I ❤️ 🍕🍕 👩‍💻 🇫🇷 👍🏽 1️⃣ 12 coffee☕"
+            .tokenize()
+            .filter(|t| !t.is_separator())
+            .map(|t| (t.lemma().to_string(), t.is_emoji()))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("i".to_string(), false),
+                ("❤".to_string(), true),
+                ("🍕🍕".to_string(), true),
+                ("👩\u{200D}💻".to_string(), true),
+                ("🇫🇷".to_string(), true),
+                ("👍🏽".to_string(), true),
+                ("1\u{20E3}".to_string(), true),
+                ("12".to_string(), false),
+                ("coffee☕".to_string(), false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+
+use super::{identity_char_map, Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+/// Combining circumflex accent of 'ĉ', 'ĝ', 'ĥ', 'ĵ' and 'ŝ', decomposed by the compatibility decomposition.
+const CIRCUMFLEX: char = '\u{302}';
+/// Combining breve of 'ŭ', decomposed by the compatibility decomposition.
+const BREVE: char = '\u{306}';
+
+/// A [`Normalizer`] for the Esperanto language.
+///
+/// The Esperanto letters with diacritics are often replaced by ASCII workarounds, the x-system writing 'ĉ' as "cx",
+/// and the h-system writing it as "ch". This Normalizer folds the diacritics to the x-system,
+/// so "ĉiuĵaŭde" and "cxiujxauxde" both become "cxiujxauxde".
+///
+/// The h-system is only folded when enabled by [`NormalizerOption::esperanto_h_system`], so "ĉu" and "chu"
+/// both become "cxu", because it also matches the compound words like "flughaveno".
+/// The 'ŭ' not being marked in the h-system, only its diacritic and x-system spellings are folded.
+///
+/// The diacritics are folded before being removed by the `NonspacingMarkNormalizer`.
+pub struct EsperantoNormalizer;
+
+impl Normalizer for EsperantoNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        let h_system = options.esperanto_h_system;
+        let mut previous = None;
+        let char_map = token
+            .char_map
+            .take()
+            .or_else(|| options.create_char_map.then(|| identity_char_map(token.lemma())));
+        match char_map {
+            // the decomposed characters are folded one by one to keep the char_map aligned.
+            Some(char_map) => {
+                let mut folded = String::with_capacity(token.lemma.len());
+                let mut lemma = token.lemma();
+                let char_map = char_map
+                    .into_iter()
+                    .map(|(original_len, normalized_len)| {
+                        let (segment, rest) = lemma.split_at(normalized_len as usize);
+                        lemma = rest;
+                        let len = folded.len();
+                        fold(segment, h_system, &mut previous, &mut folded);
+                        (original_len, (folded.len() - len) as u8)
+                    })
+                    .collect();
+                token.lemma = Cow::Owned(folded);
+                token.char_map = Some(char_map);
+            }
+            None => {
+                let mut folded = String::with_capacity(token.lemma.len());
+                fold(token.lemma(), h_system, &mut previous, &mut folded);
+                token.lemma = Cow::Owned(folded);
+            }
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Latin
+            && token.language == Some(Language::Epo)
+            && token.is_word()
+            && token.lemma().contains(['h', CIRCUMFLEX, BREVE].as_slice())
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Latin && language == Language::Epo
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Pushes the provided text into `folded`, replacing the diacritics, and the 'h' of the h-system if `h_system`,
+/// by an 'x' when they follow the letters they apply to, `previous` being the last pushed character.
+fn fold(text: &str, h_system: bool, previous: &mut Option<char>, folded: &mut String) {
+    for c in text.chars() {
+        let c = match (*previous, c) {
+            (Some('c' | 'g' | 'h' | 'j' | 's'), CIRCUMFLEX) | (Some('u'), BREVE) => 'x',
+            (Some('c' | 'g' | 'h' | 'j' | 's'), 'h') if h_system => 'x',
+            (_, c) => c,
+        };
+        folded.push(c);
+        *previous = Some(c);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AllowList, Language, TokenizerBuilder};
+
+    fn lemmas(text: &str, h_system: bool) -> Vec<String> {
+        let allow_list = AllowList::new().languages([Language::Epo]);
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list).create_char_map(true).esperanto_h_system(h_system);
+        let tokenizer = builder.build();
+
+        tokenizer
+            .tokenize(text)
+            .inspect(|t| assert_eq!(t.validate(text), Ok(())))
+            .map(|t| t.lemma.into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn esperanto() {
+        let folded = ["cxiujxauxde", " ", "sxi", " ", "mangxas"];
+        assert_eq!(lemmas("Ĉiuĵaŭde ŝi manĝas", false), folded);
+        assert_eq!(lemmas("cxiujxauxde sxi mangxas", false), folded);
+        // the 'ŭ' is written 'u' in the h-system.
+        let h_system = ["cxiujxaude", " ", "sxi", " ", "mangxas"];
+        assert_eq!(lemmas("chiujhaude shi manghas", true), h_system);
+    }
+
+    #[test]
+    fn h_system_disabled_by_default() {
+        // the 'h' starting the next root of a compound word is kept.
+        assert_eq!(lemmas("flughaveno", false), ["flughaveno"]);
+        assert_eq!(lemmas("chiujhaude", false), ["chiujhaude"]);
+        assert_eq!(lemmas("flughaveno", true), ["flugxaveno"]);
+    }
+
+    #[test]
+    fn esperanto_char_map() {
+        let allow_list = AllowList::new().languages([Language::Epo]);
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list).create_char_map(true).esperanto_h_system(true);
+        let tokenizer = builder.build();
+
+        // the ASCII h-system has no char_map before this normalizer.
+        let token = tokenizer.tokenize("chu").next().unwrap();
+        assert_eq!(token.lemma(), "cxu");
+        assert_eq!(token.char_map, Some(vec![(1, 1), (1, 1), (1, 1)]));
+    }
+}
@@ -0,0 +1,61 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// Offset between a Mtavruli capital and its Mkhedruli letter, like 'Ა' (U+1C90) and 'ა' (U+10D0).
+const MTAVRULI_OFFSET: u32 = 0x1C90 - 0x10D0;
+
+/// A [`Normalizer`](super::Normalizer) for the Georgian script.
+///
+/// The Mtavruli capitals, like 'Ა', are normalized to their Mkhedruli letters, like 'ა'.
+/// They were only encoded in Unicode 11, so this folding doesn't depend on the Unicode version
+/// of the lowercasing of the [`LowercaseNormalizer`](super::LowercaseNormalizer).
+pub struct GeorgianNormalizer;
+
+impl CharNormalizer for GeorgianNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        if is_mtavruli(c) {
+            char::from_u32(c as u32 - MTAVRULI_OFFSET).map(Into::into)
+        } else {
+            Some(c.into())
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Georgian && token.lemma().chars().any(is_mtavruli)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Georgian
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_mtavruli(c: char) -> bool {
+    matches!(c, '\u{1C90}'..='\u{1CBA}' | '\u{1CBD}'..='\u{1CBF}')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, TokenizerBuilder};
+
+    #[test]
+    fn mtavruli() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "ᲡᲐᲥᲐᲠᲗᲕᲔᲚᲝ";
+        let token = tokenizer.tokenize(text).next().unwrap();
+        assert_eq!(token.script, Script::Georgian);
+        assert_eq!(token.lemma(), "საქართველო");
+        assert_eq!(token.char_map, Some(vec![(3, 3); 10]));
+        assert_eq!(token.validate(text), Ok(()));
+    }
+}
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use super::{Normalizer, NormalizerOption};
-use crate::{Script, Token};
+use crate::{Language, Script, Token};
 
 /// Normalize Greek characters by:
 /// 1. convert final sigma into ordinary sigma
@@ -22,6 +22,14 @@ impl Normalizer for GreekNormalizer {
     fn should_normalize(&self, token: &Token) -> bool {
         token.script == Script::Greek
     }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Greek
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,60 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Gujarati script.
+///
+/// Gujarati text is normalized by:
+/// - removing the nukta ('઼'), used to write the sounds borrowed from Persian or English, like in "ફ઼"
+/// - normalizing the candrabindu ('ઁ') to the anusvara ('ં'), both marking the nasalization
+/// - normalizing the Gujarati digits, like '૧', to the ASCII digits
+pub struct GujaratiNormalizer;
+
+impl CharNormalizer for GujaratiNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            '\u{ABC}' => None,
+            '\u{A81}' => Some('\u{A82}'.into()),
+            '\u{AE6}'..='\u{AEF}' => Some(char::from(b'0' + (c as u32 - 0xAE6) as u8).into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Gujarati && token.lemma().chars().any(is_variant)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Gujarati
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_variant(c: char) -> bool {
+    matches!(c, '\u{ABC}' | '\u{A81}' | '\u{AE6}'..='\u{AEF}')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, Tokenize};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.tokenize().map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn gujarati() {
+        assert_eq!(lemmas("ફ઼ોન"), lemmas("ફોન"));
+        assert_eq!(lemmas("હઁસ"), lemmas("હંસ"));
+
+        let token = "૨૦૨૪".tokenize().next().unwrap();
+        assert_eq!(token.script, Script::Gujarati);
+        assert_eq!(token.lemma(), "2024");
+    }
+}
@@ -0,0 +1,69 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Gurmukhi script, used to write Punjabi.
+///
+/// Punjabi words are spelled with or without some marks depending on the writer,
+/// so Gurmukhi text is normalized by:
+/// - removing the nukta ('਼'), the letters with a nukta, like 'ਖ਼', being decomposed beforehand
+/// - normalizing the tippi ('ੰ') to the bindi ('ਂ'), both marking the nasalization
+/// - removing the addak ('ੱ') marking the gemination of the following consonant
+/// - normalizing the Gurmukhi digits, like '੧', to the ASCII digits
+pub struct GurmukhiNormalizer;
+
+impl CharNormalizer for GurmukhiNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            '\u{A3C}' | '\u{A71}' => None,
+            '\u{A70}' => Some('\u{A02}'.into()),
+            '\u{A66}'..='\u{A6F}' => {
+                let digit = (c as u32 - 0xA66) as u8;
+                Some(char::from(b'0' + digit).into())
+            }
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Gurmukhi && token.lemma().chars().any(is_variant)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Gurmukhi
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_variant(c: char) -> bool {
+    matches!(c, '\u{A3C}' | '\u{A70}' | '\u{A71}' | '\u{A66}'..='\u{A6F}')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, Tokenize};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.tokenize().map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn gurmukhi() {
+        // the nukta of the precomposed "ਖ਼" and of the decomposed "ਜ਼".
+        assert_eq!(lemmas("\u{A59}ਬਰ ਜ\u{A3C}ਮੀਨ"), lemmas("ਖਬਰ ਜਮੀਨ"));
+        // the tippi and the bindi.
+        assert_eq!(lemmas("ਪੰਜਾਬ"), lemmas("ਪਂਜਾਬ"));
+        // the addak.
+        assert_eq!(lemmas("ਪੱਕਾ"), lemmas("ਪਕਾ"));
+
+        let token = "੧੯੪੭".tokenize().next().unwrap();
+        assert_eq!(token.script, Script::Gurmukhi);
+        assert_eq!(token.lemma(), "1947");
+    }
+}
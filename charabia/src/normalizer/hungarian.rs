@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+
+use super::{identity_char_map, replace_lemma, Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+/// Most common Hungarian case endings, with their long vowels folded, the longest ones first.
+const SUFFIXES: &[&str] = &[
+    "kent", "ban", "ben", "bol", "böl", "rol", "röl", "nal", "nel", "hoz", "hez", "höz", "tol",
+    "töl", "nak", "nek", "val", "vel", "ert", "ba", "be", "ra", "re", "ig", "on", "en", "ön",
+];
+
+/// Minimum number of characters of the stem left by the suffix stripping.
+const MIN_STEM_CHARS: usize = 3;
+
+/// Hungarian specialized [`Normalizer`].
+///
+/// Hungarian words are agglutinated, so this Normalizer folds the long vowels into their short counterparts
+/// and strips the most common case endings, e.g. "Budapesten" becomes "budapest" and "házból" becomes "haz".
+/// The umlauts are removed along with the other diacritics unless they are kept
+/// with [`crate::TokenizerBuilder::hungarian_keep_umlauts`], keeping "ö" and "ü" distinct from "o" and "u".
+///
+/// The suffixes are not stripped when the words are stemmed, with the `stemming-hungarian` feature
+/// and [`crate::TokenizerBuilder::stemming`], the Snowball stemmer removing them instead.
+pub struct HungarianNormalizer;
+
+impl Normalizer for HungarianNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        let keep_umlauts = options.hungarian_keep_umlauts;
+        let char_map = token
+            .char_map
+            .take()
+            .or_else(|| options.create_char_map.then(|| identity_char_map(token.lemma())));
+        match char_map {
+            // the decomposed characters are folded one by one to keep the char_map aligned.
+            Some(char_map) => {
+                let mut folded = String::with_capacity(token.lemma.len());
+                let mut lemma = token.lemma();
+                let char_map = char_map
+                    .into_iter()
+                    .map(|(original_len, normalized_len)| {
+                        let (segment, rest) = lemma.split_at(normalized_len as usize);
+                        lemma = rest;
+                        let len = folded.len();
+                        fold_vowels(segment, keep_umlauts, &mut folded);
+                        (original_len, (folded.len() - len) as u8)
+                    })
+                    .collect();
+                token.lemma = Cow::Owned(folded);
+                token.char_map = Some(char_map);
+            }
+            None => {
+                let mut folded = String::with_capacity(token.lemma.len());
+                fold_vowels(token.lemma(), keep_umlauts, &mut folded);
+                token.lemma = Cow::Owned(folded);
+            }
+        }
+
+        // the Snowball stemmer, applied afterwards, strips the suffixes itself.
+        let stemmed = cfg!(feature = "stemming-hungarian") && options.stemming;
+        if !stemmed {
+            if let Some(stem) = strip_suffix(token.lemma(), keep_umlauts) {
+                let stem = stem.to_string();
+                replace_lemma(&mut token, stem, options);
+            }
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Latin && token.language == Some(Language::Hun) && token.is_word()
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Latin && language == Language::Hun
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Pushes the provided text into `folded`, removing the acute accents marking the long vowels,
+/// and the diaeresis and double acute accents marking the umlauts unless `keep_umlauts` is set.
+///
+/// The text is decomposed, so "ő" is an "o" followed by a combining double acute accent.
+fn fold_vowels(text: &str, keep_umlauts: bool, folded: &mut String) {
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{301}' => (),
+            '\u{308}' | '\u{30B}' if !keep_umlauts => (),
+            'o' | 'u' if keep_umlauts && matches!(chars.peek(), Some('\u{308}' | '\u{30B}')) => {
+                chars.next();
+                folded.push(if c == 'o' { 'ö' } else { 'ü' });
+            }
+            c => folded.push(c),
+        }
+    }
+}
+
+/// Returns the provided word without its case ending, if it has one and the remaining stem is long enough.
+fn strip_suffix(word: &str, keep_umlauts: bool) -> Option<&str> {
+    SUFFIXES.iter().find_map(|suffix| {
+        let stem = if keep_umlauts {
+            word.strip_suffix(suffix)
+        } else {
+            word.strip_suffix(suffix.replace('ö', "o").as_str())
+        }?;
+        (stem.chars().count() >= MIN_STEM_CHARS).then_some(stem)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AllowList, Language, TokenizerBuilder};
+
+    fn stems(text: &str, keep_umlauts: bool) -> Vec<String> {
+        let allow_list = AllowList::new().languages([Language::Hun]);
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list).hungarian_keep_umlauts(keep_umlauts);
+        let tokenizer = builder.build();
+
+        tokenizer.tokenize(text).filter(|t| t.is_word()).map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn hungarian() {
+        let text = "Budapesten a házból könyvből kertben";
+        assert_eq!(stems(text, false), ["budapest", "a", "haz", "konyv", "kert"]);
+        assert_eq!(stems(text, true), ["budapest", "a", "haz", "könyv", "kert"]);
+    }
+
+    #[test]
+    fn hungarian_char_map() {
+        let allow_list = AllowList::new().languages([Language::Hun]);
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list).hungarian_keep_umlauts(true).create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "Őszön";
+        let token = tokenizer.tokenize(text).next().unwrap();
+        assert_eq!(token.lemma(), "ösz");
+        assert_eq!(token.char_map, Some(vec![(2, 2), (1, 1), (1, 1), (2, 0), (1, 0)]));
+        assert_eq!(token.validate(text), Ok(()));
+    }
+
+    #[test]
+    fn unknown_language() {
+        use crate::normalizer::NormalizerOption;
+        use crate::Normalize;
+
+        // the language of a `&str` is unknown, so its words are not stripped of the Hungarian suffixes.
+        let options = NormalizerOption { lossy: true, ..Default::default() };
+        assert_eq!("kitten".normalize(&options), "kitten");
+        assert_eq!("München".normalize(&options), "munchen");
+        assert_eq!("házban".normalize(&options), "hazban");
+    }
+}
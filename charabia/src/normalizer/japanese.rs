@@ -37,6 +37,14 @@ impl Normalizer for JapaneseNormalizer {
             && matches!(token.language, None | Some(Language::Jpn))
             && !token.lemma().is_hiragana()
     }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Cj && matches!(language, Language::Other | Language::Jpn)
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,188 @@
+use std::borrow::Cow;
+use std::iter::once;
+
+use unicode_normalization::UnicodeNormalization;
+
+use super::{identity_char_map, Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+/// Prolonged sound mark, lengthening the vowel of the preceding kana, like in "コーヒー" (coffee).
+const PROLONGED_SOUND_MARK: char = 'ー';
+
+/// Iteration marks repeating the preceding kanji, hiragana, or katakana, like in "人々" (people),
+/// their voiced versions, like 'ゞ', being decomposed in the mark followed by a combining voiced sound mark.
+const ITERATION_MARKS: [char; 3] = ['々', 'ゝ', 'ヽ'];
+
+/// Handling of the prolonged sound mark 'ー' by the [`JapaneseMarksNormalizer`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProlongedSoundMark {
+    /// the mark is kept, "コンピューター" and "コンピュータ" being different words.
+    #[default]
+    Keep,
+    /// the mark is removed, so "コンピューター" matches "コンピュータ".
+    Strip,
+    /// the mark is replaced by the vowel of the preceding kana, so "コーヒー" matches "コオヒイ".
+    Fold,
+}
+
+/// A [`Normalizer`] for the Japanese marks.
+///
+/// The iteration marks are expanded to the character they repeat, like "人々" to "人人" and "いすゞ" to "いすず",
+/// and the prolonged sound mark 'ー' is handled as configured by [`NormalizerOption::prolonged_sound_mark`],
+/// so the common variants of the katakana words match.
+pub struct JapaneseMarksNormalizer;
+
+impl Normalizer for JapaneseMarksNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        let prolonged_sound_mark = options.prolonged_sound_mark;
+        let mut previous = None;
+        let char_map = token
+            .char_map
+            .take()
+            .or_else(|| options.create_char_map.then(|| identity_char_map(token.lemma())));
+        match char_map {
+            // the decomposed characters are expanded one by one to keep the char_map aligned.
+            Some(char_map) => {
+                let mut expanded = String::with_capacity(token.lemma.len());
+                let mut lemma = token.lemma();
+                let char_map = char_map
+                    .into_iter()
+                    .map(|(original_len, normalized_len)| {
+                        let (segment, rest) = lemma.split_at(normalized_len as usize);
+                        lemma = rest;
+                        let len = expanded.len();
+                        expand(segment, prolonged_sound_mark, &mut previous, &mut expanded);
+                        (original_len, (expanded.len() - len) as u8)
+                    })
+                    .collect();
+                token.lemma = Cow::Owned(expanded);
+                token.char_map = Some(char_map);
+            }
+            None => {
+                let mut expanded = String::with_capacity(token.lemma.len());
+                expand(token.lemma(), prolonged_sound_mark, &mut previous, &mut expanded);
+                token.lemma = Cow::Owned(expanded);
+            }
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Cj
+            && token.language != Some(Language::Cmn)
+            && token.lemma().contains([PROLONGED_SOUND_MARK, '々', 'ゝ', 'ヽ'].as_slice())
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Cj && language != Language::Cmn
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Pushes the provided text into `expanded`, expanding the marks,
+/// `previous` being the last pushed character that is not a combining mark.
+fn expand(
+    text: &str,
+    prolonged_sound_mark: ProlongedSoundMark,
+    previous: &mut Option<char>,
+    expanded: &mut String,
+) {
+    for c in text.chars() {
+        let c = match (c, *previous) {
+            (c, Some(previous)) if ITERATION_MARKS.contains(&c) => previous,
+            (PROLONGED_SOUND_MARK, previous) => match prolonged_sound_mark {
+                ProlongedSoundMark::Keep => c,
+                ProlongedSoundMark::Strip => continue,
+                ProlongedSoundMark::Fold => previous.and_then(vowel).unwrap_or(c),
+            },
+            (c, _) => c,
+        };
+
+        expanded.push(c);
+        if !matches!(c, '\u{3099}' | '\u{309A}') {
+            *previous = Some(c);
+        }
+    }
+}
+
+/// Returns the vowel of the provided kana, in the same syllabary.
+fn vowel(kana: char) -> Option<char> {
+    // the voiced kana, like 'ぱ', have the vowel of their unvoiced kana.
+    let kana = once(kana).nfd().next()?;
+    // the katakana are located 0x60 code points after their hiragana.
+    let (hiragana, katakana) = match kana {
+        'ァ'..='ヶ' => (char::from_u32(kana as u32 - 0x60)?, true),
+        'ぁ'..='ゖ' => (kana, false),
+        _ => return None,
+    };
+
+    let vowel = match hiragana {
+        'あ' | 'か' | 'さ' | 'た' | 'な' | 'は' | 'ま' | 'や' | 'ら' | 'わ' | 'ぁ' | 'ゃ'
+        | 'ゎ' | 'ゕ' => 'あ',
+        'い' | 'き' | 'し' | 'ち' | 'に' | 'ひ' | 'み' | 'り' | 'ゐ' | 'ぃ' => 'い',
+        'う' | 'く' | 'す' | 'つ' | 'ぬ' | 'ふ' | 'む' | 'ゆ' | 'る' | 'ぅ' | 'っ' | 'ゅ' => {
+            'う'
+        }
+        'え' | 'け' | 'せ' | 'て' | 'ね' | 'へ' | 'め' | 'れ' | 'ゑ' | 'ぇ' | 'ゖ' => {
+            'え'
+        }
+        'お' | 'こ' | 'そ' | 'と' | 'の' | 'ほ' | 'も' | 'よ' | 'ろ' | 'を' | 'ぉ' | 'ょ' => {
+            'お'
+        }
+        _ => return None,
+    };
+
+    if katakana {
+        char::from_u32(vowel as u32 + 0x60)
+    } else {
+        Some(vowel)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use super::{JapaneseMarksNormalizer, ProlongedSoundMark};
+    use crate::normalizer::{Normalizer, NormalizerOption};
+    use crate::{Language, Script, Token};
+
+    fn normalize(lemma: &str, prolonged_sound_mark: ProlongedSoundMark) -> Token<'_> {
+        let token = Token {
+            lemma: Cow::Borrowed(lemma),
+            char_end: lemma.chars().count(),
+            byte_end: lemma.len(),
+            script: Script::Cj,
+            language: Some(Language::Jpn),
+            ..Default::default()
+        };
+        let options =
+            NormalizerOption { create_char_map: true, prolonged_sound_mark, ..Default::default() };
+        let token = JapaneseMarksNormalizer.normalize(token, &options);
+        assert_eq!(token.validate(lemma), Ok(()));
+        token
+    }
+
+    #[test]
+    fn iteration_marks() {
+        assert_eq!(normalize("人々", ProlongedSoundMark::Keep).lemma(), "人人");
+        // the voiced iteration mark 'ゞ' is decomposed like the voiced kana 'ず'.
+        let token = normalize("いすゝ\u{3099}", ProlongedSoundMark::Keep);
+        assert_eq!(token.lemma(), "いすす\u{3099}");
+        assert_eq!(token.char_map, Some(vec![(3, 3), (3, 3), (3, 3), (3, 3)]));
+    }
+
+    #[test]
+    fn prolonged_sound_mark() {
+        assert_eq!(normalize("コーヒー", ProlongedSoundMark::Keep).lemma(), "コーヒー");
+        let token = normalize("コーヒー", ProlongedSoundMark::Strip);
+        assert_eq!(token.lemma(), "コヒ");
+        assert_eq!(token.char_map, Some(vec![(3, 3), (3, 0), (3, 3), (3, 0)]));
+        assert_eq!(normalize("コーヒー", ProlongedSoundMark::Fold).lemma(), "コオヒイ");
+        assert_eq!(normalize("すーぱー", ProlongedSoundMark::Fold).lemma(), "すうぱあ");
+    }
+}
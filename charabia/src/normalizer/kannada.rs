@@ -0,0 +1,67 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Kannada script.
+///
+/// Kannada text is normalized by:
+/// - normalizing the candrabindus ('ಀ', 'ಁ') to the anusvara ('ಂ')
+/// - normalizing the jihvamuliya ('ೱ') and the upadhmaniya ('ೲ'), allophones of the visarga, to the visarga ('ಃ')
+/// - removing the zero width joiners and non-joiners, which only select the rendering of the conjuncts
+/// - normalizing the Kannada digits, like '೧', to the ASCII digits
+pub struct KannadaNormalizer;
+
+impl CharNormalizer for KannadaNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            '\u{C80}' | '\u{C81}' => Some('\u{C82}'.into()),
+            '\u{CF1}' | '\u{CF2}' => Some('\u{C83}'.into()),
+            '\u{200C}' | '\u{200D}' => None,
+            '\u{CE6}'..='\u{CEF}' => Some(char::from(b'0' + (c as u32 - 0xCE6) as u8).into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Kannada && token.lemma().chars().any(is_variant)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Kannada
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_variant(c: char) -> bool {
+    matches!(
+        c,
+        '\u{C80}' | '\u{C81}' | '\u{CF1}' | '\u{CF2}' | '\u{200C}' | '\u{200D}' | '\u{CE6}'
+            ..='\u{CEF}'
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, Tokenize};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.tokenize().map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn kannada() {
+        assert_eq!(lemmas("ಕಁನ್ನಡ"), lemmas("ಕಂನ್ನಡ"));
+        assert_eq!(lemmas("ಅಂತ\u{CF2}ಪುರ"), lemmas("ಅಂತಃಪುರ"));
+        assert_eq!(lemmas("ಕ್\u{200D}ಷ"), lemmas("ಕ್ಷ"));
+
+        let token = "೨೦೨೪".tokenize().next().unwrap();
+        assert_eq!(token.script, Script::Kannada);
+        assert_eq!(token.lemma(), "2024");
+    }
+}
@@ -0,0 +1,105 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Kazakh language, written in the Cyrillic script
+/// and in the Latin alphabet adopted in 2021.
+///
+/// The Cyrillic words are transliterated to the Latin alphabet, like "қазақ" to "qazaq",
+/// without the diacritics that are removed from the Latin words by the `NonspacingMarkNormalizer`,
+/// like "Өскемен" and "Öskemen" both becoming "oskemen".
+/// The dotless 'ı' of the Latin words is normalized to 'i', the capital 'I' being lowercased to 'i'.
+///
+/// The language is not detected, it must be allowed with [`crate::AllowList`].
+pub struct KazakhNormalizer;
+
+impl CharNormalizer for KazakhNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        let transliterated = match c {
+            'а' | 'ә' => "a",
+            'б' => "b",
+            'в' => "v",
+            'г' | 'ғ' => "g",
+            'д' => "d",
+            'е' | 'э' => "e",
+            'ж' => "j",
+            'з' => "z",
+            'и' | 'й' | 'і' | 'ı' => "i",
+            'к' => "k",
+            'қ' => "q",
+            'л' => "l",
+            'м' => "m",
+            'н' | 'ң' => "n",
+            'о' | 'ө' => "o",
+            'п' => "p",
+            'р' => "r",
+            'с' => "s",
+            'т' => "t",
+            'у' | 'ұ' | 'ү' => "u",
+            'ф' => "f",
+            'х' | 'һ' => "h",
+            'ц' => "ts",
+            'ч' => "ch",
+            'ш' => "s",
+            'щ' => "ss",
+            'ы' => "y",
+            'ю' => "iu",
+            'я' => "ia",
+            // the hard and soft signs, and the marks decomposed from 'й' and 'ё'.
+            'ъ' | 'ь' | '\u{306}' | '\u{308}' => return None,
+            _ => return Some(c.into()),
+        };
+
+        Some(transliterated.to_string().into())
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        matches!(token.script, Script::Cyrillic | Script::Latin)
+            && token.language == Some(Language::Kaz)
+            && !token.lemma().is_ascii()
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        matches!(script, Script::Cyrillic | Script::Latin) && language == Language::Kaz
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AllowList, Language, TokenizerBuilder};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        let allow_list = AllowList::new().languages([Language::Kaz]);
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list);
+        let tokenizer = builder.build();
+
+        tokenizer.tokenize(text).map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn kazakh() {
+        assert_eq!(lemmas("Қазақстан Республикасы"), ["qazaqstan", " ", "respublikasy"]);
+        assert_eq!(lemmas("Qazaqstan Respublikasy"), ["qazaqstan", " ", "respublikasy"]);
+        assert_eq!(lemmas("Өскемен Ұлытау Іле"), lemmas("Öskemen Ūlytau Ile"));
+        assert_eq!(lemmas("Айғыз"), lemmas("Aiğyz"));
+    }
+
+    #[test]
+    fn unknown_language() {
+        use crate::normalizer::NormalizerOption;
+        use crate::Normalize;
+
+        // the language of a `&str` is unknown, so the Cyrillic words are not transliterated as Kazakh.
+        let options = NormalizerOption { lossy: true, ..Default::default() };
+        assert_eq!("Привет мир".normalize(&options), "привет мир");
+        assert_eq!("Қазақстан".normalize(&options), "қазақстан");
+    }
+}
@@ -0,0 +1,72 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Central Kurdish (Sorani) language, written in the Arabic script,
+/// replacing the [`ArabicNormalizer`](super::ArabicNormalizer) which would merge the 'ە' vowel with the 'ه' consonant.
+///
+/// Sorani text is normalized by:
+/// - normalizing the Arabic letters typed by the Arabic keyboard layouts to their Kurdish forms:
+///   the Yeh 'ي' and Alef Maksura 'ى' to the Farsi Yeh 'ی', the Kaf 'ك' to the Keheh 'ک'
+///   and the Taa Marbuta 'ة' to the Ae 'ە'
+/// - normalizing the Heh Doachashmee 'ھ' to the Heh 'ه', both writing the 'h' consonant
+/// - removing the Tatweel ('ـ') characters
+///
+/// The language is not detected, it must be allowed with [`crate::AllowList`].
+pub struct KurdishNormalizer;
+
+impl CharNormalizer for KurdishNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            'ـ' => None,
+            'ي' | 'ى' => Some('ی'.into()),
+            'ك' => Some('ک'.into()),
+            'ة' => Some('ە'.into()),
+            'ھ' => Some('ه'.into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Arabic
+            && token.language == Some(Language::Ckb)
+            && token.lemma().chars().any(is_variant)
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Arabic && language == Language::Ckb
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_variant(c: char) -> bool {
+    matches!(c, 'ـ' | 'ي' | 'ى' | 'ك' | 'ة' | 'ھ')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AllowList, Language, TokenizerBuilder};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        let allow_list = AllowList::new().languages([Language::Ckb]);
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list);
+        let tokenizer = builder.build();
+
+        tokenizer.tokenize(text).map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn kurdish() {
+        assert_eq!(lemmas("كوردي"), ["کوردی"]);
+        assert_eq!(lemmas("ھەولێر"), ["هەولێر"]);
+        // the 'ە' vowel is kept distinct from the 'ه' consonant.
+        assert_eq!(lemmas("خانة"), ["خانە"]);
+    }
+}
@@ -1,7 +1,7 @@
 use std::iter::once;
 
 use super::{CharNormalizer, CharOrStr};
-use crate::detection::Script;
+use crate::detection::{Language, Script};
 use crate::Token;
 
 /// A global [`Normalizer`] lowercasing characters.
@@ -27,9 +27,24 @@ impl CharNormalizer for LowercaseNormalizer {
 
     fn should_normalize(&self, token: &Token) -> bool {
         // https://en.wikipedia.org/wiki/Letter_case#Capitalisation
-        matches!(token.script, Script::Latin | Script::Cyrillic | Script::Greek | Script::Georgian)
+        CharNormalizer::supports(self, token.script, Language::Other)
             && token.lemma.chars().any(char::is_uppercase)
     }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        matches!(
+            script,
+            Script::Latin | Script::Cyrillic | Script::Greek | Script::Georgian | Script::Armenian
+        )
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,129 @@
+use std::borrow::Cow;
+
+use super::{identity_char_map, Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+/// Virama of Malayalam, the chandrakkala ('്').
+const VIRAMA: char = '\u{D4D}';
+const ZWJ: char = '\u{200D}';
+
+/// A [`Normalizer`] for the Malayalam script.
+///
+/// The chillus, the consonants without their inherent vowel like 'ൻ', have been encoded as the sequence
+/// of the consonant, a virama, and a zero width joiner before their atomic characters were added to Unicode,
+/// so this Normalizer replaces the sequences by the atomic chillus, e.g. "ന്‍" becomes "ൻ",
+/// to match the documents using both encodings.
+pub struct MalayalamNormalizer;
+
+impl Normalizer for MalayalamNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        let char_map = token
+            .char_map
+            .take()
+            .or_else(|| options.create_char_map.then(|| identity_char_map(token.lemma())));
+
+        // each original character with its normalized version, the identity if there is no char_map.
+        let mut lemma = token.lemma();
+        let chars: Vec<(u8, &str)> = match &char_map {
+            Some(char_map) => char_map
+                .iter()
+                .map(|&(original_len, normalized_len)| {
+                    let (segment, rest) = lemma.split_at(normalized_len as usize);
+                    lemma = rest;
+                    (original_len, segment)
+                })
+                .collect(),
+            None => lemma.char_indices().map(|(i, c)| (0, &lemma[i..i + c.len_utf8()])).collect(),
+        };
+
+        let mut normalized = String::with_capacity(token.lemma.len());
+        let mut new_char_map = Vec::with_capacity(chars.len());
+        let mut i = 0;
+        while i < chars.len() {
+            let chillu = match chars[i..] {
+                [(_, consonant), (_, virama), (_, zwj), ..]
+                    if virama.starts_with(VIRAMA) && zwj.starts_with(ZWJ) =>
+                {
+                    consonant.chars().next().and_then(chillu)
+                }
+                _ => None,
+            };
+
+            match chillu {
+                Some(chillu) => {
+                    normalized.push(chillu);
+                    new_char_map.push((chars[i].0, chillu.len_utf8() as u8));
+                    new_char_map.extend(chars[i + 1..i + 3].iter().map(|&(len, _)| (len, 0)));
+                    i += 3;
+                }
+                None => {
+                    normalized.push_str(chars[i].1);
+                    new_char_map.push((chars[i].0, chars[i].1.len() as u8));
+                    i += 1;
+                }
+            }
+        }
+
+        token.lemma = Cow::Owned(normalized);
+        token.char_map = char_map.map(|_| new_char_map);
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Malayalam && token.lemma().contains([VIRAMA, ZWJ].as_slice())
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Malayalam
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the atomic chillu of the provided consonant, if any.
+fn chillu(consonant: char) -> Option<char> {
+    match consonant {
+        'ണ' => Some('ൺ'),
+        'ന' => Some('ൻ'),
+        'ര' => Some('ർ'),
+        'ല' => Some('ൽ'),
+        'ള' => Some('ൾ'),
+        'ക' => Some('ൿ'),
+        'മ' => Some('ൔ'),
+        'യ' => Some('ൕ'),
+        'ഴ' => Some('ൖ'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Tokenize, TokenizerBuilder};
+
+    #[test]
+    fn malayalam() {
+        let sequences: Vec<_> =
+            "അവന്\u{200D} കേരളത്തില്\u{200D}".tokenize().map(|t| t.lemma).collect();
+        let atomic: Vec<_> = "അവൻ കേരളത്തിൽ".tokenize().map(|t| t.lemma).collect();
+        assert_eq!(sequences, atomic);
+
+        // the ZWNJ selects the visible virama and is not a chillu.
+        let token = "അവന്\u{200C}".tokenize().next().unwrap();
+        assert_eq!(token.lemma(), "അവന്\u{200C}");
+    }
+
+    #[test]
+    fn malayalam_char_map() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "അവന്\u{200D}";
+        let token = tokenizer.tokenize(text).next().unwrap();
+        assert_eq!(token.lemma(), "അവൻ");
+        assert_eq!(token.char_map, Some(vec![(3, 3), (3, 3), (3, 3), (3, 0), (3, 0)]));
+        assert_eq!(token.validate(text), Ok(()));
+    }
+}
@@ -1,42 +1,160 @@
 use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::time::Instant;
 
 use once_cell::sync::Lazy;
 
 pub use self::arabic::ArabicNormalizer;
+#[cfg(feature = "armenian")]
+pub use self::armenian::ArmenianNormalizer;
+#[cfg(feature = "bengali")]
+pub use self::bengali::BengaliNormalizer;
+#[cfg(feature = "cherokee")]
+pub use self::cherokee::CherokeeNormalizer;
 #[cfg(feature = "chinese")]
 pub use self::chinese::ChineseNormalizer;
-pub use self::classify::{Classifier, ClassifierOption};
+pub use self::cjk_numeral::CjkNumeralNormalizer;
+pub use self::classify::{ClassifiedTokenIter, Classifier, ClassifierOption, Classify};
 pub use self::compatibility_decomposition::CompatibilityDecompositionNormalizer;
 pub use self::control_char::ControlCharNormalizer;
+pub use self::emoji::EmojiNormalizer;
+#[cfg(feature = "esperanto")]
+pub use self::esperanto::EsperantoNormalizer;
+#[cfg(feature = "georgian")]
+pub use self::georgian::GeorgianNormalizer;
 #[cfg(feature = "greek")]
 use self::greek::GreekNormalizer;
+#[cfg(feature = "gujarati")]
+pub use self::gujarati::GujaratiNormalizer;
+#[cfg(feature = "gurmukhi")]
+pub use self::gurmukhi::GurmukhiNormalizer;
+#[cfg(feature = "hungarian")]
+pub use self::hungarian::HungarianNormalizer;
 #[cfg(feature = "japanese-transliteration")]
 pub use self::japanese::JapaneseNormalizer;
+pub use self::japanese_marks::{JapaneseMarksNormalizer, ProlongedSoundMark};
+#[cfg(feature = "kannada")]
+pub use self::kannada::KannadaNormalizer;
+#[cfg(feature = "kazakh")]
+pub use self::kazakh::KazakhNormalizer;
+#[cfg(feature = "kurdish")]
+pub use self::kurdish::KurdishNormalizer;
 pub use self::lowercase::LowercaseNormalizer;
+#[cfg(feature = "malayalam")]
+pub use self::malayalam::MalayalamNormalizer;
+#[cfg(feature = "mongolian")]
+pub use self::mongolian::MongolianNormalizer;
 use self::nonspacing_mark::NonspacingMarkNormalizer;
+#[cfg(feature = "odia")]
+pub use self::odia::OdiaNormalizer;
+#[cfg(feature = "pashto")]
+pub use self::pashto::PashtoNormalizer;
+#[cfg(feature = "phonetic")]
+pub use self::phonetic::{PhoneticAlgorithm, PhoneticNormalizer, PhoneticOption};
 use self::quote::QuoteNormalizer;
-use crate::segmenter::SegmentedTokenIter;
-use crate::Token;
+pub use self::romanization::RomanizationNormalizer;
+#[cfg(feature = "serbian")]
+pub use self::serbian::SerbianNormalizer;
+#[cfg(feature = "sinhala")]
+pub use self::sinhala::SinhalaNormalizer;
+pub use self::skeleton::SkeletonNormalizer;
+#[cfg(feature = "stemming")]
+pub use self::stemming::StemmingNormalizer;
+#[cfg(feature = "tamil")]
+pub use self::tamil::TamilNormalizer;
+#[cfg(feature = "telugu")]
+pub use self::telugu::TeluguNormalizer;
+#[cfg(feature = "tifinagh")]
+pub use self::tifinagh::TifinaghNormalizer;
+#[cfg(feature = "urdu")]
+pub use self::urdu::UrduNormalizer;
+#[cfg(feature = "uyghur")]
+pub use self::uyghur::UyghurNormalizer;
+use crate::detection::{Language, Script};
+use crate::metrics::DocumentMetrics;
+use crate::segmenter::{Segment, SegmentedTokenIter};
+use crate::{SeparatorKind, Token, TokenKind};
 
 mod arabic;
+#[cfg(feature = "armenian")]
+mod armenian;
+#[cfg(feature = "bengali")]
+mod bengali;
+#[cfg(feature = "cherokee")]
+mod cherokee;
 #[cfg(feature = "chinese")]
 mod chinese;
+mod cjk_numeral;
 mod classify;
 mod compatibility_decomposition;
 mod control_char;
+mod emoji;
+#[cfg(feature = "esperanto")]
+mod esperanto;
+#[cfg(feature = "georgian")]
+mod georgian;
 #[cfg(feature = "greek")]
 mod greek;
+#[cfg(feature = "gujarati")]
+mod gujarati;
+#[cfg(feature = "gurmukhi")]
+mod gurmukhi;
+#[cfg(feature = "hungarian")]
+mod hungarian;
 #[cfg(feature = "japanese-transliteration")]
 mod japanese;
+mod japanese_marks;
+#[cfg(feature = "kannada")]
+mod kannada;
+#[cfg(feature = "kazakh")]
+mod kazakh;
+#[cfg(feature = "kurdish")]
+mod kurdish;
 mod lowercase;
+#[cfg(feature = "malayalam")]
+mod malayalam;
+#[cfg(feature = "mongolian")]
+mod mongolian;
 mod nonspacing_mark;
+#[cfg(feature = "odia")]
+mod odia;
+#[cfg(feature = "pashto")]
+mod pashto;
+#[cfg(feature = "phonetic")]
+mod phonetic;
 mod quote;
+mod romanization;
+#[cfg(feature = "serbian")]
+mod serbian;
+#[cfg(feature = "sinhala")]
+mod sinhala;
+mod skeleton;
+#[cfg(feature = "stemming")]
+mod stemming;
+#[cfg(feature = "tamil")]
+mod tamil;
+#[cfg(feature = "telugu")]
+mod telugu;
+#[cfg(feature = "tifinagh")]
+mod tifinagh;
+#[cfg(feature = "urdu")]
+mod urdu;
+#[cfg(feature = "uyghur")]
+mod uyghur;
 
 /// List of [`Normalizer`]s used by [`Normalize::normalize`] that are not considered lossy.
 pub static NORMALIZERS: Lazy<Vec<Box<dyn Normalizer>>> = Lazy::new(|| {
     vec![
         Box::new(CompatibilityDecompositionNormalizer),
         Box::new(ControlCharNormalizer),
+        #[cfg(feature = "malayalam")]
+        Box::new(MalayalamNormalizer),
+        #[cfg(feature = "sinhala")]
+        Box::new(SinhalaNormalizer),
+        #[cfg(feature = "tifinagh")]
+        Box::new(TifinaghNormalizer),
+        #[cfg(feature = "uyghur")]
+        Box::new(UyghurNormalizer),
         Box::new(Classifier),
     ]
 });
@@ -45,44 +163,445 @@ pub static NORMALIZERS: Lazy<Vec<Box<dyn Normalizer>>> = Lazy::new(|| {
 pub static LOSSY_NORMALIZERS: Lazy<Vec<Box<dyn Normalizer>>> = Lazy::new(|| {
     vec![
         Box::new(LowercaseNormalizer),
+        #[cfg(feature = "armenian")]
+        Box::new(ArmenianNormalizer),
+        #[cfg(feature = "cherokee")]
+        Box::new(CherokeeNormalizer),
+        #[cfg(feature = "georgian")]
+        Box::new(GeorgianNormalizer),
         Box::new(QuoteNormalizer),
         #[cfg(feature = "chinese")]
         Box::new(ChineseNormalizer),
+        Box::new(JapaneseMarksNormalizer),
         #[cfg(feature = "japanese-transliteration")]
         Box::new(JapaneseNormalizer),
         #[cfg(feature = "greek")]
         Box::new(GreekNormalizer),
+        #[cfg(feature = "serbian")]
+        Box::new(SerbianNormalizer),
         Box::new(ArabicNormalizer),
+        #[cfg(feature = "urdu")]
+        Box::new(UrduNormalizer),
+        #[cfg(feature = "kurdish")]
+        Box::new(KurdishNormalizer),
+        #[cfg(feature = "pashto")]
+        Box::new(PashtoNormalizer),
+        #[cfg(feature = "bengali")]
+        Box::new(BengaliNormalizer),
+        #[cfg(feature = "gurmukhi")]
+        Box::new(GurmukhiNormalizer),
+        #[cfg(feature = "gujarati")]
+        Box::new(GujaratiNormalizer),
+        #[cfg(feature = "odia")]
+        Box::new(OdiaNormalizer),
+        #[cfg(feature = "tamil")]
+        Box::new(TamilNormalizer),
+        #[cfg(feature = "telugu")]
+        Box::new(TeluguNormalizer),
+        #[cfg(feature = "kannada")]
+        Box::new(KannadaNormalizer),
+        #[cfg(feature = "mongolian")]
+        Box::new(MongolianNormalizer),
+        #[cfg(feature = "hungarian")]
+        Box::new(HungarianNormalizer),
+        #[cfg(feature = "esperanto")]
+        Box::new(EsperantoNormalizer),
         Box::new(NonspacingMarkNormalizer),
+        #[cfg(feature = "kazakh")]
+        Box::new(KazakhNormalizer),
+        Box::new(EmojiNormalizer),
+        #[cfg(feature = "stemming")]
+        Box::new(StemmingNormalizer),
+        #[cfg(feature = "phonetic")]
+        Box::new(PhoneticNormalizer),
+        Box::new(SkeletonNormalizer),
+        Box::new(RomanizationNormalizer),
+        Box::new(CjkNumeralNormalizer),
     ]
 });
 
+/// [`NORMALIZERS`] that may modify the Tokens whose lemma is normalized ASCII, see [`is_normalized_ascii`].
+static ASCII_NORMALIZERS: Lazy<Vec<&'static dyn Normalizer>> = Lazy::new(|| {
+    NORMALIZERS.iter().filter(|n| !n.preserves_normalized_ascii()).map(|n| n.as_ref()).collect()
+});
+
+/// [`LOSSY_NORMALIZERS`] that may modify the Tokens whose lemma is normalized ASCII, see [`is_normalized_ascii`].
+static ASCII_LOSSY_NORMALIZERS: Lazy<Vec<&'static dyn Normalizer>> = Lazy::new(|| {
+    LOSSY_NORMALIZERS
+        .iter()
+        .filter(|n| !n.preserves_normalized_ascii())
+        .map(|n| n.as_ref())
+        .collect()
+});
+
+/// [`NORMALIZERS`] applied by the [`Normalize`] implementation of `&str`, see [`needs_language`].
+static STR_NORMALIZERS: Lazy<Vec<&'static dyn Normalizer>> = Lazy::new(|| {
+    NORMALIZERS.iter().filter(|n| !needs_language(n.as_ref())).map(|n| n.as_ref()).collect()
+});
+
+/// [`LOSSY_NORMALIZERS`] applied by the [`Normalize`] implementation of `&str`, see [`needs_language`].
+static STR_LOSSY_NORMALIZERS: Lazy<Vec<&'static dyn Normalizer>> = Lazy::new(|| {
+    LOSSY_NORMALIZERS.iter().filter(|n| !needs_language(n.as_ref())).map(|n| n.as_ref()).collect()
+});
+
+/// Returns true if the normalizer is specialized for some languages and doesn't support any script
+/// when the language is unknown, like the Hungarian or Serbian ones.
+///
+/// These normalizers are skipped when normalizing a `&str`, whose language is unknown.
+fn needs_language(normalizer: &dyn Normalizer) -> bool {
+    !Script::all().iter().any(|script| normalizer.supports(*script, Language::Other))
+}
+
+/// Returns true if the lemma is only made of lowercase ASCII letters, digits, punctuation and spaces,
+/// which most of the normalizers leave unchanged, see [`Normalizer::preserves_normalized_ascii`].
+pub fn is_normalized_ascii(lemma: &str) -> bool {
+    // the bytes are checked in bulk, uppercase letters and control characters being the only ASCII bytes excluded.
+    lemma.bytes().all(|b| matches!(b, b' '..=b'@' | b'['..=b'~'))
+}
+
+/// Returns the list of [`Normalizer`]s that could be applied on a text of the provided [`Script`] and [`Language`],
+/// in the order they are applied.
+///
+/// The normalizers considered as lossy, listed in [`LOSSY_NORMALIZERS`], are listed after the other ones
+/// and are only applied when the lossy normalization is enabled.
+///
+/// # Example
+///
+/// ```
+/// use charabia::{normalizers_for, Language, Script};
+///
+/// let normalizers: Vec<_> =
+///     normalizers_for(Script::Arabic, Language::Ara).iter().map(|n| n.name()).collect();
+/// assert!(normalizers.contains(&"ArabicNormalizer"));
+/// assert!(!normalizers.contains(&"LowercaseNormalizer"));
+/// ```
+pub fn normalizers_for(script: Script, language: Language) -> Vec<&'static dyn Normalizer> {
+    NORMALIZERS
+        .iter()
+        .chain(LOSSY_NORMALIZERS.iter())
+        .filter(|normalizer| normalizer.supports(script, language))
+        .map(|normalizer| normalizer.as_ref())
+        .collect()
+}
+
 pub(crate) const DEFAULT_NORMALIZER_OPTION: NormalizerOption = NormalizerOption {
     create_char_map: false,
     lossy: true,
-    classifier: ClassifierOption { stop_words: None, separators: None },
+    classifier: ClassifierOption { stop_words: None, separators: None, separator_set: None },
+    query_prefix: false,
+    query_phrase: false,
+    skip_classification: false,
+    skeleton: false,
+    romanize: false,
+    emoji_names: false,
+    cjk_numerals: false,
+    prolonged_sound_mark: ProlongedSoundMark::Keep,
+    keep_original: false,
+    blank_input: BlankInput::Keep,
+    stemming: false,
+    esperanto_h_system: false,
+    #[cfg(feature = "hungarian")]
+    hungarian_keep_umlauts: false,
+    #[cfg(feature = "phonetic")]
+    phonetic: None,
 };
 
 /// Iterator over Normalized [`Token`]s.
 pub struct NormalizedTokenIter<'o, 'tb> {
     token_iter: SegmentedTokenIter<'o, 'tb>,
     options: &'tb NormalizerOption<'tb>,
+    /// tokens normalized in advance to find the last word when `query_prefix` is enabled.
+    pending: VecDeque<Token<'o>>,
+    finished: bool,
+    /// index of the quoted phrase being read when `query_phrase` is enabled.
+    phrase: Option<usize>,
+    phrase_count: usize,
+    /// statistics of the consumed tokens, only measured when an observer is set.
+    metrics: Option<DocumentMetrics>,
+    /// blank tokens read in advance to find a word when `blank_input` is not `BlankInput::Keep`.
+    leading: VecDeque<Token<'o>>,
+    /// a word has been found, or the whole text is blank and has been handled.
+    blank_checked: bool,
+}
+
+impl<'o> NormalizedTokenIter<'o, '_> {
+    /// Returns true if the tokenization stopped early because of the limits set with
+    /// [`crate::TokenizerBuilder::max_input_bytes`] or [`crate::TokenizerBuilder::max_tokens`].
+    ///
+    /// The returned value is only final once the iterator is exhausted.
+    pub fn is_truncated(&self) -> bool {
+        self.token_iter.is_truncated()
+    }
+
+    fn next_normalized(&mut self) -> Option<Token<'o>> {
+        let start = self.metrics.as_ref().map(|_| Instant::now());
+        let mut token = self.token_iter.next()?;
+        let segmented = start.map(|start| (start, Instant::now()));
+        token = if self.token_iter.is_protected() {
+            Token { kind: TokenKind::Word, ..token }
+        } else {
+            token.normalize(self.options)
+        };
+        if let (Some(metrics), Some((start, segmented))) = (&mut self.metrics, segmented) {
+            metrics.segmentation += segmented - start;
+            metrics.normalization += segmented.elapsed();
+        }
+        if self.options.query_phrase {
+            if token.is_separator() {
+                // each double quote opens or closes a phrase.
+                for _ in token.lemma.chars().filter(|c| matches!(c, '"' | '“' | '”')) {
+                    self.phrase = match self.phrase {
+                        Some(_) => None,
+                        None => {
+                            self.phrase_count += 1;
+                            Some(self.phrase_count - 1)
+                        }
+                    };
+                }
+            } else {
+                token.phrase = self.phrase;
+            }
+        }
+
+        Some(token)
+    }
 }
 
 impl<'o> Iterator for NormalizedTokenIter<'o, '_> {
     type Item = Token<'o>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(self.token_iter.next()?.normalize(self.options))
+        let token = match self.options.blank_input {
+            BlankInput::Keep => self.next_token(),
+            _ => self.next_non_blank(),
+        };
+        if let (Some(metrics), Some(token)) = (&mut self.metrics, &token) {
+            metrics.count(token);
+        }
+        token
+    }
+}
+
+impl Drop for NormalizedTokenIter<'_, '_> {
+    fn drop(&mut self) {
+        if let (Some(metrics), Some(observer)) = (&self.metrics, self.token_iter.observer()) {
+            observer.on_document(metrics);
+        }
+    }
+}
+
+impl<'o> NormalizedTokenIter<'o, '_> {
+    /// Reads the tokens in advance until a word is found,
+    /// the whole text being skipped or merged in a single separator if it is blank.
+    fn next_non_blank(&mut self) -> Option<Token<'o>> {
+        if !self.blank_checked {
+            while let Some(token) = self.next_token() {
+                let blank = is_blank(&token);
+                self.leading.push_back(token);
+                if !blank {
+                    self.blank_checked = true;
+                    break;
+                }
+            }
+
+            if !self.blank_checked {
+                self.blank_checked = true;
+                let leading = std::mem::take(&mut self.leading);
+                return match self.options.blank_input {
+                    BlankInput::SingleSeparator => merge_blank_tokens(leading),
+                    _ => None,
+                };
+            }
+        }
+
+        self.leading.pop_front().or_else(|| self.next_token())
+    }
+
+    fn next_token(&mut self) -> Option<Token<'o>> {
+        if !self.options.query_prefix {
+            return self.next_normalized();
+        }
+
+        // keep the tokens that may be part of the last word until the end of the text is reached.
+        while !self.finished && self.pending.len() <= last_word_len(&self.pending) {
+            match self.next_normalized() {
+                Some(token) => self.pending.push_back(token),
+                None => {
+                    self.finished = true;
+                    mark_last_word_as_prefix(&mut self.pending);
+                }
+            }
+        }
+
+        self.pending.pop_front()
     }
 }
 
+/// Returns true if the token is a separator or is only made of whitespaces, like a tabulation.
+fn is_blank(token: &Token) -> bool {
+    token.is_separator() || token.lemma().chars().all(char::is_whitespace)
+}
+
+/// Merges the blank tokens of a text in a single separator covering the whole text.
+fn merge_blank_tokens<'o>(tokens: VecDeque<Token<'o>>) -> Option<Token<'o>> {
+    let mut tokens = tokens.into_iter();
+    let mut merged = tokens.next()?;
+    let mut hard = merged.separator_kind() == Some(SeparatorKind::Hard);
+    for token in tokens {
+        hard |= token.separator_kind() == Some(SeparatorKind::Hard);
+        if merged.char_map.is_some() || token.char_map.is_some() {
+            let char_map = merged.char_map.get_or_insert_with(|| identity_char_map(&merged.lemma));
+            char_map.extend(token.char_map.unwrap_or_else(|| identity_char_map(&token.lemma)));
+        }
+        if let (Some(original), Some(other)) = (&mut merged.original, token.original) {
+            original.to_mut().push_str(&other);
+        }
+        merged.lemma.to_mut().push_str(&token.lemma);
+        merged.char_end = token.char_end;
+        merged.byte_end = token.byte_end;
+    }
+    merged.kind =
+        TokenKind::Separator(if hard { SeparatorKind::Hard } else { SeparatorKind::Soft });
+    Some(merged)
+}
+
+/// Returns true if the token is a single CJK character,
+/// which is likely to be a fragment of an incomplete word when it ends a query.
+fn is_cj_fragment(token: &Token) -> bool {
+    token.script == Script::Cj && !token.is_separator() && token.original_char_count() == 1
+}
+
+/// Returns the number of tokens at the end of the queue that may be part of the last word.
+fn last_word_len(tokens: &VecDeque<Token>) -> usize {
+    match tokens.back() {
+        Some(token) if is_cj_fragment(token) => 2,
+        _ => 1,
+    }
+}
+
+/// Flag the last token as a prefix if it's not a separator.
+///
+/// A trailing CJK fragment is merged with the preceding CJK word instead of committing to a word boundary,
+/// for instance, "北京大" is segmented as "北京" and "大" but is likely to be the beginning of "北京大学".
+fn mark_last_word_as_prefix(tokens: &mut VecDeque<Token>) {
+    let len = tokens.len();
+    if len >= 2
+        && is_cj_fragment(&tokens[len - 1])
+        && tokens[len - 2].script == Script::Cj
+        && !tokens[len - 2].is_separator()
+    {
+        let fragment = tokens.pop_back().unwrap();
+        let last = tokens.back_mut().unwrap();
+        if last.char_map.is_some() || fragment.char_map.is_some() {
+            let char_map = last.char_map.get_or_insert_with(|| identity_char_map(&last.lemma));
+            char_map
+                .extend(fragment.char_map.unwrap_or_else(|| identity_char_map(&fragment.lemma)));
+        }
+        if let (Some(original), Some(other)) = (&mut last.original, fragment.original) {
+            original.to_mut().push_str(&other);
+        }
+        last.lemma.to_mut().push_str(&fragment.lemma);
+        last.char_end = fragment.char_end;
+        last.byte_end = fragment.byte_end;
+        last.kind = TokenKind::Word;
+    }
+
+    if let Some(last) = tokens.back_mut() {
+        last.is_prefix = !last.is_separator();
+    }
+}
+
+/// Replace the lemma of the token by a lemma that can't be aligned character by character with the current one,
+/// like a stem or a phonetic key.
+///
+/// The bytes of the new lemma are assigned to the first characters of the current lemma in the `char_map`
+/// and the remaining characters are mapped to an empty string.
+#[cfg(any(feature = "stemming", feature = "phonetic", feature = "hungarian"))]
+fn replace_lemma(token: &mut Token, lemma: String, options: &NormalizerOption) {
+    if options.create_char_map {
+        let mut char_map =
+            token.char_map.take().unwrap_or_else(|| identity_char_map(token.lemma()));
+
+        let mut remaining = lemma.len();
+        for (_, normalized_len) in char_map.iter_mut() {
+            let len = remaining.min(*normalized_len as usize);
+            *normalized_len = len as u8;
+            remaining -= len;
+        }
+
+        // the new lemma may be longer than the current one, e.g. "ß" is stemmed as "ss" in German.
+        if let Some((_, normalized_len)) = char_map.last_mut() {
+            *normalized_len += remaining as u8;
+        }
+
+        token.char_map = Some(char_map);
+    }
+
+    token.lemma = Cow::Owned(lemma);
+}
+
+/// Returns the `char_map` of a lemma that has not been normalized.
+pub(crate) fn identity_char_map(lemma: &str) -> Vec<(u8, u8)> {
+    lemma.chars().map(|c| (c.len_utf8() as u8, c.len_utf8() as u8)).collect()
+}
+
+/// Tokens emitted for a text without any word, only made of separators and whitespaces, like "  " or " - ",
+/// see [`TokenizerBuilder::blank_input`](crate::TokenizerBuilder::blank_input).
+///
+/// An empty text never gives any token.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlankInput {
+    /// emit the tokens of the text as for any other text.
+    #[default]
+    Keep,
+    /// emit no token.
+    Skip,
+    /// emit a single separator token covering the whole text,
+    /// hard if the text contains a hard separator, soft otherwise.
+    SingleSeparator,
+}
+
 /// Structure for providing options to a normalizer.
 #[derive(Debug, Clone, Default)]
 pub struct NormalizerOption<'tb> {
+    /// Create the `char_map` of the normalized tokens, see [`Token::char_map`].
+    ///
+    /// When disabled, no normalizer computes a `char_map`,
+    /// which saves time and memory for the consumers that never highlight the original text.
     pub create_char_map: bool,
     pub classifier: ClassifierOption<'tb>,
     pub lossy: bool,
+    /// Flag the last word of the text as a potential prefix, see [`Token::is_prefix`].
+    pub query_prefix: bool,
+    /// Group the words of double-quoted spans in phrases, see [`Token::phrase`].
+    pub query_phrase: bool,
+    /// Leave the [`TokenKind`] of the tokens to [`TokenKind::Unknown`] instead of classifying them,
+    /// for consumers doing their own classification.
+    pub skip_classification: bool,
+    /// Compute the skeleton of the words, see [`SkeletonNormalizer`].
+    pub skeleton: bool,
+    /// Compute the romanization of the Greek, Cyrillic and Hangul words, see [`RomanizationNormalizer`].
+    pub romanize: bool,
+    /// Replace the emoji by their name, see [`EmojiNormalizer`].
+    pub emoji_names: bool,
+    /// Compute the ASCII digits version of the CJK numerals, see [`CjkNumeralNormalizer`].
+    pub cjk_numerals: bool,
+    /// Handling of the Japanese prolonged sound mark 'ー', see [`JapaneseMarksNormalizer`].
+    pub prolonged_sound_mark: ProlongedSoundMark,
+    /// Keep the original lemma of the tokens before their normalization, see [`Token::original`].
+    pub keep_original: bool,
+    /// Tokens emitted for a text only made of separators and whitespaces, see [`BlankInput`].
+    pub blank_input: BlankInput,
+    /// Stem the words of the languages enabled by the `stemming-*` features, see `StemmingNormalizer`.
+    pub stemming: bool,
+    /// Fold the Esperanto h-system into the x-system, see `EsperantoNormalizer`.
+    pub esperanto_h_system: bool,
+    /// Keep the Hungarian umlauts when folding the long vowels, see [`HungarianNormalizer`].
+    #[cfg(feature = "hungarian")]
+    pub hungarian_keep_umlauts: bool,
+    /// Compute the phonetic keys of the words, see [`PhoneticNormalizer`].
+    #[cfg(feature = "phonetic")]
+    pub phonetic: Option<PhoneticOption>,
 }
 
 /// Trait defining a normalizer.
@@ -96,11 +615,43 @@ pub trait Normalizer: Sync + Send {
     ///
     /// Some normalizer are specialized for a `Script` or/and a `Language` and shouldn't be called on every `Token`s.
     fn should_normalize(&self, token: &Token) -> bool;
+
+    /// Return true if the normalizer may process Tokens of a specific [`Script`] and [`Language`],
+    /// `Language::Other` meaning that the language is unknown.
+    ///
+    /// Unlike [`Normalizer::should_normalize`], this doesn't depend on the content of a Token,
+    /// it is used to list the normalizers specialized for a `Script` or/and a `Language`.
+    fn supports(&self, _script: Script, _language: Language) -> bool {
+        true
+    }
+
+    /// Return true if the normalizer never modifies a Token whose lemma is only made of
+    /// lowercase ASCII letters, digits, punctuation and spaces, see [`is_normalized_ascii`].
+    ///
+    /// Such normalizers are skipped on these Tokens, which are the most common ones in English-heavy texts.
+    fn preserves_normalized_ascii(&self) -> bool {
+        false
+    }
+
+    /// Return true if the normalizer is lossy, destroying distinctions between the words,
+    /// like the lowercasing or the removal of the diacritics, listed in [`LOSSY_NORMALIZERS`],
+    /// or lossless, like the compatibility decomposition, listed in [`NORMALIZERS`].
+    ///
+    /// The lossy normalizers are only applied when the lossy normalization is enabled,
+    /// see [`Tokenizer::tokenize_lossless`](crate::Tokenizer::tokenize_lossless) to only apply the lossless ones.
+    fn is_lossy(&self) -> bool {
+        false
+    }
+
+    /// Returns the name of the normalizer, by default the name of the implementing type.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>().rsplit("::").next().unwrap_or_default()
+    }
 }
 
 // Allow taking &Cow as argument to spare the allocation if it is already borrowed (and thus ~Copy)
 #[allow(clippy::ptr_arg)]
-fn shrink_cow<'o>(s: &Cow<'o, str>, new_size: usize) -> Cow<'o, str> {
+pub(crate) fn shrink_cow<'o>(s: &Cow<'o, str>, new_size: usize) -> Cow<'o, str> {
     match s {
         Cow::Borrowed(s) => Cow::Borrowed(&s[..new_size]),
         Cow::Owned(s) => Cow::Owned(s[..new_size].to_string()),
@@ -111,33 +662,25 @@ pub trait CharNormalizer: Sync + Send {
     fn normalize_char(&self, c: char) -> Option<CharOrStr>;
 
     fn normalize_cow_str<'o>(&self, s: Cow<'o, str>) -> Cow<'o, str> {
-        let mut new: Option<Cow<str>> = None;
+        let mut new: Option<String> = None;
 
         for (i, c) in s.char_indices() {
-            new = match self.normalize_char(c) {
-                Some(CharOrStr::Char(normalized)) if normalized == c => {
-                    new.take().map(|mut new| {
-                        new.to_mut().push(normalized);
-                        new
-                    })
-                }
-                Some(CharOrStr::Char(normalized)) => {
-                    new.take().or_else(|| Some(shrink_cow(&s, i))).map(|mut new| {
-                        new.to_mut().push(normalized);
-                        new
-                    })
-                }
-                Some(CharOrStr::Str(normalized)) => {
-                    new.take().or_else(|| Some(shrink_cow(&s, i))).map(|mut new| {
-                        new.to_mut().push_str(&normalized);
-                        new
-                    })
-                }
-                None => new.take().or_else(|| Some(shrink_cow(&s, i))),
+            let normalized = self.normalize_char(c);
+            if new.is_none() && matches!(normalized, Some(CharOrStr::Char(n)) if n == c) {
+                continue;
             }
+
+            // the normalized lemma is only allocated once, at the first normalized char,
+            // with enough capacity to avoid reallocations when the lemma doesn't grow.
+            let new = new.get_or_insert_with(|| {
+                let mut new = String::with_capacity(s.len());
+                new.push_str(&s[..i]);
+                new
+            });
+            push_normalized_char(new, normalized);
         }
 
-        new.unwrap_or(s)
+        new.map_or(s, Cow::Owned)
     }
 
     fn normalize_str<'o>(&self, s: &'o str) -> Cow<'o, str> {
@@ -148,6 +691,23 @@ pub trait CharNormalizer: Sync + Send {
     ///
     /// Some normalizer are specialized for a `Script` or/and a `Language` and shouldn't be called on every `Token`s.
     fn should_normalize(&self, token: &Token) -> bool;
+
+    /// Return true if the normalizer may process Tokens of a specific [`Script`] and [`Language`],
+    /// see [`Normalizer::supports`].
+    fn supports(&self, _script: Script, _language: Language) -> bool {
+        true
+    }
+
+    /// Return true if the normalizer never modifies the normalized ASCII Tokens,
+    /// see [`Normalizer::preserves_normalized_ascii`].
+    fn preserves_normalized_ascii(&self) -> bool {
+        false
+    }
+
+    /// Return true if the normalizer is lossy, see [`Normalizer::is_lossy`].
+    fn is_lossy(&self) -> bool {
+        false
+    }
 }
 
 impl<T> Normalizer for T
@@ -158,28 +718,28 @@ where
         if options.create_char_map {
             match token.char_map.take() {
                 Some(mut char_map) => {
-                    let mut lemma = String::new();
+                    let mut lemma = String::with_capacity(token.lemma.len());
                     let mut tail = token.lemma.as_ref();
                     for (_, normalized_len) in char_map.iter_mut() {
                         let (head, t) = tail.split_at(*normalized_len as usize);
                         tail = t;
-                        let normalized = self.normalize_str(head);
-                        *normalized_len = normalized.len() as u8;
-                        lemma.push_str(normalized.as_ref());
+                        let start = lemma.len();
+                        for c in head.chars() {
+                            push_normalized_char(&mut lemma, self.normalize_char(c));
+                        }
+                        *normalized_len = (lemma.len() - start) as u8;
                     }
 
                     token.lemma = Cow::Owned(lemma);
                     token.char_map = Some(char_map);
                 }
                 None => {
-                    let mut buffer = [0; 4];
-                    let mut char_map = Vec::new();
-                    let mut lemma = String::new();
+                    let mut char_map = Vec::with_capacity(token.lemma.len());
+                    let mut lemma = String::with_capacity(token.lemma.len());
                     for c in token.lemma().chars() {
-                        let char_str = c.encode_utf8(&mut buffer);
-                        let normalized = self.normalize_str(char_str);
-                        char_map.push((char_str.len() as u8, normalized.len() as u8));
-                        lemma.push_str(normalized.as_ref());
+                        let start = lemma.len();
+                        push_normalized_char(&mut lemma, self.normalize_char(c));
+                        char_map.push((c.len_utf8() as u8, (lemma.len() - start) as u8));
                     }
 
                     token.lemma = Cow::Owned(lemma);
@@ -196,6 +756,27 @@ where
     fn should_normalize(&self, token: &Token) -> bool {
         CharNormalizer::should_normalize(self, token)
     }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        CharNormalizer::supports(self, script, language)
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        CharNormalizer::preserves_normalized_ascii(self)
+    }
+
+    fn is_lossy(&self) -> bool {
+        CharNormalizer::is_lossy(self)
+    }
+}
+
+/// Pushes the normalized char in the lemma, nothing being pushed if the char is removed.
+fn push_normalized_char(lemma: &mut String, normalized: Option<CharOrStr>) {
+    match normalized {
+        Some(CharOrStr::Char(c)) => lemma.push(c),
+        Some(CharOrStr::Str(s)) => lemma.push_str(&s),
+        None => (),
+    }
 }
 
 pub enum CharOrStr {
@@ -220,7 +801,18 @@ impl<'o, 'tb> SegmentedTokenIter<'o, 'tb> {
     ///
     /// A Latin `Token` would not be normalized the same as a Chinese `Token`.
     pub fn normalize(self, options: &'tb NormalizerOption<'tb>) -> NormalizedTokenIter<'o, 'tb> {
-        NormalizedTokenIter { token_iter: self, options }
+        let metrics = self.observer().map(|_| DocumentMetrics::default());
+        NormalizedTokenIter {
+            token_iter: self,
+            options,
+            pending: VecDeque::new(),
+            finished: false,
+            phrase: None,
+            phrase_count: 0,
+            metrics,
+            leading: VecDeque::new(),
+            blank_checked: false,
+        }
     }
 }
 
@@ -236,6 +828,29 @@ impl Normalize for Token<'_> {
     ///
     /// A Latin `Token` would not be normalized the same as a Chinese `Token`.
     fn normalize(mut self, options: &NormalizerOption) -> Self::Item {
+        if options.keep_original && self.original.is_none() {
+            self.original = Some(self.lemma.clone());
+        }
+
+        // fast path skipping the normalizers that have nothing to do on the normalized ASCII Tokens.
+        if is_normalized_ascii(self.lemma()) {
+            for normalizer in ASCII_NORMALIZERS.iter() {
+                if normalizer.should_normalize(&self) {
+                    self = normalizer.normalize(self, options);
+                }
+            }
+
+            if options.lossy {
+                for normalizer in ASCII_LOSSY_NORMALIZERS.iter() {
+                    if normalizer.should_normalize(&self) {
+                        self = normalizer.normalize(self, options);
+                    }
+                }
+            }
+
+            return self;
+        }
+
         for normalizer in NORMALIZERS.iter() {
             if normalizer.should_normalize(&self) {
                 self = normalizer.normalize(self, options);
@@ -260,12 +875,13 @@ impl<'o> Normalize for &'o str {
     /// Normalize an str.
     fn normalize(self, options: &NormalizerOption) -> Self::Item {
         let mut normalized = Token { lemma: Cow::Borrowed(self), ..Default::default() };
-        for normalizer in NORMALIZERS.iter() {
+        // the language of the text being unknown, the normalizers specialized for a language are skipped.
+        for normalizer in STR_NORMALIZERS.iter() {
             normalized = normalizer.normalize(normalized, options);
         }
 
         if options.lossy {
-            for normalizer in LOSSY_NORMALIZERS.iter() {
+            for normalizer in STR_LOSSY_NORMALIZERS.iter() {
                 normalized = normalizer.normalize(normalized, options);
             }
         }
@@ -274,6 +890,27 @@ impl<'o> Normalize for &'o str {
     }
 }
 
+/// Returns true if `a` and `b` are equal once segmented and normalized the way they would be when indexed.
+///
+/// The texts are compared token by token, separators included,
+/// so filters and facets can match the strings exactly like the index does.
+///
+/// # Example
+///
+/// ```
+/// use charabia::normalized_eq;
+/// use charabia::normalizer::NormalizerOption;
+///
+/// let options = NormalizerOption { lossy: true, ..Default::default() };
+/// assert!(normalized_eq("Thé Café", "the cafe", &options));
+/// assert!(!normalized_eq("Thé Café", "the-cafe", &options));
+/// ```
+pub fn normalized_eq(a: &str, b: &str, options: &NormalizerOption) -> bool {
+    let a = a.segment().normalize(options).map(|token| token.lemma);
+    let b = b.segment().normalize(options).map(|token| token.lemma);
+    a.eq(b)
+}
+
 #[cfg(test)]
 mod test {
     use std::borrow::Cow;
@@ -282,7 +919,7 @@ mod test {
     use crate::normalizer::{
         CompatibilityDecompositionNormalizer, LowercaseNormalizer, Normalizer,
     };
-    use crate::Token;
+    use crate::{Language, Script, Token};
 
     macro_rules! test_normalizer {
         ($normalizer:expr, $tokens:expr, $normalizer_result:expr, $global_result:expr) => {
@@ -293,7 +930,24 @@ mod test {
             const TEST_NORMALIZER_OPTIONS: NormalizerOption = NormalizerOption {
                 create_char_map: true,
                 lossy: true,
-                classifier: crate::normalizer::ClassifierOption { stop_words: None, separators: None },
+                classifier: crate::normalizer::ClassifierOption { stop_words: None, separators: None, separator_set: None },
+                query_prefix: false,
+                query_phrase: false,
+                skip_classification: false,
+                skeleton: false,
+                romanize: false,
+                emoji_names: false,
+                cjk_numerals: false,
+                prolonged_sound_mark: crate::ProlongedSoundMark::Keep,
+                keep_original: false,
+                blank_input: crate::BlankInput::Keep,
+                // the stemming only applies to the languages enabled by the `stemming-*` features.
+                stemming: true,
+                esperanto_h_system: false,
+                #[cfg(feature = "hungarian")]
+                hungarian_keep_umlauts: false,
+                #[cfg(feature = "phonetic")]
+                phonetic: None,
             };
 
             #[test]
@@ -347,7 +1001,24 @@ Make sure that normalized tokens are valid or change the trigger condition of th
                     classifier:  crate::normalizer::ClassifierOption {
                         stop_words: Some(stop_words),
                         separators: Some(separators.as_slice()),
-                    }
+                        separator_set: None,
+                    },
+                    query_prefix: false,
+                    query_phrase: false,
+                    skip_classification: false,
+                    skeleton: false,
+                    romanize: false,
+                    emoji_names: false,
+                    cjk_numerals: false,
+                    prolonged_sound_mark: crate::ProlongedSoundMark::Keep,
+                    keep_original: false,
+                    blank_input: crate::BlankInput::Keep,
+                    stemming: true,
+                    esperanto_h_system: false,
+                    #[cfg(feature = "hungarian")]
+                    hungarian_keep_umlauts: false,
+                    #[cfg(feature = "phonetic")]
+                    phonetic: None,
                 };
 
                 let normalized_token = token.normalize(&normalizer_option);
@@ -357,6 +1028,96 @@ Make sure that normalized tokens are valid or change the trigger condition of th
     }
     pub(crate) use test_normalizer;
 
+    #[test]
+    fn normalizers_for_script_and_language() {
+        let names = |script, language| -> Vec<_> {
+            super::normalizers_for(script, language).iter().map(|n| n.name()).collect()
+        };
+
+        let latin = names(Script::Latin, Language::Eng);
+        assert!(latin.contains(&"CompatibilityDecompositionNormalizer"));
+        assert!(latin.contains(&"LowercaseNormalizer"));
+        assert!(latin.contains(&"QuoteNormalizer"));
+        assert!(!latin.contains(&"ArabicNormalizer"));
+
+        #[cfg(feature = "urdu")]
+        {
+            let urdu = names(Script::Arabic, Language::Urd);
+            assert!(urdu.contains(&"UrduNormalizer"));
+            assert!(!urdu.contains(&"ArabicNormalizer"));
+        }
+
+        let hebrew = names(Script::Hebrew, Language::Heb);
+        assert!(hebrew.contains(&"NonspacingMarkNormalizer"));
+        assert!(!hebrew.contains(&"LowercaseNormalizer"));
+
+        #[cfg(feature = "chinese")]
+        {
+            assert!(names(Script::Cj, Language::Cmn).contains(&"ChineseNormalizer"));
+            assert!(names(Script::Cj, Language::Other).contains(&"ChineseNormalizer"));
+            assert!(!names(Script::Cj, Language::Jpn).contains(&"ChineseNormalizer"));
+        }
+    }
+
+    #[test]
+    fn no_char_map_when_disabled() {
+        let options = super::NormalizerOption { lossy: true, ..Default::default() };
+        let samples = [
+            ("Thé QUICK’s ﬁre", Script::Latin, Some(Language::Eng)),
+            ("ΠΑΝΑΓΙΑ Άλφα", Script::Greek, Some(Language::Ell)),
+            ("ｶﾞｷﾞ 尊嚴", Script::Cj, Some(Language::Jpn)),
+            ("尊嚴", Script::Cj, Some(Language::Cmn)),
+            ("الْعَرَبِيَّة", Script::Arabic, Some(Language::Ara)),
+            ("עִבְרִית", Script::Hebrew, Some(Language::Heb)),
+        ];
+
+        for (lemma, script, language) in samples {
+            let mut token =
+                Token { lemma: Cow::Borrowed(lemma), script, language, ..Default::default() };
+            for normalizer in super::NORMALIZERS.iter().chain(super::LOSSY_NORMALIZERS.iter()) {
+                if normalizer.should_normalize(&token) {
+                    token = normalizer.normalize(token, &options);
+                    assert_eq!(token.char_map, None, "{} created a char_map", normalizer.name());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lossy_normalizers_are_tagged() {
+        for normalizer in super::NORMALIZERS.iter() {
+            assert!(!normalizer.is_lossy(), "{} is tagged as lossy", normalizer.name());
+        }
+        for normalizer in super::LOSSY_NORMALIZERS.iter() {
+            assert!(normalizer.is_lossy(), "{} is not tagged as lossy", normalizer.name());
+        }
+    }
+
+    #[test]
+    fn normalized_ascii_fast_path() {
+        use crate::Normalize;
+
+        assert!(super::is_normalized_ascii("hello, world! 42"));
+        assert!(!super::is_normalized_ascii("Hello"));
+        assert!(!super::is_normalized_ascii("café"));
+        assert!(!super::is_normalized_ascii("a\u{7}b"));
+
+        // the fast path gives the same result as the whole normalizer chain.
+        let options = super::NormalizerOption { lossy: true, ..Default::default() };
+        for (lemma, script) in
+            [("hello", Script::Latin), ("it's", Script::Latin), ("42", Script::Cj)]
+        {
+            let token = Token { lemma: Cow::Borrowed(lemma), script, ..Default::default() };
+            let mut expected = token.clone();
+            for normalizer in super::NORMALIZERS.iter().chain(super::LOSSY_NORMALIZERS.iter()) {
+                if normalizer.should_normalize(&expected) {
+                    expected = normalizer.normalize(expected, &options);
+                }
+            }
+            assert_eq!(token.normalize(&options), expected);
+        }
+    }
+
     #[test]
     fn split_at() {
         fn display_token<N>(token: &Token) {
@@ -0,0 +1,70 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Mongolian language, written in the traditional Mongolian script or in Cyrillic.
+///
+/// Traditional Mongolian text is normalized by:
+/// - removing the free variation selectors ('\u{180B}' to '\u{180D}' and '\u{180F}'), only choosing the displayed glyph of a letter
+/// - removing the Mongolian vowel separator ('\u{180E}'), only changing the shape of the final vowel
+///
+/// Cyrillic Mongolian text is normalized by replacing the fita ('Ѳ') and the izhitsa ('Ѵ'),
+/// used in place of the 'Ө' and 'Ү' letters by the legacy fonts and keyboard layouts, with these letters.
+pub struct MongolianNormalizer;
+
+impl CharNormalizer for MongolianNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            '\u{180B}'..='\u{180F}' => None,
+            'Ѳ' => Some('Ө'.into()),
+            'ѳ' => Some('ө'.into()),
+            'Ѵ' => Some('Ү'.into()),
+            'ѵ' => Some('ү'.into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        matches!(token.script, Script::Mongolian | Script::Cyrillic)
+            && token.lemma().chars().any(is_variant)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        matches!(script, Script::Mongolian | Script::Cyrillic)
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_variant(c: char) -> bool {
+    matches!(c, '\u{180B}'..='\u{180F}' | 'Ѳ' | 'ѳ' | 'Ѵ' | 'ѵ')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, Tokenize};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.tokenize().map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn traditional_mongolian() {
+        assert_eq!(lemmas("ᠪᠢᠴᠢᠭ\u{180B}"), lemmas("ᠪᠢᠴᠢᠭ"));
+        assert_eq!(lemmas("ᠬᠠᠶᠠ\u{180E}ᠠ"), lemmas("ᠬᠠᠶᠠᠠ"));
+
+        let token = "ᠮᠣᠩᠭᠣᠯ".tokenize().next().unwrap();
+        assert_eq!(token.script, Script::Mongolian);
+    }
+
+    #[test]
+    fn cyrillic_mongolian() {
+        assert_eq!(lemmas("Ѳнѳр Ѵнэн"), lemmas("Өнөр Үнэн"));
+        assert_eq!(lemmas("Өнөр Үнэн"), ["өнөр", " ", "үнэн"]);
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use once_cell::sync::Lazy;
 
 use super::CharNormalizer;
-use crate::detection::Script;
+use crate::detection::{Language, Script};
 use crate::normalizer::CharOrStr;
 use crate::Token;
 
@@ -26,10 +26,24 @@ impl CharNormalizer for NonspacingMarkNormalizer {
     }
 
     fn should_normalize(&self, token: &Token) -> bool {
+        CharNormalizer::supports(self, token.script, token.language.unwrap_or_default())
+            && token.lemma().chars().any(is_nonspacing_mark)
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        // the Uyghur orthography is fully vocalized, its marks are part of the letters.
         matches!(
-            token.script,
+            script,
             Script::Hebrew | Script::Thai | Script::Arabic | Script::Latin | Script::Greek
-        ) && token.lemma().chars().any(is_nonspacing_mark)
+        ) && !(cfg!(feature = "uyghur") && language == Language::Uig)
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
     }
 }
 
@@ -0,0 +1,61 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Odia script, detected as [`Script::Oriya`].
+///
+/// Odia text is normalized by:
+/// - removing the nukta ('଼'), the letters with a nukta, like 'ଡ଼', being decomposed beforehand
+/// - normalizing the candrabindu ('ଁ') to the anusvara ('ଂ'), both marking the nasalization
+/// - normalizing the Odia digits, like '୧', to the ASCII digits
+pub struct OdiaNormalizer;
+
+impl CharNormalizer for OdiaNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            '\u{B3C}' => None,
+            '\u{B01}' => Some('\u{B02}'.into()),
+            '\u{B66}'..='\u{B6F}' => Some(char::from(b'0' + (c as u32 - 0xB66) as u8).into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Oriya && token.lemma().chars().any(is_variant)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Oriya
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_variant(c: char) -> bool {
+    matches!(c, '\u{B3C}' | '\u{B01}' | '\u{B66}'..='\u{B6F}')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, Tokenize};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.tokenize().map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn odia() {
+        // the precomposed "ଡ଼".
+        assert_eq!(lemmas("ବ\u{B5C}"), lemmas("ବଡ"));
+        assert_eq!(lemmas("ଚାଁଦ"), lemmas("ଚାଂଦ"));
+
+        let token = "୨୦୨୪".tokenize().next().unwrap();
+        assert_eq!(token.script, Script::Oriya);
+        assert_eq!(token.lemma(), "2024");
+    }
+}
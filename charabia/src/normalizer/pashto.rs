@@ -0,0 +1,72 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Pashto language, written in the Arabic script,
+/// replacing the [`ArabicNormalizer`](super::ArabicNormalizer) which would merge the Yeh 'ي' with the Alef Maksura 'ى',
+/// two distinct letters in Pashto.
+///
+/// Pashto text is normalized by:
+/// - normalizing the Arabic letters typed by the Arabic and Persian keyboard layouts to their Pashto forms:
+///   the Alef Maksura 'ى' to the Farsi Yeh 'ی', the Kaf 'ك' to the Keheh 'ک' and the Gaf 'گ' to the Kaf with Ring 'ګ'
+/// - normalizing the Taa Marbuta 'ة' and the Ae 'ە' to the Heh 'ه'
+/// - removing the Tatweel ('ـ') characters
+///
+/// The language is not detected, it must be allowed with [`crate::AllowList`].
+pub struct PashtoNormalizer;
+
+impl CharNormalizer for PashtoNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            'ـ' => None,
+            'ى' => Some('ی'.into()),
+            'ك' => Some('ک'.into()),
+            'گ' => Some('ګ'.into()),
+            'ة' | 'ە' => Some('ه'.into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Arabic
+            && token.language == Some(Language::Pus)
+            && token.lemma().chars().any(is_variant)
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Arabic && language == Language::Pus
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_variant(c: char) -> bool {
+    matches!(c, 'ـ' | 'ى' | 'ك' | 'گ' | 'ة' | 'ە')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AllowList, Language, TokenizerBuilder};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        let allow_list = AllowList::new().languages([Language::Pus]);
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list);
+        let tokenizer = builder.build();
+
+        tokenizer.tokenize(text).map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn pashto() {
+        assert_eq!(lemmas("پښتو"), ["پښتو"]);
+        assert_eq!(lemmas("گډ كور"), ["ګډ", " ", "کور"]);
+        // the Yeh and the Farsi Yeh are distinct letters in Pashto.
+        assert_eq!(lemmas("سړي سړى"), ["سړي", " ", "سړی"]);
+    }
+}
@@ -0,0 +1,212 @@
+use rphonetic::{Cologne, DoubleMetaphone, Encoder, Soundex};
+
+use super::{replace_lemma, Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+/// Phonetic algorithm used to compute the phonetic keys of the words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhoneticAlgorithm {
+    /// American Soundex, e.g. "Robert" gives "R163".
+    Soundex,
+    /// Double Metaphone giving a primary and an alternate key, e.g. "Schmidt" gives "XMT" and "SMT".
+    DoubleMetaphone,
+    /// Kölner Phonetik designed for German words, e.g. "Müller" gives "657".
+    Cologne,
+}
+
+/// Structure for providing options to the [`PhoneticNormalizer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhoneticOption {
+    pub algorithm: PhoneticAlgorithm,
+    /// Replace the lemma by the primary phonetic key instead of only filling [`TokenForms::phonetic_keys`](crate::TokenForms::phonetic_keys).
+    pub replace_lemma: bool,
+}
+
+/// Compute the phonetic keys of Latin words with the `rphonetic` crate,
+/// allowing to match the words that sound alike.
+///
+/// The keys are stored in [`TokenForms::phonetic_keys`](crate::TokenForms::phonetic_keys) and,
+/// depending on the [`PhoneticOption`], the primary key replaces the lemma.
+/// Nothing is done if no `PhoneticOption` is provided in the [`NormalizerOption`].
+pub struct PhoneticNormalizer;
+
+impl Normalizer for PhoneticNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        let Some(phonetic) = options.phonetic else { return token };
+
+        // words without any letter have no phonetic key.
+        let keys = keys(phonetic.algorithm, token.lemma());
+        if keys.is_empty() {
+            return token;
+        }
+
+        if phonetic.replace_lemma && keys[0] != token.lemma() {
+            replace_lemma(&mut token, keys[0].clone(), options);
+        }
+
+        token.forms_mut().phonetic_keys = keys;
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Latin && token.is_word()
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Latin
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the phonetic keys of the word, the primary one first, or none if the word has no letter.
+fn keys(algorithm: PhoneticAlgorithm, word: &str) -> Vec<String> {
+    // the encoders only map the ASCII letters, the other letters are transliterated.
+    let word: String =
+        deunicode::deunicode(word).chars().filter(char::is_ascii_alphabetic).collect();
+    if word.is_empty() {
+        return Vec::new();
+    }
+
+    match algorithm {
+        PhoneticAlgorithm::Soundex => vec![Soundex::default().encode(&word)],
+        PhoneticAlgorithm::DoubleMetaphone => {
+            let double_metaphone = DoubleMetaphone::default();
+            let primary = double_metaphone.encode(&word);
+            let alternate = double_metaphone.encode_alternate(&word);
+            if primary == alternate {
+                vec![primary]
+            } else {
+                vec![primary, alternate]
+            }
+        }
+        PhoneticAlgorithm::Cologne => vec![Cologne.encode(&word)],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow::Owned;
+
+    use crate::normalizer::test::test_normalizer;
+    use crate::token::TokenKind;
+
+    #[test]
+    fn soundex_keys() {
+        let keys = |word| keys(PhoneticAlgorithm::Soundex, word);
+        assert_eq!(keys("Robert"), ["R163"]);
+        assert_eq!(keys("Rupert"), ["R163"]);
+        assert_eq!(keys("Tymczak"), ["T522"]);
+        assert_eq!(keys("Lee"), ["L000"]);
+        assert!(keys("42").is_empty());
+    }
+
+    #[test]
+    fn cologne_keys() {
+        let keys = |word| keys(PhoneticAlgorithm::Cologne, word);
+        assert_eq!(keys("Müller-Lüdenscheidt"), ["65752682"]);
+        assert_eq!(keys("Wikipedia"), ["3412"]);
+        assert_eq!(keys("Meyer"), keys("Mayr"));
+        assert!(keys("").is_empty());
+    }
+
+    #[test]
+    fn double_metaphone_keys() {
+        let keys = |word| keys(PhoneticAlgorithm::DoubleMetaphone, word);
+        assert_eq!(keys("Smith"), ["SM0", "XMT"]);
+        assert_eq!(keys("Schmidt"), ["XMT", "SMT"]);
+        assert_eq!(keys("Thompson"), ["TMPS"]);
+        assert_eq!(keys("knight"), ["NT"]);
+    }
+
+    const SOUNDEX_OPTIONS: NormalizerOption = NormalizerOption {
+        create_char_map: true,
+        lossy: true,
+        classifier: crate::normalizer::ClassifierOption {
+            stop_words: None,
+            separators: None,
+            separator_set: None,
+        },
+        query_prefix: false,
+        query_phrase: false,
+        skip_classification: false,
+        skeleton: false,
+        romanize: false,
+        emoji_names: false,
+        cjk_numerals: false,
+        prolonged_sound_mark: crate::ProlongedSoundMark::Keep,
+        keep_original: false,
+        blank_input: crate::BlankInput::Keep,
+        stemming: false,
+        esperanto_h_system: false,
+        #[cfg(feature = "hungarian")]
+        hungarian_keep_umlauts: false,
+        phonetic: Some(PhoneticOption {
+            algorithm: PhoneticAlgorithm::Soundex,
+            replace_lemma: true,
+        }),
+    };
+
+    fn token(lemma: &str) -> Token<'static> {
+        Token {
+            lemma: Owned(lemma.to_string()),
+            char_end: lemma.chars().count(),
+            byte_end: lemma.len(),
+            script: Script::Latin,
+            kind: TokenKind::Word,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn replace_lemma() {
+        let normalized = PhoneticNormalizer.normalize(token("robert"), &SOUNDEX_OPTIONS);
+        assert_eq!(normalized.lemma(), "R163");
+        assert_eq!(normalized.phonetic_keys(), ["R163"]);
+        assert_eq!(normalized.char_map, Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (1, 0), (1, 0)]));
+        assert_eq!(normalized.original_lengths(4), (4, 4));
+    }
+
+    #[test]
+    fn attach_keys() {
+        let options = NormalizerOption {
+            phonetic: Some(PhoneticOption {
+                algorithm: PhoneticAlgorithm::DoubleMetaphone,
+                replace_lemma: false,
+            }),
+            ..SOUNDEX_OPTIONS
+        };
+        let normalized = PhoneticNormalizer.normalize(token("schmidt"), &options);
+        assert_eq!(normalized.lemma(), "schmidt");
+        assert_eq!(normalized.char_map, None);
+        assert_eq!(normalized.phonetic_keys(), ["XMT", "SMT"]);
+
+        let normalized = PhoneticNormalizer.normalize(token("1984"), &options);
+        assert!(normalized.phonetic_keys().is_empty());
+        assert_eq!(normalized.forms, None);
+    }
+
+    // base tokens to normalize.
+    fn tokens() -> Vec<Token<'static>> {
+        vec![token("Müller")]
+    }
+
+    // expected result of the current Normalizer,
+    // the tested options don't enable the phonetic keys.
+    fn normalizer_result() -> Vec<Token<'static>> {
+        vec![token("Müller")]
+    }
+
+    // expected result of the complete Normalizer pieline.
+    fn normalized_tokens() -> Vec<Token<'static>> {
+        vec![Token {
+            lemma: Owned("muller".to_string()),
+            char_map: Some(vec![(1, 1), (2, 1), (1, 1), (1, 1), (1, 1), (1, 1)]),
+            ..token("Müller")
+        }]
+    }
+
+    test_normalizer!(PhoneticNormalizer, tokens(), normalizer_result(), normalized_tokens());
+}
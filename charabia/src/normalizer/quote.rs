@@ -1,5 +1,5 @@
 use super::{CharNormalizer, CharOrStr};
-use crate::detection::Script;
+use crate::detection::{Language, Script};
 use crate::Token;
 
 /// Latin specialized [`Normalizer`].
@@ -19,6 +19,18 @@ impl CharNormalizer for QuoteNormalizer {
     fn should_normalize(&self, token: &Token) -> bool {
         token.script == Script::Latin && token.lemma.chars().any(is_unicode_high_quotation_mark)
     }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Latin
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
 }
 
 fn is_unicode_high_quotation_mark(c: char) -> bool {
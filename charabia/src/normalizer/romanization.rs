@@ -0,0 +1,298 @@
+use unicode_normalization::UnicodeNormalization;
+
+use super::{Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+/// Compute the romanization of the Greek, Cyrillic and Hangul words, their transliteration to the Latin alphabet,
+/// for the indexes queried with Latin keyboards.
+///
+/// Greek is transliterated following ISO 843, e.g. "αθήνα" gives "athina",
+/// Cyrillic following BGN/PCGN without diacritics nor apostrophes, e.g. "щука" gives "shchuka",
+/// and Hangul following the Revised Romanization of Korean, e.g. "안녕" gives "annyeong",
+/// with the linking of the final consonants and the nasal and lateral assimilations, e.g. "한국말" gives "hangungmal".
+/// The romanization is returned by [`Token::romanized`] and doesn't replace the lemma.
+/// Nothing is done if the romanization is not enabled in the [`NormalizerOption`].
+pub struct RomanizationNormalizer;
+
+impl Normalizer for RomanizationNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        if options.romanize {
+            let romanized = match token.script {
+                Script::Greek => romanize(token.lemma(), GREEK_DIGRAPHS, greek_char),
+                Script::Cyrillic => romanize(token.lemma(), &[], cyrillic_char),
+                Script::Hangul => romanize_hangul(token.lemma()),
+                _ => return token,
+            };
+            token.forms_mut().romanized = Some(romanized);
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        matches!(token.script, Script::Greek | Script::Cyrillic | Script::Hangul) && token.is_word()
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        matches!(script, Script::Greek | Script::Cyrillic | Script::Hangul)
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Greek pairs of letters transliterated together.
+const GREEK_DIGRAPHS: &[(&str, &str)] = &[
+    ("ου", "ou"),
+    ("αυ", "av"),
+    ("ευ", "ev"),
+    ("ηυ", "iv"),
+    ("γγ", "ng"),
+    ("γξ", "nx"),
+    ("γχ", "nch"),
+];
+
+fn romanize(
+    lemma: &str,
+    digraphs: &[(&str, &str)],
+    romanize_char: fn(char) -> Option<&'static str>,
+) -> String {
+    // the decomposed letters, like "й", are recomposed to be transliterated.
+    let lemma: String = lemma.nfc().flat_map(char::to_lowercase).collect();
+    let mut romanized = String::with_capacity(lemma.len());
+    let mut rest = lemma.as_str();
+    while let Some(c) = rest.chars().next() {
+        match digraphs.iter().find(|(digraph, _)| rest.starts_with(digraph)) {
+            Some((digraph, latin)) => {
+                romanized.push_str(latin);
+                rest = &rest[digraph.len()..];
+            }
+            None => {
+                match romanize_char(c) {
+                    Some(latin) => romanized.push_str(latin),
+                    None => romanized.push(c),
+                }
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    romanized
+}
+
+fn greek_char(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'α' | 'ά' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' | 'έ' => "e",
+        'ζ' => "z",
+        'η' | 'ή' => "i",
+        'θ' => "th",
+        'ι' | 'ί' | 'ϊ' | 'ΐ' => "i",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' | 'ό' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' | 'ύ' | 'ϋ' | 'ΰ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        'ω' | 'ώ' => "o",
+        _ => return None,
+    };
+    Some(latin)
+}
+
+fn cyrillic_char(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' | 'ґ' => "g",
+        'д' => "d",
+        'е' | 'ё' | 'э' => "e",
+        'ж' => "zh",
+        'з' => "z",
+        'и' | 'і' => "i",
+        'й' | 'ы' => "y",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' | 'ў' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ъ' | 'ь' => "",
+        'ю' => "yu",
+        'я' => "ya",
+        'є' => "ye",
+        'ї' => "yi",
+        'ђ' => "dj",
+        'ј' => "j",
+        'љ' => "lj",
+        'њ' => "nj",
+        'ћ' => "c",
+        'џ' | 'ѕ' => "dz",
+        'ѓ' => "gj",
+        'ќ' => "kj",
+        _ => return None,
+    };
+    Some(latin)
+}
+
+const HANGUL_SYLLABLES_START: u32 = 0xAC00;
+const HANGUL_SYLLABLES_END: u32 = 0xD7A3;
+
+/// Initial consonants of the Hangul syllables, in the Unicode order.
+const HANGUL_INITIALS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+/// Vowels of the Hangul syllables, in the Unicode order.
+const HANGUL_VOWELS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+/// Final consonants of the Hangul syllables, in the Unicode order, as pronounced at the end of a syllable.
+const HANGUL_FINALS: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+    "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+/// Final consonants of the Hangul syllables, in the Unicode order, as linked to a following vowel.
+const HANGUL_LINKED_FINALS: [&str; 28] = [
+    "", "g", "kk", "ks", "n", "nj", "n", "d", "r", "lg", "lm", "lb", "ls", "lt", "lp", "r", "m",
+    "b", "ps", "s", "ss", "ng", "j", "ch", "k", "t", "p", "",
+];
+
+const HANGUL_SILENT_INITIAL: usize = 11;
+const HANGUL_INITIAL_N: usize = 2;
+const HANGUL_INITIAL_R: usize = 5;
+const HANGUL_INITIAL_M: usize = 6;
+const HANGUL_FINAL_N: usize = 4;
+
+/// Returns the indexes of the initial consonant, the vowel and the final consonant of a Hangul syllable.
+fn hangul_jamos(c: char) -> Option<(usize, usize, usize)> {
+    let index = (c as u32).checked_sub(HANGUL_SYLLABLES_START)? as usize;
+    (c as u32 <= HANGUL_SYLLABLES_END).then_some((
+        index / (21 * 28),
+        index % (21 * 28) / 28,
+        index % 28,
+    ))
+}
+
+fn romanize_hangul(lemma: &str) -> String {
+    // the syllables are decomposed in conjoining jamos by the compatibility decomposition.
+    let chars: Vec<char> = lemma.nfc().collect();
+    let mut romanized = String::with_capacity(lemma.len());
+    let mut previous_final = "";
+    for (i, &c) in chars.iter().enumerate() {
+        let Some((initial, vowel, final_)) = hangul_jamos(c) else {
+            romanized.push(c);
+            previous_final = "";
+            continue;
+        };
+
+        // the 'ㄹ' and the 'ㄴ' following an 'l' sound are both pronounced 'l'.
+        match initial {
+            HANGUL_INITIAL_R | HANGUL_INITIAL_N if previous_final == "l" => romanized.push('l'),
+            initial => romanized.push_str(HANGUL_INITIALS[initial]),
+        }
+        romanized.push_str(HANGUL_VOWELS[vowel]);
+
+        let next_initial =
+            chars.get(i + 1).and_then(|&c| hangul_jamos(c)).map(|(initial, _, _)| initial);
+        let coda = match (HANGUL_FINALS[final_], next_initial) {
+            ("", _) => "",
+            (_, Some(HANGUL_SILENT_INITIAL)) => HANGUL_LINKED_FINALS[final_],
+            // the stops are nasalized before a nasal consonant.
+            ("k", Some(HANGUL_INITIAL_N | HANGUL_INITIAL_M)) => "ng",
+            ("t", Some(HANGUL_INITIAL_N | HANGUL_INITIAL_M)) => "n",
+            ("p", Some(HANGUL_INITIAL_N | HANGUL_INITIAL_M)) => "m",
+            ("n", Some(HANGUL_INITIAL_R)) if final_ == HANGUL_FINAL_N => "l",
+            (coda, _) => coda,
+        };
+        romanized.push_str(coda);
+        previous_final = coda;
+    }
+
+    romanized
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use super::RomanizationNormalizer;
+    use crate::normalizer::{Normalizer, NormalizerOption};
+    use crate::{Script, Token, Tokenize, TokenizerBuilder};
+
+    #[test]
+    fn romanize() {
+        let mut builder = TokenizerBuilder::default();
+        builder.romanize(true);
+        let tokenizer = builder.build();
+
+        let romanized: Vec<_> = tokenizer
+            .tokenize("Αθήνα Ευρώπη ΑΓΓΕΛΟΣ Москва щука Україна Hello")
+            .filter(|t| t.is_word())
+            .map(|t| t.romanized().map(str::to_string))
+            .collect();
+        assert_eq!(
+            romanized,
+            [
+                Some("athina".to_string()),
+                Some("evropi".to_string()),
+                Some("angelos".to_string()),
+                Some("moskva".to_string()),
+                Some("shchuka".to_string()),
+                Some("ukrayina".to_string()),
+                None,
+            ]
+        );
+
+        // nothing is computed when the romanization is disabled.
+        assert!("Москва".tokenize().all(|t| t.forms.is_none()));
+    }
+
+    #[test]
+    fn romanize_hangul() {
+        let options = NormalizerOption { romanize: true, ..NormalizerOption::default() };
+        let romanized = |lemma: &str| {
+            let token = Token {
+                lemma: Cow::Owned(lemma.to_string()),
+                script: Script::Hangul,
+                ..Default::default()
+            };
+            RomanizationNormalizer.normalize(token, &options).romanized().unwrap().to_string()
+        };
+
+        assert_eq!(romanized("안녕"), "annyeong");
+        assert_eq!(romanized("서울"), "seoul");
+        // the final consonants are linked to the following vowels.
+        assert_eq!(romanized("한국어"), "hangugeo");
+        // the nasal and lateral assimilations.
+        assert_eq!(romanized("감사합니다"), "gamsahamnida");
+        assert_eq!(romanized("신라"), "silla");
+        assert_eq!(romanized("설날"), "seollal");
+        // the syllables decomposed by the compatibility decomposition are recomposed.
+        assert_eq!(romanized("\u{1112}\u{1161}\u{11AB}"), "han");
+    }
+}
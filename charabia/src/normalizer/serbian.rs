@@ -0,0 +1,175 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::detection::{Language, Script};
+use crate::Token;
+
+/// Serbian specialized [`Normalizer`](super::Normalizer).
+///
+/// Serbian is written interchangeably in Cyrillic and in Latin,
+/// so this Normalizer transliterates the Cyrillic Serbian words to the Latin alphabet,
+/// the diacritics being removed as they are from the Latin words, e.g. "шта" becomes "sta".
+pub struct SerbianNormalizer;
+
+impl CharNormalizer for SerbianNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        let latin = match c.to_lowercase().next().unwrap_or(c) {
+            'а' => "a",
+            'б' => "b",
+            'в' => "v",
+            'г' => "g",
+            'д' => "d",
+            'ђ' => "đ",
+            'е' => "e",
+            'ж' => "z",
+            'з' => "z",
+            'и' => "i",
+            'ј' => "j",
+            'к' => "k",
+            'л' => "l",
+            'љ' => "lj",
+            'м' => "m",
+            'н' => "n",
+            'њ' => "nj",
+            'о' => "o",
+            'п' => "p",
+            'р' => "r",
+            'с' => "s",
+            'т' => "t",
+            'ћ' => "c",
+            'у' => "u",
+            'ф' => "f",
+            'х' => "h",
+            'ц' => "c",
+            'ч' => "c",
+            'џ' => "dz",
+            'ш' => "s",
+            _ => return Some(c.into()),
+        };
+
+        let mut chars = latin.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c.into()),
+            _ => Some(latin.to_string().into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Cyrillic && token.language == Some(Language::Srp)
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Cyrillic && language == Language::Srp
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow::Owned;
+
+    use crate::normalizer::test::test_normalizer;
+    use crate::normalizer::{Normalizer, NormalizerOption};
+    use crate::token::TokenKind;
+    use crate::Language;
+
+    // base tokens to normalize.
+    fn tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("Љубовић".to_string()),
+                char_end: 7,
+                byte_end: 14,
+                script: Script::Cyrillic,
+                language: Some(Language::Srp),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("шта".to_string()),
+                char_end: 3,
+                byte_end: 6,
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the current Normalizer.
+    fn normalizer_result() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("ljubovic".to_string()),
+                char_end: 7,
+                byte_end: 14,
+                char_map: Some(vec![(2, 2), (2, 1), (2, 1), (2, 1), (2, 1), (2, 1), (2, 1)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Srp),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("шта".to_string()),
+                char_end: 3,
+                byte_end: 6,
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the complete Normalizer pieline.
+    fn normalized_tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("ljubovic".to_string()),
+                char_end: 7,
+                byte_end: 14,
+                char_map: Some(vec![(2, 2), (2, 1), (2, 1), (2, 1), (2, 1), (2, 1), (2, 1)]),
+                script: Script::Cyrillic,
+                language: Some(Language::Srp),
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("шта".to_string()),
+                char_end: 3,
+                byte_end: 6,
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+        ]
+    }
+
+    test_normalizer!(SerbianNormalizer, tokens(), normalizer_result(), normalized_tokens());
+
+    #[test]
+    fn both_orthographies_match() {
+        use crate::TokenizerBuilder;
+
+        let mut builder = TokenizerBuilder::default();
+        builder.default_language(Script::Cyrillic, Language::Srp);
+        let tokenizer = builder.build();
+
+        let cyrillic: Vec<_> = tokenizer.tokenize("Ђорђе чита књигу").map(|t| t.lemma).collect();
+        let latin: Vec<_> = tokenizer.tokenize("Đorđe čita knjigu").map(|t| t.lemma).collect();
+        assert_eq!(cyrillic, latin);
+    }
+
+    #[test]
+    fn unknown_language() {
+        use crate::Normalize;
+
+        // the language of a `&str` is unknown, so the Cyrillic words are not transliterated as Serbian.
+        let options = NormalizerOption { lossy: true, ..Default::default() };
+        assert_eq!("Привет мир".normalize(&options), "привет мир");
+        assert_eq!("Ђорђе чита".normalize(&options), "ђорђе чита");
+    }
+}
@@ -0,0 +1,38 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::detection::{Language, Script};
+use crate::Token;
+
+/// Zero width joiner, requesting the conjunct form of the consonants, like the rakaransaya of "ශ්‍රී".
+const ZWJ: char = '\u{200D}';
+
+/// Sinhala specialized [`Normalizer`](super::Normalizer).
+///
+/// The conjuncts of Sinhala are written with or without a zero width joiner depending on the input method,
+/// which only changes their rendering, so this Normalizer removes the joiners to match both spellings.
+pub struct SinhalaNormalizer;
+
+impl CharNormalizer for SinhalaNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        (c != ZWJ).then(|| c.into())
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Sinhala && token.lemma().contains(ZWJ)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Sinhala
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Tokenize;
+
+    #[test]
+    fn sinhala() {
+        let joined: Vec<_> = "ශ්\u{200D}රී".tokenize().map(|t| t.lemma.into_owned()).collect();
+        let unjoined: Vec<_> = "ශ්රී".tokenize().map(|t| t.lemma.into_owned()).collect();
+        assert_eq!(joined, unjoined);
+    }
+}
@@ -0,0 +1,76 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use super::{Normalizer, NormalizerOption};
+use crate::{Script, Token};
+
+/// Compute the skeleton of the words, a coarse form used by fuzzy matching layers to generate candidates cheaply.
+///
+/// The skeleton is the lemma without diacritics, lowercased, with the common Latin spelling confusions folded
+/// and the doubled letters collapsed, e.g. "Philippe" and "Filipe" both give "filipe".
+/// It is returned by [`Token::skeleton`] and doesn't replace the lemma.
+/// Nothing is done if the skeleton is not enabled in the [`NormalizerOption`].
+pub struct SkeletonNormalizer;
+
+impl Normalizer for SkeletonNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        if options.skeleton {
+            token.forms_mut().skeleton = Some(skeleton(token.lemma(), token.script));
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.is_word()
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Spelling confusions folded in the Latin words, the longest first.
+const LATIN_CONFUSIONS: &[(&str, &str)] =
+    &[("ph", "f"), ("ck", "k"), ("q", "k"), ("y", "i"), ("z", "s"), ("w", "v")];
+
+fn skeleton(lemma: &str, script: Script) -> String {
+    let mut folded: String =
+        lemma.nfkd().filter(|c| !is_combining_mark(*c)).flat_map(char::to_lowercase).collect();
+
+    if script == Script::Latin {
+        for (from, to) in LATIN_CONFUSIONS {
+            if folded.contains(from) {
+                folded = folded.replace(from, to);
+            }
+        }
+    }
+
+    let mut skeleton = String::with_capacity(folded.len());
+    let mut previous = None;
+    for c in folded.chars() {
+        if previous != Some(c) {
+            skeleton.push(c);
+        }
+        previous = Some(c);
+    }
+
+    skeleton
+}
+
+#[cfg(test)]
+mod test {
+    use super::skeleton;
+    use crate::Script;
+
+    #[test]
+    fn skeletons() {
+        assert_eq!(skeleton("Philippe", Script::Latin), "filipe");
+        assert_eq!(skeleton("filipe", Script::Latin), "filipe");
+        assert_eq!(skeleton("Ångström", Script::Latin), "angstrom");
+        assert_eq!(skeleton("Jackson", Script::Latin), "jakson");
+        assert_eq!(skeleton("Mississippi", Script::Latin), "misisipi");
+        // the confusions are only folded in Latin words.
+        assert_eq!(skeleton("Ἀλλά", Script::Greek), "αλα");
+    }
+}
@@ -0,0 +1,202 @@
+use std::borrow::Cow;
+
+use rust_stemmers::{Algorithm, Stemmer};
+
+use super::{replace_lemma, Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+/// Reduce Latin words to their stem using the Snowball stemmer of the `Token` language.
+///
+/// Only the languages enabled by a `stemming-*` feature are stemmed, when [`NormalizerOption::stemming`] is set,
+/// and only if the language of the `Token` is known,
+/// for instance by setting a default language using [`crate::TokenizerBuilder::default_language`].
+pub struct StemmingNormalizer;
+
+impl Normalizer for StemmingNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        if !options.stemming {
+            return token;
+        }
+
+        let Some(algorithm) = token.language.and_then(algorithm) else { return token };
+        if let Cow::Owned(stem) = Stemmer::create(algorithm).stem(token.lemma()) {
+            if stem != token.lemma() {
+                replace_lemma(&mut token, stem, options);
+            }
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Latin
+            && token.is_word()
+            && token.language.and_then(algorithm).is_some()
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Latin && algorithm(language).is_some()
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the Snowball algorithm of the provided language if its stemming is enabled.
+fn algorithm(language: Language) -> Option<Algorithm> {
+    match language {
+        #[cfg(feature = "stemming-danish")]
+        Language::Dan => Some(Algorithm::Danish),
+        #[cfg(feature = "stemming-dutch")]
+        Language::Nld => Some(Algorithm::Dutch),
+        #[cfg(feature = "stemming-english")]
+        Language::Eng => Some(Algorithm::English),
+        #[cfg(feature = "stemming-finnish")]
+        Language::Fin => Some(Algorithm::Finnish),
+        #[cfg(feature = "stemming-french")]
+        Language::Fra => Some(Algorithm::French),
+        #[cfg(feature = "stemming-german")]
+        Language::Deu => Some(Algorithm::German),
+        #[cfg(feature = "stemming-hungarian")]
+        Language::Hun => Some(Algorithm::Hungarian),
+        #[cfg(feature = "stemming-italian")]
+        Language::Ita => Some(Algorithm::Italian),
+        #[cfg(feature = "stemming-norwegian")]
+        Language::Nob => Some(Algorithm::Norwegian),
+        #[cfg(feature = "stemming-portuguese")]
+        Language::Por => Some(Algorithm::Portuguese),
+        #[cfg(feature = "stemming-romanian")]
+        Language::Ron => Some(Algorithm::Romanian),
+        #[cfg(feature = "stemming-spanish")]
+        Language::Spa => Some(Algorithm::Spanish),
+        #[cfg(feature = "stemming-swedish")]
+        Language::Swe => Some(Algorithm::Swedish),
+        #[cfg(feature = "stemming-turkish")]
+        Language::Tur => Some(Algorithm::Turkish),
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "stemming-english"))]
+mod test {
+    use std::borrow::Cow::Owned;
+
+    use crate::normalizer::test::test_normalizer;
+    use crate::normalizer::{Normalizer, NormalizerOption};
+    use crate::token::TokenKind;
+
+    // base tokens to normalize.
+    fn tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("Ponies".to_string()),
+                char_end: 6,
+                byte_end: 6,
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                script: Script::Latin,
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the current Normalizer.
+    fn normalizer_result() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("run".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 0), (1, 0), (1, 0), (1, 0)]),
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("Poni".to_string()),
+                char_end: 6,
+                byte_end: 6,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (1, 0), (1, 0)]),
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                script: Script::Latin,
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the complete Normalizer pieline.
+    fn normalized_tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("run".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 0), (1, 0), (1, 0), (1, 0)]),
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("poni".to_string()),
+                char_end: 6,
+                byte_end: 6,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (1, 0), (1, 0)]),
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                script: Script::Latin,
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+        ]
+    }
+
+    test_normalizer!(StemmingNormalizer, tokens(), normalizer_result(), normalized_tokens());
+
+    #[test]
+    fn disabled_by_default() {
+        use crate::TokenizerBuilder;
+
+        let mut builder = TokenizerBuilder::default();
+        builder.default_language(Script::Latin, Language::Eng);
+        assert_eq!(builder.build().tokenize("running").next().unwrap().lemma(), "running");
+
+        builder.stemming(true);
+        assert_eq!(builder.build().tokenize("running").next().unwrap().lemma(), "run");
+    }
+}
@@ -0,0 +1,124 @@
+use std::borrow::Cow;
+
+use super::{identity_char_map, Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+/// Pulli ('்'), the virama of Tamil marking a consonant without vowel.
+const PULLI: char = '\u{BCD}';
+
+/// A [`Normalizer`] for the Tamil script.
+///
+/// The two-part vowel signs, like 'ொ', are already decomposed into their sequence of signs, 'ெ' and 'ா',
+/// by the [`CompatibilityDecompositionNormalizer`](super::CompatibilityDecompositionNormalizer),
+/// so Tamil text is normalized by:
+/// - removing the vowel signs and the pulli ('்') repeated by mistake, like in "கா\u{BBE}"
+/// - normalizing the Tamil digits, like '௧', to the ASCII digits
+pub struct TamilNormalizer;
+
+impl Normalizer for TamilNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        let char_map = token
+            .char_map
+            .take()
+            .or_else(|| options.create_char_map.then(|| identity_char_map(token.lemma())));
+
+        let mut normalized = String::with_capacity(token.lemma.len());
+        let mut previous = None;
+        match char_map {
+            // the characters are normalized one by one to keep the char_map aligned.
+            Some(char_map) => {
+                let mut lemma = token.lemma();
+                let char_map = char_map
+                    .into_iter()
+                    .map(|(original_len, normalized_len)| {
+                        let (segment, rest) = lemma.split_at(normalized_len as usize);
+                        lemma = rest;
+                        let len = normalized.len();
+                        normalize_str(segment, &mut previous, &mut normalized);
+                        (original_len, (normalized.len() - len) as u8)
+                    })
+                    .collect();
+                token.char_map = Some(char_map);
+            }
+            None => normalize_str(token.lemma(), &mut previous, &mut normalized),
+        }
+
+        token.lemma = Cow::Owned(normalized);
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        let mut previous = None;
+        token.script == Script::Tamil
+            && token.lemma().chars().any(|c| {
+                let repeated = previous == Some(c) && is_sign(c);
+                previous = Some(c);
+                repeated || is_digit(c)
+            })
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Tamil
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Pushes the normalized version of the provided text into `normalized`,
+/// `previous` being the last character read, which may be in a previous part of the lemma.
+fn normalize_str(text: &str, previous: &mut Option<char>, normalized: &mut String) {
+    for c in text.chars() {
+        if is_digit(c) {
+            normalized.push(char::from(b'0' + (c as u32 - 0xBE6) as u8));
+        } else if !(*previous == Some(c) && is_sign(c)) {
+            normalized.push(c);
+        }
+        *previous = Some(c);
+    }
+}
+
+/// Returns true for the dependent vowel signs, the au length mark, and the pulli.
+fn is_sign(c: char) -> bool {
+    matches!(c, '\u{BBE}'..='\u{BCC}' | '\u{BD7}' | PULLI)
+}
+
+fn is_digit(c: char) -> bool {
+    matches!(c, '\u{BE6}'..='\u{BEF}')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Tokenize, TokenizerBuilder};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.tokenize().map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn tamil() {
+        // the precomposed and the decomposed two-part vowel sign.
+        assert_eq!(lemmas("கொடு"), lemmas("கெ\u{BBE}டு"));
+        // the repeated vowel sign and pulli.
+        assert_eq!(lemmas("கா\u{BBE}ல்\u{BCD}"), lemmas("கால்"));
+        assert_eq!(lemmas("௧௯௪௭"), ["1947"]);
+    }
+
+    #[test]
+    fn tamil_char_map() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "கா\u{BBE}ல்";
+        let token = tokenizer.tokenize(text).next().unwrap();
+        assert_eq!(token.lemma(), "கால்");
+        assert_eq!(token.char_map, Some(vec![(3, 3), (3, 3), (3, 0), (3, 3), (3, 3)]));
+        assert_eq!(token.validate(text), Ok(()));
+    }
+}
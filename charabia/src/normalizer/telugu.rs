@@ -0,0 +1,60 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// A [`Normalizer`](super::Normalizer) for the Telugu script.
+///
+/// Telugu text is normalized by:
+/// - normalizing the candrabindus ('ఀ', 'ఁ') and the anusvara above ('ఄ') to the anusvara ('ం')
+/// - removing the zero width joiners and non-joiners, which only select the rendering of the conjuncts
+/// - normalizing the Telugu digits, like '౧', to the ASCII digits
+pub struct TeluguNormalizer;
+
+impl CharNormalizer for TeluguNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            '\u{C00}' | '\u{C01}' | '\u{C04}' => Some('\u{C02}'.into()),
+            '\u{200C}' | '\u{200D}' => None,
+            '\u{C66}'..='\u{C6F}' => Some(char::from(b'0' + (c as u32 - 0xC66) as u8).into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Telugu && token.lemma().chars().any(is_variant)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Telugu
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_variant(c: char) -> bool {
+    matches!(c, '\u{C00}' | '\u{C01}' | '\u{C04}' | '\u{200C}' | '\u{200D}' | '\u{C66}'..='\u{C6F}')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, Tokenize};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        text.tokenize().map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn telugu() {
+        assert_eq!(lemmas("తెలుఁగు"), lemmas("తెలుంగు"));
+        assert_eq!(lemmas("క్\u{200C}ష"), lemmas("క్ష"));
+
+        let token = "౨౦౨౪".tokenize().next().unwrap();
+        assert_eq!(token.script, Script::Telugu);
+        assert_eq!(token.lemma(), "2024");
+    }
+}
@@ -0,0 +1,42 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::detection::{Language, Script};
+use crate::Token;
+
+/// Tifinagh consonant joiner, requesting the bi-consonant ligature of the surrounding consonants, like in "ⵏ⵿ⵜ".
+const CONSONANT_JOINER: char = '\u{2D7F}';
+
+/// Tifinagh specialized [`Normalizer`](super::Normalizer).
+///
+/// The bi-consonant ligatures of the Amazigh languages are written with a consonant joiner between the consonants,
+/// which only changes their rendering, so this Normalizer expands the ligatures by removing the joiners
+/// to match both spellings.
+pub struct TifinaghNormalizer;
+
+impl CharNormalizer for TifinaghNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        (c != CONSONANT_JOINER).then(|| c.into())
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Tifinagh && token.lemma().contains(CONSONANT_JOINER)
+    }
+
+    fn supports(&self, script: Script, _language: Language) -> bool {
+        script == Script::Tifinagh
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Script, Tokenize};
+
+    #[test]
+    fn tifinagh() {
+        let joined: Vec<_> = "ⵜⴰⵎⴰⵣⵉⵖ\u{2D7F}ⵜ".tokenize().map(|t| t.lemma.into_owned()).collect();
+        let unjoined: Vec<_> = "ⵜⴰⵎⴰⵣⵉⵖⵜ".tokenize().map(|t| t.lemma.into_owned()).collect();
+        assert_eq!(joined, unjoined);
+
+        let token = "ⵜⴰⵎⴰⵣⵉⵖⵜ".tokenize().next().unwrap();
+        assert_eq!(token.script, Script::Tifinagh);
+    }
+}
@@ -0,0 +1,77 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Language, Script, Token};
+
+/// Zero width non-joiner, breaking the cursive joining inside the Urdu words, like in "ہم‌آہنگی".
+const ZWNJ: char = '\u{200C}';
+
+/// A [`Normalizer`](super::Normalizer) for the Urdu language, replacing the [`ArabicNormalizer`](super::ArabicNormalizer)
+/// whose foldings are wrong for the Urdu orthography.
+///
+/// Urdu text is normalized by:
+/// - normalizing the Arabic letters typed by the Arabic keyboard layouts to their Urdu forms:
+///   the Yeh 'ي' and Alef Maksura 'ى' to the Farsi Yeh 'ی', the Kaf 'ك' to the Keheh 'ک',
+///   the Heh 'ه' to the Heh Goal 'ہ' and the Taa Marbuta 'ة' to the Teh Marbuta Goal 'ۃ'
+/// - removing the Tatweel ('ـ') characters
+/// - removing the zero width non-joiners, only changing the rendering of the words
+pub struct UrduNormalizer;
+
+impl CharNormalizer for UrduNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            'ـ' | ZWNJ => None,
+            'ي' | 'ى' => Some('ی'.into()),
+            'ك' => Some('ک'.into()),
+            'ه' => Some('ہ'.into()),
+            'ة' => Some('ۃ'.into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Arabic
+            && token.language == Some(Language::Urd)
+            && token.lemma().chars().any(is_variant)
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Arabic && language == Language::Urd
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+
+    fn is_lossy(&self) -> bool {
+        true
+    }
+}
+
+fn is_variant(c: char) -> bool {
+    matches!(c, 'ـ' | ZWNJ | 'ي' | 'ى' | 'ك' | 'ه' | 'ة')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AllowList, Language, TokenizerBuilder};
+
+    fn lemmas(text: &str) -> Vec<String> {
+        let allow_list = AllowList::new().languages([Language::Urd]);
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list);
+        let tokenizer = builder.build();
+
+        tokenizer.tokenize(text).map(|t| t.lemma.into_owned()).collect()
+    }
+
+    #[test]
+    fn urdu() {
+        assert_eq!(lemmas("كتاب"), lemmas("کتاب"));
+        assert_eq!(lemmas("يه"), ["یہ"]);
+        assert_eq!(lemmas("ہم\u{200C}آہنگی"), lemmas("ہمآہنگی"));
+        // the Arabic article is not split from the Urdu words.
+        assert_eq!(lemmas("الفاظ"), ["الفاظ"]);
+        // unless Urdu is selected, the Arabic texts are segmented as Arabic.
+        let tokenizer = TokenizerBuilder::default().build();
+        assert_eq!(tokenizer.segment_str("الفاظ").collect::<Vec<_>>(), ["ال", "فاظ"]);
+    }
+}
@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+use super::{Normalizer, NormalizerOption};
+use crate::{Language, Script, Token};
+
+/// Arabic hamza above, decomposed from the hamza forms of the letters by the compatibility decomposition.
+const HAMZA_ABOVE: char = '\u{654}';
+
+/// A [`Normalizer`] for the Uyghur language, written in the Arabic script.
+///
+/// The Uyghur orthography is fully vocalized, its vowels being written with letters like 'ۇ' or 'ې'
+/// and the syllables starting with a vowel with the Yeh with Hamza 'ئ', like in "ئۇيغۇر" (Uyghur).
+/// So the hamza forms, decomposed by the [`CompatibilityDecompositionNormalizer`](super::CompatibilityDecompositionNormalizer),
+/// are recomposed by this Normalizer, and neither the harakat stripping of the `NonspacingMarkNormalizer`
+/// nor the letter foldings of the [`ArabicNormalizer`](super::ArabicNormalizer) are applied to the Uyghur words.
+///
+/// The language is not detected, it must be allowed with [`crate::AllowList`].
+pub struct UyghurNormalizer;
+
+impl Normalizer for UyghurNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, _options: &NormalizerOption) -> Token<'o> {
+        match token.char_map.take() {
+            // the decomposition of an original character is contained in its segment of the char_map.
+            Some(char_map) => {
+                let mut recomposed = String::with_capacity(token.lemma.len());
+                let mut lemma = token.lemma();
+                let char_map = char_map
+                    .into_iter()
+                    .map(|(original_len, normalized_len)| {
+                        let (segment, rest) = lemma.split_at(normalized_len as usize);
+                        lemma = rest;
+                        let len = recomposed.len();
+                        recompose_hamza(segment, &mut recomposed);
+                        (original_len, (recomposed.len() - len) as u8)
+                    })
+                    .collect();
+                token.lemma = Cow::Owned(recomposed);
+                token.char_map = Some(char_map);
+            }
+            None => {
+                let mut recomposed = String::with_capacity(token.lemma.len());
+                recompose_hamza(token.lemma(), &mut recomposed);
+                token.lemma = Cow::Owned(recomposed);
+            }
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Arabic
+            && token.language == Some(Language::Uig)
+            && token.lemma().contains(HAMZA_ABOVE)
+    }
+
+    fn supports(&self, script: Script, language: Language) -> bool {
+        script == Script::Arabic && language == Language::Uig
+    }
+
+    fn preserves_normalized_ascii(&self) -> bool {
+        true
+    }
+}
+
+/// Pushes the provided text into `recomposed`, replacing the letters followed by a hamza above by their hamza forms.
+fn recompose_hamza(text: &str, recomposed: &mut String) {
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let hamza_form = match c {
+            'ي' => Some('ئ'),
+            'و' => Some('ؤ'),
+            'ە' => Some('ۀ'),
+            _ => None,
+        };
+
+        match hamza_form {
+            Some(hamza_form) if chars.next_if_eq(&HAMZA_ABOVE).is_some() => {
+                recomposed.push(hamza_form)
+            }
+            _ => recomposed.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AllowList, Language, TokenizerBuilder};
+
+    #[test]
+    fn uyghur() {
+        let allow_list = AllowList::new().languages([Language::Uig]);
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list).create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "ئۇيغۇر تىلى";
+        let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+        let lemmas: Vec<_> = tokens.iter().map(|t| t.lemma()).collect();
+        // the hamza form and the Alef Maksura 'ى' are kept.
+        assert_eq!(lemmas, ["ئۇيغۇر", " ", "تىلى"]);
+        assert_eq!(tokens[0].char_map.as_ref().unwrap()[0], (2, 2));
+        assert_eq!(tokens[0].validate(text), Ok(()));
+    }
+}
@@ -28,6 +28,17 @@ impl Segmenter for ArabicSegmenter {
     }
 }
 
+/// Arabic [`Segmenter`] keeping the words starting with `ال` whole,
+/// for the proper-noun-heavy texts where splitting the article, like in `البانيا` (Albania), hurts the relevancy,
+/// see [`TokenizerBuilder::arabic_article_splitting`](crate::TokenizerBuilder::arabic_article_splitting).
+pub struct ArabicKeepArticleSegmenter;
+
+impl Segmenter for ArabicKeepArticleSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        Box::new(Some(to_segment).into_iter())
+    }
+}
+
 // Test the segmenter:
 #[cfg(test)]
 mod test {
@@ -1,23 +1,92 @@
-use jieba_rs::Jieba;
-use once_cell::sync::Lazy;
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{self, BufRead};
 use std::path::Path;
+use std::sync::Arc;
+
+use jieba_rs::Jieba;
+use once_cell::sync::Lazy;
 
-use crate::segmenter::Segmenter;
+use crate::fingerprint::checksum;
+use crate::segmenter::utils::n_best_segmentations;
+use crate::segmenter::{DictionaryInfo, SegmentOrigin, Segmenter};
 
 /// Chinese Script specialized [`Segmenter`].
 ///
 /// This Segmenter uses [`Jieba`] internally to segment the provided text
 /// without HMM feature.
-pub struct ChineseSegmenter;
+///
+/// By default, the segmenter shares a process-wide [`Jieba`] instance,
+/// a segmenter using its own dictionary can be created with [`ChineseSegmenter::new`]
+/// and assigned to a `Tokenizer` using [`crate::TokenizerBuilder::segmenter`].
+#[derive(Clone)]
+pub struct ChineseSegmenter {
+    jieba: Arc<Jieba>,
+    custom: bool,
+}
+
+impl ChineseSegmenter {
+    /// Creates a segmenter using the provided [`Jieba`] instance.
+    pub fn new(jieba: Arc<Jieba>) -> Self {
+        Self { jieba, custom: true }
+    }
+}
+
+impl Default for ChineseSegmenter {
+    fn default() -> Self {
+        Self { jieba: JIEBA.clone(), custom: false }
+    }
+}
 
 impl Segmenter for ChineseSegmenter {
     fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
-        let segmented = JIEBA.cut(to_segment, false); // disable Hidden Markov Models.
+        let segmented = self.jieba.cut(to_segment, false); // disable Hidden Markov Models.
 
         Box::new(segmented.into_iter())
     }
+
+    fn segment_str_with_origin<'o>(
+        &self,
+        to_segment: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+        let jieba = self.jieba.clone();
+        let segmented = self.jieba.cut(to_segment, false);
+
+        Box::new(segmented.into_iter().map(move |segment| {
+            // all the words returned by `cut_all` are found in the dictionary.
+            let in_dictionary = jieba.cut_all(segment).contains(&segment);
+            (segment, SegmentOrigin { in_dictionary: Some(in_dictionary), cost: None })
+        }))
+    }
+
+    fn segment_str_alternatives<'o>(&self, to_segment: &'o str, n: usize) -> Vec<Vec<&'o str>> {
+        // the words of the dictionary found in the text, ordered by their starting index.
+        let mut words_ending: HashMap<usize, Vec<usize>> = HashMap::new();
+        for word in self.jieba.cut_all(to_segment) {
+            let start = word.as_ptr() as usize - to_segment.as_ptr() as usize;
+            words_ending.entry(start).or_default().push(start + word.len());
+        }
+
+        let default = self.jieba.cut(to_segment, false);
+        n_best_segmentations(to_segment, n, default, |start| {
+            words_ending.remove(&start).unwrap_or_default()
+        })
+    }
+
+    fn dictionaries(&self) -> Vec<DictionaryInfo> {
+        let dictionary = if self.custom {
+            DictionaryInfo { name: "jieba", version: None, source: "custom", checksum: None }
+        } else {
+            // the checksum only covers the added words, the base dictionary is embedded in jieba-rs.
+            DictionaryInfo {
+                name: "jieba",
+                version: None,
+                source: "https://github.com/messense/jieba-rs",
+                checksum: Some(checksum(WORDS.join("\n").as_bytes())),
+            }
+        };
+        vec![dictionary]
+    }
 }
 
 fn read_lines<P>(filename: P) -> Vec<String>
@@ -45,13 +114,15 @@ where
     return vec![];
 }
 
-static JIEBA: Lazy<Jieba> = Lazy::new(|| {
+/// Words added to the default [`Jieba`] dictionary.
+static WORDS: Lazy<Vec<String>> = Lazy::new(|| read_lines("./words.txt"));
+
+static JIEBA: Lazy<Arc<Jieba>> = Lazy::new(|| {
     let mut jieba = Jieba::new();
-    let lines = read_lines("./words.txt");
-    for line in lines {
+    for line in WORDS.iter() {
         jieba.add_word(line.as_str(), Some(99 as usize), None);
     }
-    jieba
+    Arc::new(jieba)
 });
 
 #[cfg(test)]
@@ -135,5 +206,40 @@ mod test {
     ];
 
     // Macro that run several tests on the Segmenter.
-    test_segmenter!(ChineseSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Cj, Language::Cmn);
+    #[test]
+    fn guessed() {
+        let mut builder = crate::TokenizerBuilder::default();
+        builder.mark_guessed(true);
+        let tokenizer = builder.build();
+
+        // the rare char isn't a word of the dictionary.
+        let guessed: Vec<_> = tokenizer.tokenize("北京𠀀").map(|t| t.guessed).collect();
+        assert_eq!(guessed, [false, true]);
+
+        // the tokens are not marked by default.
+        assert!("𠀀".tokenize().all(|t| !t.guessed));
+    }
+
+    #[test]
+    fn segment_str_alternatives() {
+        let alternatives =
+            ChineseSegmenter::default().segment_str_alternatives("南京市长江大桥", 3);
+        assert_eq!(
+            alternatives,
+            [
+                vec!["南京市", "长江大桥"],
+                vec!["南京市", "长江", "大桥"],
+                vec!["南京", "市", "长江大桥"]
+            ]
+        );
+    }
+
+    test_segmenter!(
+        ChineseSegmenter::default(),
+        TEXT,
+        SEGMENTED,
+        TOKENIZED,
+        Script::Cj,
+        Language::Cmn
+    );
 }
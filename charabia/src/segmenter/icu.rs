@@ -0,0 +1,63 @@
+use icu_segmenter::options::WordBreakInvariantOptions;
+use icu_segmenter::{WordSegmenter, WordSegmenterBorrowed};
+use once_cell::sync::Lazy;
+
+use crate::segmenter::{DictionaryInfo, Segmenter};
+
+/// [`Segmenter`] delegating the segmentation to the ICU4X word segmenter.
+///
+/// ICU4X finds the Unicode word boundaries and segments the scripts written without spaces
+/// using its own models, it is used for Myanmar when the `icu` feature is enabled,
+/// and for Thai and Khmer when their dictionary based segmenters, the `thai` and `khmer` features, are disabled.
+pub struct IcuSegmenter;
+
+static WORD_SEGMENTER: Lazy<WordSegmenterBorrowed<'static>> =
+    Lazy::new(|| WordSegmenter::new_auto(WordBreakInvariantOptions::default()));
+
+impl Segmenter for IcuSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        // the segmenter returns the byte offsets of the boundaries, starting with 0.
+        let mut start = 0;
+        let segments =
+            WORD_SEGMENTER.segment_str(to_segment).filter(|end| *end > 0).map(move |end| {
+                let segment = &to_segment[start..end];
+                start = end;
+                segment
+            });
+
+        Box::new(segments)
+    }
+
+    fn dictionaries(&self) -> Vec<DictionaryInfo> {
+        vec![DictionaryInfo {
+            name: "icu4x-segmenter",
+            version: None,
+            source: "https://github.com/unicode-org/icu4x",
+            checksum: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::IcuSegmenter;
+    use crate::segmenter::Segmenter;
+    use crate::{segmenter_for, Language, Script};
+
+    #[test]
+    fn segment() {
+        let text = "ภาษาไทยง่ายนิดเดียว";
+        let segments: Vec<_> = IcuSegmenter.segment_str(text).collect();
+        assert!(segments.len() > 1);
+        assert_eq!(segments.concat(), text);
+    }
+
+    #[test]
+    fn used_for_southeast_asian_scripts() {
+        assert_eq!(segmenter_for(Script::Myanmar, Language::Mya).name(), "IcuSegmenter");
+        #[cfg(not(feature = "thai"))]
+        assert_eq!(segmenter_for(Script::Thai, Language::Tha).name(), "IcuSegmenter");
+        #[cfg(not(feature = "khmer"))]
+        assert_eq!(segmenter_for(Script::Khmer, Language::Khm).name(), "IcuSegmenter");
+    }
+}
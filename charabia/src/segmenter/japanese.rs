@@ -1,44 +1,129 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
 use lindera_core::mode::Mode;
 #[cfg(feature = "japanese-segmentation-ipadic")]
 use lindera_core::mode::Penalty;
-use lindera_dictionary::{DictionaryConfig, DictionaryKind};
+use lindera_core::LinderaResult;
+use lindera_dictionary::DictionaryConfig;
+#[cfg(any(
+    feature = "japanese-segmentation-ipadic",
+    feature = "japanese-segmentation-unidic"
+))]
+use lindera_dictionary::DictionaryKind;
 use lindera_tokenizer::tokenizer::{Tokenizer, TokenizerConfig};
+#[cfg(any(
+    feature = "japanese-segmentation-ipadic",
+    feature = "japanese-segmentation-unidic"
+))]
 use once_cell::sync::Lazy;
 
-use crate::segmenter::Segmenter;
+use crate::segmenter::{DictionaryInfo, SegmentOrigin, Segmenter};
 
 /// Japanese specialized [`Segmenter`].
 ///
 /// This Segmenter uses lindera internally to segment the provided text.
-pub struct JapaneseSegmenter;
+///
+/// By default, the segmenter shares a process-wide lindera [`Tokenizer`],
+/// a segmenter using its own dictionary or mode can be created with [`JapaneseSegmenter::new`]
+/// and assigned to a `Tokenizer` using [`crate::TokenizerBuilder::segmenter`].
+///
+/// The default dictionary is embedded in the binary by the `japanese-segmentation-ipadic` or `japanese-segmentation-unidic` feature,
+/// the `japanese-segmentation-external` feature only builds the segmenter,
+/// the dictionary must then be loaded at runtime using [`JapaneseSegmenter::from_path`].
+#[derive(Clone)]
+pub struct JapaneseSegmenter {
+    lindera: Arc<Tokenizer>,
+    dictionary: DictionaryInfo,
+}
+
+impl JapaneseSegmenter {
+    /// Creates a segmenter using the provided lindera [`Tokenizer`].
+    pub fn new(lindera: Arc<Tokenizer>) -> Self {
+        let dictionary =
+            DictionaryInfo { name: "lindera", version: None, source: "custom", checksum: None };
+        Self { lindera, dictionary }
+    }
+
+    /// Creates a segmenter loading the dictionary described by the provided configuration.
+    ///
+    /// IPADIC words are decomposed if the `japanese-segmentation-ipadic` feature is enabled,
+    /// like with the embedded dictionary.
+    pub fn from_dictionary_config(dictionary: DictionaryConfig) -> LinderaResult<Self> {
+        #[cfg(feature = "japanese-segmentation-ipadic")]
+        let mode = Mode::Decompose(Penalty::default());
+        #[cfg(not(feature = "japanese-segmentation-ipadic"))]
+        let mode = Mode::Normal;
+
+        let config = TokenizerConfig { dictionary, mode, ..TokenizerConfig::default() };
+        Ok(Self::new(Arc::new(Tokenizer::from_config(config)?)))
+    }
+
+    /// Creates a segmenter loading a prebuilt IPADIC or UniDic dictionary from the provided directory.
+    pub fn from_path(path: impl Into<PathBuf>) -> LinderaResult<Self> {
+        Self::from_dictionary_config(DictionaryConfig { kind: None, path: Some(path.into()) })
+    }
+}
 
-static LINDERA: Lazy<Tokenizer> = Lazy::new(|| {
+#[cfg(any(feature = "japanese-segmentation-ipadic", feature = "japanese-segmentation-unidic"))]
+impl Default for JapaneseSegmenter {
+    fn default() -> Self {
+        #[cfg(feature = "japanese-segmentation-ipadic")]
+        let dictionary = DictionaryInfo {
+            name: "ipadic",
+            version: Some("2.7.0-20070801"),
+            source: "https://sourceforge.net/projects/mecab/files/mecab-ipadic",
+            checksum: None,
+        };
+        #[cfg(feature = "japanese-segmentation-unidic")]
+        let dictionary = DictionaryInfo {
+            name: "unidic",
+            version: Some("2.1.2"),
+            source: "https://clrd.ninjal.ac.jp/unidic",
+            checksum: None,
+        };
+        Self { lindera: LINDERA.clone(), dictionary }
+    }
+}
+
+#[cfg(any(feature = "japanese-segmentation-ipadic", feature = "japanese-segmentation-unidic"))]
+static LINDERA: Lazy<Arc<Tokenizer>> = Lazy::new(|| {
     #[cfg(all(feature = "japanese-segmentation-ipadic", feature = "japanese-segmentation-unidic"))]
     compile_error!("Feature japanese-segmentation-ipadic and japanese-segmentation-unidic are mutually exclusive and cannot be enabled together");
 
     #[cfg(feature = "japanese-segmentation-ipadic")]
-    let config = TokenizerConfig {
-        dictionary: DictionaryConfig { kind: Some(DictionaryKind::IPADIC), path: None },
-        mode: Mode::Decompose(Penalty::default()),
-        ..TokenizerConfig::default()
-    };
+    let kind = DictionaryKind::IPADIC;
     #[cfg(feature = "japanese-segmentation-unidic")]
-    let config = TokenizerConfig {
-        dictionary: DictionaryConfig { kind: Some(DictionaryKind::UniDic), path: None },
-        mode: Mode::Normal,
-        ..TokenizerConfig::default()
-    };
-    Tokenizer::from_config(config).unwrap()
+    let kind = DictionaryKind::UniDic;
+
+    let dictionary = DictionaryConfig { kind: Some(kind), path: None };
+    JapaneseSegmenter::from_dictionary_config(dictionary).unwrap().lindera
 });
 
 impl Segmenter for JapaneseSegmenter {
     fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
-        let segment_iterator = LINDERA.tokenize(to_segment).unwrap();
+        let segment_iterator = self.lindera.tokenize(to_segment).unwrap();
         Box::new(segment_iterator.into_iter().map(|token| token.text))
     }
+
+    fn segment_str_with_origin<'o>(
+        &self,
+        to_segment: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+        let segment_iterator = self.lindera.tokenize(to_segment).unwrap();
+        // the costs of the lattice aren't exposed by the tokens of lindera.
+        Box::new(segment_iterator.into_iter().map(|token| {
+            let in_dictionary = Some(!token.word_id.is_unknown());
+            (token.text, SegmentOrigin { in_dictionary, cost: None })
+        }))
+    }
+
+    fn dictionaries(&self) -> Vec<DictionaryInfo> {
+        vec![self.dictionary.clone()]
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "japanese"))]
 mod test {
     use crate::segmenter::test::test_segmenter;
 
@@ -132,6 +217,18 @@ mod test {
     #[cfg(all(feature = "japanese-segmentation-ipadic", feature = "japanese-segmentation-unidic"))]
     compile_error!("Feature japanese-segmentation-ipadic and japanese-segmentation-unidic are mutually exclusive and cannot be enabled together");
 
+    #[test]
+    fn from_missing_path() {
+        assert!(JapaneseSegmenter::from_path("missing/unidic").is_err());
+    }
+
     // Macro that run several tests on the Segmenter.
-    test_segmenter!(JapaneseSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Cj, Language::Jpn);
+    test_segmenter!(
+        JapaneseSegmenter::default(),
+        TEXT,
+        SEGMENTED,
+        TOKENIZED,
+        Script::Cj,
+        Language::Jpn
+    );
 }
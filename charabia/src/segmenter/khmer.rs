@@ -1,8 +1,12 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use charabia_fst_dictionaries::KHMER_WORDS_FST;
 use fst::raw::Fst;
 
 // Import `Segmenter` trait.
-use crate::segmenter::utils::FstSegmenter;
-use crate::segmenter::Segmenter;
+use crate::segmenter::utils::{FstSegmenter, WordsFst};
+use crate::segmenter::{DictionaryInfo, SegmentOrigin, Segmenter};
 
 extern crate alloc; // required as my-data-mod is written for #[no_std]
 
@@ -15,10 +19,8 @@ extern crate alloc; // required as my-data-mod is written for #[no_std]
 use once_cell::sync::Lazy;
 
 // dictionary source - https://github.com/unicode-org/icu/blob/main/icu4c/source/data/brkitr/dictionaries/khmerdict.txt
-static WORDS_FST: Lazy<Fst<&[u8]>> =
-    Lazy::new(|| Fst::new(&include_bytes!("../../dictionaries/fst/khmer/words.fst")[..]).unwrap());
-
-static FST_SEGMENTER: Lazy<FstSegmenter> = Lazy::new(|| FstSegmenter::new(&WORDS_FST));
+static WORDS_FST: Lazy<Arc<WordsFst>> =
+    Lazy::new(|| Arc::new(Fst::new(Cow::Borrowed(KHMER_WORDS_FST)).unwrap()));
 
 // Make a small documentation of the specialized Segmenter like below.
 /// <Script/Language> specialized [`Segmenter`].
@@ -29,12 +31,52 @@ static FST_SEGMENTER: Lazy<FstSegmenter> = Lazy::new(|| FstSegmenter::new(&WORDS
 //TIP: Name the Segmenter with its purpose and not its internal behavior:
 //     prefer JapaneseSegmenter (based on the Language) instead of LinderaSegmenter (based on the used Library).
 //     Same for the filename, prefer `japanese.rs` instead of `lindera.rs`.
-pub struct KhmerSegmenter;
+///
+/// By default, the segmenter shares the embedded dictionary,
+/// a segmenter using its own dictionary can be created with [`KhmerSegmenter::new`]
+/// and assigned to a `Tokenizer` using [`crate::TokenizerBuilder::segmenter`].
+#[derive(Clone)]
+pub struct KhmerSegmenter {
+    fst_segmenter: FstSegmenter,
+}
+
+impl KhmerSegmenter {
+    /// Creates a segmenter using the provided dictionary of words.
+    pub fn new(words_fst: Arc<WordsFst>) -> Self {
+        Self { fst_segmenter: FstSegmenter::new(words_fst, DICTIONARY_NAME, "custom") }
+    }
+}
+
+impl Default for KhmerSegmenter {
+    fn default() -> Self {
+        let fst_segmenter =
+            FstSegmenter::new(WORDS_FST.clone(), DICTIONARY_NAME, DICTIONARY_SOURCE);
+        Self { fst_segmenter }
+    }
+}
+
+const DICTIONARY_NAME: &str = "khmer-words";
+const DICTIONARY_SOURCE: &str = "https://github.com/unicode-org/icu/blob/main/icu4c/source/data/brkitr/dictionaries/khmerdict.txt";
 
 // All specialized segmenters only need to implement the method `segment_str` of the `Segmenter` trait.
 impl Segmenter for KhmerSegmenter {
     fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
-        FST_SEGMENTER.segment_str(to_segment)
+        self.fst_segmenter.segment_str(to_segment)
+    }
+
+    fn segment_str_with_origin<'o>(
+        &self,
+        to_segment: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+        self.fst_segmenter.segment_str_with_origin(to_segment)
+    }
+
+    fn segment_str_alternatives<'o>(&self, to_segment: &'o str, n: usize) -> Vec<Vec<&'o str>> {
+        self.fst_segmenter.segment_str_alternatives(to_segment, n)
+    }
+
+    fn dictionaries(&self) -> Vec<DictionaryInfo> {
+        vec![self.fst_segmenter.dictionary()]
     }
 }
 
@@ -58,7 +100,14 @@ mod test {
     const TOKENIZED: &[&str] = &["សួស្តី", "ពិភពលោក"];
 
     // Macro that run several tests on the Segmenter.
-    test_segmenter!(KhmerSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Khmer, Language::Khm);
+    test_segmenter!(
+        KhmerSegmenter::default(),
+        TEXT,
+        SEGMENTED,
+        TOKENIZED,
+        Script::Khmer,
+        Language::Khm
+    );
 }
 
 // Include the newly implemented Segmenter in the tokenization pipeline:
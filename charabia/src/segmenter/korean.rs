@@ -1,32 +1,101 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
 use lindera_core::mode::{Mode, Penalty};
-use lindera_dictionary::{DictionaryConfig, DictionaryKind};
+use lindera_core::LinderaResult;
+use lindera_dictionary::DictionaryConfig;
+#[cfg(feature = "korean")]
+use lindera_dictionary::DictionaryKind;
 use lindera_tokenizer::tokenizer::{Tokenizer, TokenizerConfig};
+#[cfg(feature = "korean")]
 use once_cell::sync::Lazy;
 
-use crate::segmenter::Segmenter;
+use crate::segmenter::{DictionaryInfo, SegmentOrigin, Segmenter};
 
 /// Korean specialized [`Segmenter`].
 ///
 /// This Segmenter uses lindera internally to segment the provided text.
-pub struct KoreanSegmenter;
-
-static LINDERA: Lazy<Tokenizer> = Lazy::new(|| {
-    let config = TokenizerConfig {
-        dictionary: DictionaryConfig { kind: Some(DictionaryKind::KoDic), path: None },
-        mode: Mode::Decompose(Penalty::default()),
-        ..TokenizerConfig::default()
-    };
-    Tokenizer::from_config(config).unwrap()
+///
+/// By default, the segmenter shares a process-wide lindera [`Tokenizer`],
+/// a segmenter using its own dictionary or mode can be created with [`KoreanSegmenter::new`]
+/// and assigned to a `Tokenizer` using [`crate::TokenizerBuilder::segmenter`].
+///
+/// The default ko-dic dictionary is embedded in the binary by the `korean` feature,
+/// the `korean-segmentation-external` feature only builds the segmenter,
+/// the dictionary must then be loaded at runtime using [`KoreanSegmenter::from_path`].
+#[derive(Clone)]
+pub struct KoreanSegmenter {
+    lindera: Arc<Tokenizer>,
+    dictionary: DictionaryInfo,
+}
+
+impl KoreanSegmenter {
+    /// Creates a segmenter using the provided lindera [`Tokenizer`].
+    pub fn new(lindera: Arc<Tokenizer>) -> Self {
+        let dictionary =
+            DictionaryInfo { name: "lindera", version: None, source: "custom", checksum: None };
+        Self { lindera, dictionary }
+    }
+
+    /// Creates a segmenter loading the dictionary described by the provided configuration.
+    pub fn from_dictionary_config(dictionary: DictionaryConfig) -> LinderaResult<Self> {
+        let config = TokenizerConfig {
+            dictionary,
+            mode: Mode::Decompose(Penalty::default()),
+            ..TokenizerConfig::default()
+        };
+        Ok(Self::new(Arc::new(Tokenizer::from_config(config)?)))
+    }
+
+    /// Creates a segmenter loading a prebuilt ko-dic dictionary from the provided directory.
+    pub fn from_path(path: impl Into<PathBuf>) -> LinderaResult<Self> {
+        Self::from_dictionary_config(DictionaryConfig { kind: None, path: Some(path.into()) })
+    }
+}
+
+#[cfg(feature = "korean")]
+impl Default for KoreanSegmenter {
+    fn default() -> Self {
+        let dictionary = DictionaryInfo {
+            name: "ko-dic",
+            version: Some("2.1.1-20180720"),
+            source: "https://bitbucket.org/eunjeon/mecab-ko-dic",
+            checksum: None,
+        };
+        Self { lindera: LINDERA.clone(), dictionary }
+    }
+}
+
+#[cfg(feature = "korean")]
+static LINDERA: Lazy<Arc<Tokenizer>> = Lazy::new(|| {
+    let dictionary = DictionaryConfig { kind: Some(DictionaryKind::KoDic), path: None };
+    KoreanSegmenter::from_dictionary_config(dictionary).unwrap().lindera
 });
 
 impl Segmenter for KoreanSegmenter {
     fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
-        let segment_iterator = LINDERA.tokenize(to_segment).unwrap();
+        let segment_iterator = self.lindera.tokenize(to_segment).unwrap();
         Box::new(segment_iterator.into_iter().map(|token| token.text))
     }
+
+    fn segment_str_with_origin<'o>(
+        &self,
+        to_segment: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+        let segment_iterator = self.lindera.tokenize(to_segment).unwrap();
+        // the costs of the lattice aren't exposed by the tokens of lindera.
+        Box::new(segment_iterator.into_iter().map(|token| {
+            let in_dictionary = Some(!token.word_id.is_unknown());
+            (token.text, SegmentOrigin { in_dictionary, cost: None })
+        }))
+    }
+
+    fn dictionaries(&self) -> Vec<DictionaryInfo> {
+        vec![self.dictionary.clone()]
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "korean"))]
 mod test {
     use crate::segmenter::test::test_segmenter;
 
@@ -38,6 +107,18 @@ mod test {
     const TOKENIZED: &[&str] =
         &["한국어", "의", "형태", "해석", "을", "실시", "할", "수", "있", "습니다", "."];
 
+    #[test]
+    fn from_missing_path() {
+        assert!(KoreanSegmenter::from_path("missing/ko-dic").is_err());
+    }
+
     // Macro that run several tests on the Segmenter.
-    test_segmenter!(KoreanSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Hangul, Language::Kor);
+    test_segmenter!(
+        KoreanSegmenter::default(),
+        TEXT,
+        SEGMENTED,
+        TOKENIZED,
+        Script::Hangul,
+        Language::Kor
+    );
 }
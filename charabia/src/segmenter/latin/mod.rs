@@ -1,5 +1,10 @@
 #[cfg(feature = "latin-camelcase")]
 mod camel_case;
+#[cfg(feature = "latin-word-boundaries")]
+mod word_boundary;
+
+#[cfg(feature = "latin-word-boundaries")]
+pub use word_boundary::LatinWordBoundarySegmenter;
 
 use crate::segmenter::Segmenter;
 
@@ -0,0 +1,54 @@
+use either::Either;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::segmenter::Segmenter;
+
+/// Latin [`Segmenter`] splitting the text on the Unicode word boundaries defined by [UAX #29](https://www.unicode.org/reports/tr29/#Word_Boundaries).
+///
+/// Unlike [`LatinSegmenter`](super::LatinSegmenter), the text is not split on the separators first,
+/// so the punctuation inside words like "can't" or numbers like "32.3" doesn't split them.
+/// The segments that don't contain any alphanumeric character are split by character to be classified as separators.
+pub struct LatinWordBoundarySegmenter;
+
+impl Segmenter for LatinWordBoundarySegmenter {
+    fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        let segments = s.split_word_bounds().flat_map(|segment| {
+            if segment.chars().any(char::is_alphanumeric) {
+                Either::Left(Some(segment).into_iter())
+            } else {
+                Either::Right(
+                    segment.char_indices().map(move |(i, c)| &segment[i..i + c.len_utf8()]),
+                )
+            }
+        });
+
+        Box::new(segments)
+    }
+
+    fn splits_separators(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::TokenizerBuilder;
+
+    #[test]
+    fn word_boundaries() {
+        let mut builder = TokenizerBuilder::default();
+        builder.latin_word_boundaries(true);
+        let tokenizer = builder.build();
+
+        let text = "The fox can't jump 32.3 feet,  right?";
+        let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+        let words: Vec<_> = tokens.iter().filter(|t| t.is_word()).map(|t| t.lemma()).collect();
+        assert_eq!(words, ["the", "fox", "can't", "jump", "32.3", "feet", "right"]);
+        assert!(tokens.iter().filter(|t| !t.is_word()).all(|t| t.is_separator()));
+
+        builder.latin_word_boundaries(false);
+        let words: Vec<_> =
+            builder.build().tokenize(text).filter(|t| t.is_word()).map(|t| t.lemma).collect();
+        assert_eq!(words, ["the", "fox", "can", "t", "jump", "32", "3", "feet", "right"]);
+    }
+}
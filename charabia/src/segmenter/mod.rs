@@ -1,97 +1,286 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 
-use aho_corasick::{AhoCorasick, FindIter, MatchKind};
-pub use arabic::ArabicSegmenter;
+use aho_corasick::{AhoCorasick, AhoCorasickKind, Input, MatchKind};
+pub use arabic::{ArabicKeepArticleSegmenter, ArabicSegmenter};
 #[cfg(feature = "chinese")]
 pub use chinese::ChineseSegmenter;
 use either::Either;
-#[cfg(feature = "japanese")]
+#[cfg(feature = "icu")]
+pub use icu::IcuSegmenter;
+#[cfg(any(feature = "japanese", feature = "japanese-segmentation-external"))]
 pub use japanese::JapaneseSegmenter;
-#[cfg(feature = "korean")]
+#[cfg(feature = "khmer")]
+pub use khmer::KhmerSegmenter;
+#[cfg(any(feature = "korean", feature = "korean-segmentation-external"))]
 pub use korean::KoreanSegmenter;
 pub use latin::LatinSegmenter;
+#[cfg(feature = "latin-word-boundaries")]
+pub use latin::LatinWordBoundarySegmenter;
+pub use mongolian::MongolianSegmenter;
 use once_cell::sync::Lazy;
-use slice_group_by::StrGroupBy;
+#[cfg(feature = "protected-patterns")]
+use regex::Regex;
+#[cfg(feature = "sinhala")]
+pub use sinhala::SinhalaSegmenter;
 #[cfg(feature = "thai")]
 pub use thai::ThaiSegmenter;
+use unicode_normalization::char::is_combining_mark;
+pub use utils::WordsFst;
+pub use whitespace::WhitespaceSegmenter;
 
-#[cfg(feature = "khmer")]
-pub use khmer::KhmerSegmenter;
-
-use crate::detection::{Detect, Language, Script, StrDetection};
-use crate::separators::DEFAULT_SEPARATORS;
+use crate::detection::{CjScript, Detect, DetectionCache, Language, Script, StrDetection};
+use crate::metrics::TokenizerObserver;
+use crate::separators::{SeparatorSet, DEFAULT_SEPARATORS};
 use crate::token::Token;
 
 mod arabic;
 #[cfg(feature = "chinese")]
 mod chinese;
-#[cfg(feature = "japanese")]
+#[cfg(feature = "icu")]
+mod icu;
+#[cfg(any(feature = "japanese", feature = "japanese-segmentation-external"))]
 mod japanese;
 #[cfg(feature = "khmer")]
 mod khmer;
-#[cfg(feature = "korean")]
+#[cfg(any(feature = "korean", feature = "korean-segmentation-external"))]
 mod korean;
 mod latin;
+mod mongolian;
+#[cfg(feature = "sinhala")]
+mod sinhala;
 #[cfg(feature = "thai")]
 mod thai;
 mod utils;
+mod whitespace;
 
 /// List of used [`Segmenter`]s linked to their corresponding [`Script`] and [`Language`].
 ///
-/// This list is used after `Script` and `Language` detection to pick the specialized [`Segmenter`].
-/// If no segmenter corresponds to the `Language`,
-/// then the segmenter corresponding to the `Script` is picked.
-/// If no segmenter corresponds to both `Script` and `Language`,
-/// then the [`DEFAULT_SEGMENTER`] is picked.
+/// This list is used after `Script` and `Language` detection to pick the specialized [`Segmenter`],
+/// following these rules, in order of precedence:
+/// 1. if only one segmenter is assigned to the `Script`, it is picked without detecting the `Language`,
+/// 2. the segmenter assigned to both the `Script` and the detected `Language`,
+/// 3. the segmenter assigned to the `Script` and `Language::Other`,
+/// 4. the [`DEFAULT_SEGMENTER`].
 ///
 /// A segmenter assigned to `Language::Other` is considered as the default `Segmenter` for any `Language` that uses the assigned `Script`.
 /// For example, [`LatinSegmenter`] is assigned to `(Script::Latin, Language::Other)`,
 /// meaning that `LatinSegmenter` is the default `Segmenter` for any `Language` that uses `Latin` `Script`.
-pub static SEGMENTERS: Lazy<HashMap<(Script, Language), Box<dyn Segmenter>>> = Lazy::new(|| {
+/// Several segmenters can be assigned to the languages of a `Script`, like a German compound splitter to `(Script::Latin, Language::Deu)`,
+/// the other languages still falling back on the segmenter of `Language::Other`.
+/// The `Language` of all the texts of this `Script` is then detected to pick their segmenter,
+/// so their tokens get the detected `Language` and the normalizers specialized for it.
+///
+/// The segmenters provided with [`TokenizerBuilder::segmenter`](crate::TokenizerBuilder::segmenter)
+/// take precedence over the ones of this list for the same `Script` and `Language`.
+/// The list is ordered, so the selection never depends on the build:
+/// if a `Script` and `Language` pair is assigned twice, the first segmenter is picked.
+pub static SEGMENTERS: Lazy<SegmenterList> = Lazy::new(|| {
     vec![
         // latin segmenter
         ((Script::Latin, Language::Other), Box::new(LatinSegmenter) as Box<dyn Segmenter>),
         // chinese segmenter
         #[cfg(feature = "chinese")]
-        ((Script::Cj, Language::Cmn), Box::new(ChineseSegmenter) as Box<dyn Segmenter>),
+        ((Script::Cj, Language::Cmn), Box::new(ChineseSegmenter::default()) as Box<dyn Segmenter>),
         // japanese segmenter
         #[cfg(feature = "japanese")]
-        ((Script::Cj, Language::Jpn), Box::new(JapaneseSegmenter) as Box<dyn Segmenter>),
+        ((Script::Cj, Language::Jpn), Box::new(JapaneseSegmenter::default()) as Box<dyn Segmenter>),
         // korean segmenter
         #[cfg(feature = "korean")]
-        ((Script::Hangul, Language::Kor), Box::new(KoreanSegmenter) as Box<dyn Segmenter>),
+        (
+            (Script::Hangul, Language::Kor),
+            Box::new(KoreanSegmenter::default()) as Box<dyn Segmenter>,
+        ),
         // thai segmenter
         #[cfg(feature = "thai")]
-        ((Script::Thai, Language::Tha), Box::new(ThaiSegmenter) as Box<dyn Segmenter>),
+        ((Script::Thai, Language::Tha), Box::new(ThaiSegmenter::default()) as Box<dyn Segmenter>),
         #[cfg(feature = "khmer")]
-        ((Script::Khmer, Language::Khm), Box::new(KhmerSegmenter) as Box<dyn Segmenter>),
+        ((Script::Khmer, Language::Khm), Box::new(KhmerSegmenter::default()) as Box<dyn Segmenter>),
         // arabic segmenter
         ((Script::Arabic, Language::Ara), Box::new(ArabicSegmenter) as Box<dyn Segmenter>),
+        // mongolian segmenter
+        ((Script::Mongolian, Language::Other), Box::new(MongolianSegmenter) as Box<dyn Segmenter>),
+        // ICU4X segmenter, used for the scripts whose dictionary based segmenter is disabled
+        #[cfg(all(feature = "icu", not(feature = "thai")))]
+        ((Script::Thai, Language::Tha), Box::new(IcuSegmenter) as Box<dyn Segmenter>),
+        #[cfg(all(feature = "icu", not(feature = "khmer")))]
+        ((Script::Khmer, Language::Khm), Box::new(IcuSegmenter) as Box<dyn Segmenter>),
+        #[cfg(feature = "icu")]
+        ((Script::Myanmar, Language::Mya), Box::new(IcuSegmenter) as Box<dyn Segmenter>),
     ]
-    .into_iter()
-    .collect()
 });
 
+/// Returns the first segmenter of [`SEGMENTERS`] assigned to the provided `Script` and `Language`.
+fn registered_segmenter(script: Script, language: Language) -> Option<&'static dyn Segmenter> {
+    SEGMENTERS.iter().find(|(key, _)| *key == (script, language)).map(|(_, segmenter)| &**segmenter)
+}
+
+/// Returns the `Script` and `Language` pairs of [`SEGMENTERS`], in their order.
+fn registered_keys<'a>() -> impl Iterator<Item = &'a (Script, Language)> {
+    SEGMENTERS.iter().map(|(key, _)| key)
+}
+
 /// Picked [`Segmenter`] when no segmenter is specialized to the detected [`Script`].
 pub static DEFAULT_SEGMENTER: Lazy<Box<dyn Segmenter>> = Lazy::new(|| Box::new(LatinSegmenter));
 
-pub static DEFAULT_SEPARATOR_AHO: Lazy<AhoCorasick> = Lazy::new(|| {
-    AhoCorasick::builder().match_kind(MatchKind::LeftmostLongest).build(DEFAULT_SEPARATORS).unwrap()
-});
+pub static DEFAULT_SEPARATOR_AHO: Lazy<AhoCorasick> =
+    Lazy::new(|| AhoOption::default().build(DEFAULT_SEPARATORS));
+
+pub static DEFAULT_SEPARATOR_PREFILTER: Lazy<SeparatorPrefilter> =
+    Lazy::new(|| SeparatorPrefilter::new(DEFAULT_SEPARATORS).unwrap());
+
+/// Fast search of the bytes starting the patterns of a separator automaton,
+/// skipping the text that can't contain a separator before running the automaton.
+///
+/// The automaton can't use its own prefilter when the patterns start with many different bytes,
+/// like the [`DEFAULT_SEPARATORS`], and then walks every byte of the text,
+/// which is slow on the long texts containing few separators.
+/// The candidate bytes are searched with `memchr` when there are at most three of them,
+/// and with a lookup table otherwise.
+#[derive(Debug, Clone)]
+pub struct SeparatorPrefilter {
+    bytes: Vec<u8>,
+    table: [bool; 256],
+}
+
+impl SeparatorPrefilter {
+    /// Creates the prefilter of the provided patterns,
+    /// returns `None` if a pattern is empty, as it can be found anywhere.
+    pub fn new<I, P>(patterns: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        let mut table = [false; 256];
+        for pattern in patterns {
+            let first = *pattern.as_ref().first()?;
+            table[first as usize] = true;
+        }
+        let bytes = (0..=u8::MAX).filter(|byte| table[*byte as usize]).collect();
+        Some(Self { bytes, table })
+    }
+
+    /// Returns the index of the first byte of the haystack that can start a pattern.
+    pub fn find(&self, haystack: &[u8]) -> Option<usize> {
+        match self.bytes[..] {
+            [] => None,
+            [a] => memchr::memchr(a, haystack),
+            [a, b] => memchr::memchr2(a, b, haystack),
+            [a, b, c] => memchr::memchr3(a, b, c, haystack),
+            _ => haystack.iter().position(|byte| self.table[*byte as usize]),
+        }
+    }
+}
+
+/// Build options of the Aho-Corasick automaton searching the separators and the words of the dictionary,
+/// see [`crate::TokenizerBuilder::separator_automaton`].
+///
+/// The default options are the ones of [`DEFAULT_SEPARATOR_AHO`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AhoOption {
+    /// implementation of the automaton, chosen by the `aho_corasick` crate depending on the patterns if `None`.
+    ///
+    /// A DFA is faster to search but slower to build and uses more memory than the NFAs,
+    /// which can be worth it for small separator sets applied on large texts.
+    pub kind: Option<AhoCorasickKind>,
+    /// use a prefilter to quickly skip the text that can't contain a match,
+    /// which slows down the texts containing many separators.
+    pub prefilter: bool,
+    /// overlapping matches resolution, [`MatchKind::LeftmostLongest`] making the longest separators win.
+    pub match_kind: MatchKind,
+}
+
+impl Default for AhoOption {
+    fn default() -> Self {
+        Self { kind: None, prefilter: true, match_kind: MatchKind::LeftmostLongest }
+    }
+}
+
+impl AhoOption {
+    /// Builds the automaton matching the provided patterns.
+    pub fn build<I, P>(&self, patterns: I) -> AhoCorasick
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        AhoCorasick::builder()
+            .kind(self.kind)
+            .prefilter(self.prefilter)
+            .match_kind(self.match_kind)
+            .build(patterns)
+            .unwrap()
+    }
+}
+
+static DEFAULT_SEGMENTER_OPTION: Lazy<SegmenterOption<'static>> =
+    Lazy::new(SegmenterOption::default);
 
 /// Iterator over segmented [`Token`]s.
 pub struct SegmentedTokenIter<'o, 'tb> {
     inner: SegmentedStrIter<'o, 'tb>,
     char_index: usize,
     byte_index: usize,
+    token_count: usize,
+    truncated: bool,
+    /// rest of the last segmented str, after a script transition.
+    remainder: Option<&'o str>,
+}
+
+impl SegmentedTokenIter<'_, '_> {
+    /// Returns true if the last segmented token is a protected term,
+    /// meaning that it must not be normalized.
+    pub(crate) fn is_protected(&self) -> bool {
+        self.inner.protected
+    }
+
+    /// Returns true if the tokenization stopped early because the text exceeds
+    /// [`SegmenterOption::max_input_bytes`] or [`SegmenterOption::max_tokens`].
+    ///
+    /// The returned value is only final once the iterator is exhausted.
+    pub fn is_truncated(&self) -> bool {
+        self.inner.truncated || self.truncated
+    }
+
+    /// Returns the observer of the statistics of the tokenization, if any.
+    pub(crate) fn observer(&self) -> Option<&dyn TokenizerObserver> {
+        self.inner.options.observer.as_deref()
+    }
 }
 
 impl<'o> Iterator for SegmentedTokenIter<'o, '_> {
     type Item = Token<'o>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let lemma = self.inner.next()?;
+        if self.inner.options.max_tokens.is_some_and(|max| self.token_count >= max) {
+            // only flag the tokenization as truncated if some tokens are left.
+            self.truncated =
+                self.truncated || self.remainder.is_some() || self.inner.next().is_some();
+            return None;
+        }
+
+        let (mut lemma, mut split) = match self.remainder.take() {
+            Some(remainder) => (remainder, true),
+            None => (self.inner.next()?, false),
+        };
+        let (mut script, mut language) = (self.inner.script, self.inner.language);
+        if self.inner.options.split_mixed_scripts && !self.inner.protected {
+            // the chars without script stay with the preceding chars, like when grouping the text by script.
+            let (head, head_script) = ScriptGroups(lemma).next().unwrap_or((lemma, Script::Other));
+            if head.len() < lemma.len() {
+                self.remainder = Some(&lemma[head.len()..]);
+                lemma = head;
+                split = true;
+            }
+
+            if split && head_script != Script::Other && head_script != script {
+                script = head_script;
+                language = None;
+            }
+        }
+
+        self.token_count += 1;
         let char_start = self.char_index;
         let byte_start = self.byte_index;
 
@@ -100,12 +289,15 @@ impl<'o> Iterator for SegmentedTokenIter<'o, '_> {
 
         Some(Token {
             lemma: Cow::Borrowed(lemma),
-            script: self.inner.script,
-            language: self.inner.language,
+            script,
+            language,
             char_start,
             char_end: self.char_index,
             byte_start,
             byte_end: self.byte_index,
+            guessed: self.inner.options.mark_guessed
+                && self.inner.origin.in_dictionary == Some(false),
+            cost: self.inner.options.segment_costs.then_some(self.inner.origin.cost).flatten(),
             ..Default::default()
         })
     }
@@ -113,46 +305,134 @@ impl<'o> Iterator for SegmentedTokenIter<'o, '_> {
 
 impl<'o, 'tb> From<SegmentedStrIter<'o, 'tb>> for SegmentedTokenIter<'o, 'tb> {
     fn from(segmented_str_iter: SegmentedStrIter<'o, 'tb>) -> Self {
-        Self { inner: segmented_str_iter, char_index: 0, byte_index: 0 }
+        Self {
+            inner: segmented_str_iter,
+            char_index: 0,
+            byte_index: 0,
+            token_count: 0,
+            truncated: false,
+            remainder: None,
+        }
     }
 }
 
 pub struct SegmentedStrIter<'o, 'tb> {
-    inner: Box<dyn Iterator<Item = &'o str> + 'o>,
-    current: Box<dyn Iterator<Item = &'o str> + 'o>,
+    protected_iter: Option<ProtectedStrIter<'o, 'tb>>,
+    inner: Box<dyn Iterator<Item = (&'o str, Script)> + 'o>,
+    current: Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o>,
     aho_iter: Option<AhoSegmentedStrIter<'o, 'tb>>,
-    segmenter: &'static dyn Segmenter,
+    segmenter: &'tb dyn Segmenter,
     options: &'tb SegmenterOption<'tb>,
     script: Script,
     language: Option<Language>,
+    protected: bool,
+    truncated: bool,
+    /// origin of the last segment, only known if the guessed segments are marked or the costs kept.
+    origin: SegmentOrigin,
 }
 
 impl<'o, 'tb> SegmentedStrIter<'o, 'tb> {
     pub fn new(original: &'o str, options: &'tb SegmenterOption<'tb>) -> Self {
-        let mut current_script = Script::Other;
-        let mut group_id = 0;
-        let inner = original.linear_group_by_key(move |c| {
-            let script = Script::from(c);
-            if script != Script::Other && script != current_script {
-                // if both previous and current scripts are differents than Script::Other,
-                // split into a new script group.
-                if current_script != Script::Other {
-                    group_id += 1;
-                }
-                current_script = script
-            }
-            group_id
-        });
+        // only the beginning of a too long text is segmented, cut on a char boundary
+        // which is not followed by a combining mark, like a Tamil vowel sign, to keep it with its base character,
+        // and which is not inside a word, the trailing part of the cut word being dropped.
+        let mut truncated = false;
+        let mut original = original;
+        if let Some(max_input_bytes) = options.max_input_bytes.filter(|max| *max < original.len()) {
+            let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+            let end = (0..=max_input_bytes)
+                .rev()
+                .filter(|i| original.is_char_boundary(*i))
+                .find(|i| {
+                    let (before, after) = original.split_at(*i);
+                    let in_word =
+                        is_word(before.chars().next_back()) && is_word(after.chars().next());
+                    !in_word && !after.starts_with(is_combining_mark)
+                })
+                .unwrap_or(0);
+            original = &original[..end];
+            truncated = true;
+        }
+
+        // protected terms are found before splitting the text by script.
+        let (protected_iter, inner) = match ProtectedStrIter::new(original, options) {
+            Some(protected_iter) => (Some(protected_iter), split_text("", options)),
+            None => (None, split_text(original, options)),
+        };
 
         Self {
-            inner: Box::new(inner),
+            protected_iter,
+            inner,
             current: Box::new(None.into_iter()),
             aho_iter: None,
             segmenter: &*DEFAULT_SEGMENTER,
             options,
             script: Script::Other,
             language: None,
+            protected: false,
+            truncated,
+            origin: SegmentOrigin::default(),
+        }
+    }
+
+    /// Segments the provided text with the current segmenter,
+    /// only looking for the origin of the segments if the guessed ones are marked or the costs kept.
+    fn segment(&self, text: &'o str) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+        if self.options.mark_guessed || self.options.segment_costs {
+            self.segmenter.segment_str_with_origin(text)
+        } else {
+            Box::new(
+                self.segmenter.segment_str(text).map(|segment| (segment, SegmentOrigin::default())),
+            )
+        }
+    }
+}
+
+/// Splits the provided text in the parts to segment separately, along with their [`Script`],
+/// the whole text being segmented at once when [`SegmenterOption::whitespace_only`] is set.
+fn split_text<'o>(
+    text: &'o str,
+    options: &SegmenterOption,
+) -> Box<dyn Iterator<Item = (&'o str, Script)> + 'o> {
+    if options.whitespace_only {
+        Box::new(Some((text, Script::Other)).into_iter().filter(|(text, _)| !text.is_empty()))
+    } else {
+        Box::new(ScriptGroups(text))
+    }
+}
+
+/// Iterator splitting a text in groups of characters of the same [`Script`], along with this script.
+///
+/// The characters without script, like the digits or the punctuation, stay in the current group,
+/// a group only made of them being of [`Script::Other`].
+/// The script of each character is computed once, and the script of the group is reused by the detection.
+struct ScriptGroups<'o>(&'o str);
+
+impl<'o> Iterator for ScriptGroups<'o> {
+    type Item = (&'o str, Script);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        let mut script = Script::Other;
+        let mut end = self.0.len();
+        for (i, c) in self.0.char_indices() {
+            let char_script = Script::from(c);
+            if char_script != Script::Other && char_script != script {
+                if script != Script::Other {
+                    // both scripts are different than Script::Other, split into a new script group.
+                    end = i;
+                    break;
+                }
+                script = char_script;
+            }
         }
+
+        let (group, rest) = self.0.split_at(end);
+        self.0 = rest;
+        Some((group, script))
     }
 }
 
@@ -161,24 +441,78 @@ impl<'o, 'tb> Iterator for SegmentedStrIter<'o, 'tb> {
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.current.next() {
-            Some(s) => Some(s),
+            Some((s, origin)) => {
+                self.origin = origin;
+                Some(s)
+            }
             None => match self.aho_iter.as_mut().and_then(|aho_iter| aho_iter.next()) {
-                Some((s, MatchType::Match)) => Some(s),
+                Some((s, MatchType::Match)) => {
+                    self.origin = SegmentOrigin::default();
+                    Some(s)
+                }
                 Some((s, MatchType::Interleave)) => {
-                    self.current = self.segmenter.segment_str(s);
+                    self.current = self.segment(s);
 
                     self.next()
                 }
                 None => {
-                    let text = self.inner.next()?;
+                    let (text, script) = match self.inner.next() {
+                        Some(group) => group,
+                        None => match self.protected_iter.as_mut()?.next()? {
+                            // a protected term is never splitted.
+                            (term, MatchType::Match) => {
+                                self.script = term.detect(None).script();
+                                self.language = None;
+                                self.aho_iter = None;
+                                self.protected = true;
+                                self.origin = SegmentOrigin::default();
+                                return Some(term);
+                            }
+                            (text, MatchType::Interleave) => {
+                                self.inner = split_text(text, self.options);
+                                return self.next();
+                            }
+                        },
+                    };
+                    self.protected = false;
+                    if self.options.whitespace_only {
+                        self.segmenter = &WhitespaceSegmenter;
+                        self.script = Script::Other;
+                        self.language = None;
+                        self.aho_iter = None;
+                        self.current = self.segment(text);
+                        return self.next();
+                    }
+
                     let mut detector = text.detect(self.options.allow_list);
-                    self.segmenter = segmenter(&mut detector);
+                    // the script of the group is already known, so the text isn't scanned again to detect it.
+                    detector.script = Some(script);
+                    // skip the language detection if a default language is set for the script.
+                    if let Some(language) = self.options.default_languages.get(&detector.script()) {
+                        detector.language = Some(*language);
+                    } else if let Some(language) = single_allowed_language(script, self.options) {
+                        // the language detection can't pick another language than the only one allowed.
+                        detector.language = Some(language);
+                    } else if detector.script() == Script::Cj && is_japanese(text, self.options) {
+                        detector.language = Some(Language::Jpn);
+                    }
+                    self.segmenter = segmenter(
+                        &mut detector,
+                        &self.options.segmenters,
+                        self.options.detection_cache.as_deref(),
+                    );
                     self.script = detector.script();
                     self.language = detector.language;
-                    self.aho_iter = Some(AhoSegmentedStrIter::new(
-                        text,
-                        self.options.aho.as_ref().unwrap_or(&DEFAULT_SEPARATOR_AHO),
-                    ));
+                    if self.segmenter.splits_separators() {
+                        self.aho_iter = None;
+                        self.current = self.segment(text);
+                    } else {
+                        let (aho, prefilter) = match self.options.aho.as_ref() {
+                            Some(aho) => (aho, self.options.separator_prefilter.as_ref()),
+                            None => (&*DEFAULT_SEPARATOR_AHO, Some(&*DEFAULT_SEPARATOR_PREFILTER)),
+                        };
+                        self.aho_iter = Some(AhoSegmentedStrIter::new(text, aho, prefilter));
+                    }
 
                     self.next()
                 }
@@ -187,15 +521,48 @@ impl<'o, 'tb> Iterator for SegmentedStrIter<'o, 'tb> {
     }
 }
 
+/// Returns the language of the provided `Script` if the allow list only allows one, skipping the language detection.
+fn single_allowed_language(script: Script, options: &SegmenterOption) -> Option<Language> {
+    match options.allow_list?.get(&script)?.as_slice() {
+        [language] => Some(*language),
+        _ => None,
+    }
+}
+
+/// Returns true if the Cj text contains kana, which are only used in Japanese,
+/// skipping the language detection that often mistakes the short Japanese texts for Chinese.
+fn is_japanese(text: &str, options: &SegmenterOption) -> bool {
+    let allowed = options
+        .allow_list
+        .and_then(|allow_list| allow_list.get(&Script::Cj))
+        .is_none_or(|languages| languages.contains(&Language::Jpn));
+    allowed && text.chars().any(|c| CjScript::from_char(c).is_some_and(|s| s.is_kana()))
+}
+
 struct AhoSegmentedStrIter<'o, 'aho> {
-    aho_iter: FindIter<'aho, 'o>,
+    aho: &'aho AhoCorasick,
+    prefilter: Option<&'aho SeparatorPrefilter>,
     prev: Either<usize, aho_corasick::Match>,
     text: &'o str,
 }
 
 impl<'o, 'aho> AhoSegmentedStrIter<'o, 'aho> {
-    fn new(text: &'o str, aho: &'aho AhoCorasick) -> Self {
-        Self { aho_iter: aho.find_iter(text), prev: Either::Left(0), text }
+    fn new(
+        text: &'o str,
+        aho: &'aho AhoCorasick,
+        prefilter: Option<&'aho SeparatorPrefilter>,
+    ) -> Self {
+        Self { aho, prefilter, prev: Either::Left(0), text }
+    }
+
+    /// Finds the first match starting from the provided index,
+    /// only running the automaton from the first byte that can start a match.
+    fn find_match(&self, start: usize) -> Option<aho_corasick::Match> {
+        let start = match self.prefilter {
+            Some(prefilter) => start + prefilter.find(&self.text.as_bytes()[start..])?,
+            None => start,
+        };
+        self.aho.find(Input::new(self.text).span(start..self.text.len()))
     }
 }
 
@@ -205,7 +572,13 @@ impl<'o, 'aho> Iterator for AhoSegmentedStrIter<'o, 'aho> {
     fn next(&mut self) -> Option<Self::Item> {
         let mut match_type = MatchType::Interleave;
         let (start, end) = match self.prev {
-            Either::Left(left) => match self.aho_iter.next() {
+            Either::Left(left) => match self.find_match(left) {
+                Some(m) if m.is_empty() => {
+                    // an empty pattern matches everywhere, skip the next char to not match it again.
+                    let next = self.text[left..].chars().next().map_or(0, char::len_utf8);
+                    self.prev = Either::Left(left + next);
+                    (left, left + next)
+                }
                 Some(m) => {
                     let range = (left, m.start());
                     self.prev = Either::Right(m);
@@ -238,54 +611,410 @@ enum MatchType {
     Match,
 }
 
+/// Iterator splitting a text on the protected terms and patterns, see [`SegmenterOption::protected_aho`],
+/// only keeping the matches bounded by the edges of the text or by non-alphanumeric characters, see [`is_bounded`].
+struct ProtectedStrIter<'o, 'tb> {
+    aho: Option<&'tb AhoCorasick>,
+    #[cfg(feature = "protected-patterns")]
+    patterns: Option<&'tb Regex>,
+    text: &'o str,
+    /// start of the rest of the text to iterate over.
+    offset: usize,
+    /// match following the interleaved text returned last.
+    next_match: Option<(usize, usize)>,
+}
+
+impl<'o, 'tb> ProtectedStrIter<'o, 'tb> {
+    /// Returns `None` if there is neither protected term nor protected pattern.
+    fn new(text: &'o str, options: &'tb SegmenterOption<'tb>) -> Option<Self> {
+        let aho = options.protected_aho.as_ref();
+        #[cfg(feature = "protected-patterns")]
+        let patterns = options.protected_patterns.as_ref();
+        #[cfg(feature = "protected-patterns")]
+        let enabled = aho.is_some() || patterns.is_some();
+        #[cfg(not(feature = "protected-patterns"))]
+        let enabled = aho.is_some();
+
+        enabled.then_some(Self {
+            aho,
+            #[cfg(feature = "protected-patterns")]
+            patterns,
+            text,
+            offset: 0,
+            next_match: None,
+        })
+    }
+
+    /// Finds the first bounded match starting from the provided index, the longest one of a term or a pattern
+    /// if both start at the same index.
+    fn find_match(&self, start: usize) -> Option<(usize, usize)> {
+        let term = self.aho.and_then(|aho| {
+            self.first_bounded(start, |start| {
+                aho.find(Input::new(self.text).span(start..self.text.len()))
+                    .map(|m| (m.start(), m.end()))
+            })
+        });
+        #[cfg(feature = "protected-patterns")]
+        if let Some(regex) = self.patterns {
+            let pattern = self.first_bounded(start, |start| {
+                regex.find_at(self.text, start).map(|m| (m.start(), m.end()))
+            });
+            return match (term, pattern) {
+                (Some(term), Some(pattern)) => {
+                    Some(std::cmp::min_by_key(term, pattern, |m| (m.0, std::cmp::Reverse(m.1))))
+                }
+                (term, pattern) => term.or(pattern),
+            };
+        }
+        term
+    }
+
+    /// Returns the first non-empty match returned by `find` that is bounded,
+    /// searching again from the next char when a match is rejected.
+    fn first_bounded(
+        &self,
+        mut start: usize,
+        find: impl Fn(usize) -> Option<(usize, usize)>,
+    ) -> Option<(usize, usize)> {
+        while start < self.text.len() {
+            let (s, e) = find(start)?;
+            if s < e && is_bounded(self.text, s, e) {
+                return Some((s, e));
+            }
+            start = s + self.text[s..].chars().next().map_or(1, char::len_utf8);
+        }
+        None
+    }
+}
+
+impl<'o> Iterator for ProtectedStrIter<'o, '_> {
+    type Item = (&'o str, MatchType);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = match self.next_match.take() {
+            Some(next_match) => next_match,
+            None if self.offset < self.text.len() => match self.find_match(self.offset) {
+                Some((start, end)) if start > self.offset => {
+                    self.next_match = Some((start, end));
+                    let interleave = &self.text[self.offset..start];
+                    self.offset = start;
+                    return Some((interleave, MatchType::Interleave));
+                }
+                Some(found) => found,
+                None => {
+                    let interleave = &self.text[self.offset..];
+                    self.offset = self.text.len();
+                    return Some((interleave, MatchType::Interleave));
+                }
+            },
+            None => return None,
+        };
+
+        self.offset = end;
+        Some((&self.text[start..end], MatchType::Match))
+    }
+}
+
+/// Returns true if the match of a protected term is not surrounded by alphanumeric characters,
+/// so the term "in" is not found in "main".
+///
+/// An edge of the match that is not alphanumeric is always bounded, like the end of "C++" in "C++x".
+fn is_bounded(text: &str, start: usize, end: usize) -> bool {
+    let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    let matched = &text[start..end];
+    let bounded_start =
+        !is_word(text[..start].chars().next_back()) || !is_word(matched.chars().next());
+    let bounded_end = !is_word(matched.chars().next_back()) || !is_word(text[end..].chars().next());
+    bounded_start && bounded_end
+}
+
 /// Try to Detect Language and Script and return the corresponding segmenter,
 /// if no Language is detected or no segmenter corresponds to the Language
 /// the function try to get a segmenter corresponding to the script;
 /// if no Script is detected or no segmenter corresponds to the Script,
 /// the function try to get the default segmenter in the map;
 /// if no default segmenter exists in the map return the library DEFAULT_SEGMENTER.
-fn segmenter<'b>(detector: &mut StrDetection) -> &'b dyn Segmenter {
+fn segmenter<'b>(
+    detector: &mut StrDetection,
+    segmenters: &'b SegmenterMap,
+    cache: Option<&DetectionCache>,
+) -> &'b dyn Segmenter {
     let detected_script = detector.script();
-    let mut filtered_segmenters =
-        SEGMENTERS.iter().filter(|((script, _), _)| *script == detected_script);
-    match (filtered_segmenters.next(), filtered_segmenters.next()) {
+    match single_script_segmenter(detected_script, segmenters) {
+        Some(segmenter) => segmenter,
+        // several segmenters found,
+        // we have to detect the language to get the good one.
+        None => {
+            let detected_language = match cache {
+                Some(cache) => cache.language(detector),
+                None => detector.language(),
+            };
+            language_segmenter(detected_script, detected_language, segmenters)
+        }
+    }
+}
+
+/// Returns the segmenter of the provided `Script` if choosing it doesn't depend on the `Language`.
+///
+/// The provided `segmenters` take precedence over the ones of [`SEGMENTERS`].
+fn single_script_segmenter(script: Script, segmenters: &SegmenterMap) -> Option<&dyn Segmenter> {
+    let mut keys = segmenters.keys().chain(registered_keys()).filter(|(s, _)| *s == script);
+    match keys.next() {
         // no specialized segmenter found for this script,
         // choose the default one.
-        (None, None) => &*DEFAULT_SEGMENTER,
+        None => Some(&**DEFAULT_SEGMENTER),
         // Only one specialized segmenter found,
         // we don't need to detect the Language.
-        (Some((_, segmenter)), None) => segmenter,
-        // several segmenters found,
-        // we have to detect the language to get the good one.
-        _ => {
-            let detected_language = detector.language();
-            SEGMENTERS
-                .get(&(detected_script, detected_language))
-                .or_else(|| SEGMENTERS.get(&(detected_script, Language::Other)))
-                .unwrap_or(&DEFAULT_SEGMENTER)
+        Some(&(script, language)) if keys.all(|key| *key == (script, language)) => {
+            Some(language_segmenter(script, language, segmenters))
         }
+        _ => None,
     }
 }
 
+fn language_segmenter(
+    script: Script,
+    language: Language,
+    segmenters: &SegmenterMap,
+) -> &dyn Segmenter {
+    let get = |language| match segmenters.get(&(script, language)) {
+        Some(segmenter) => Some(&**segmenter),
+        None => registered_segmenter(script, language),
+    };
+
+    get(language).or_else(|| get(Language::Other)).unwrap_or(&**DEFAULT_SEGMENTER)
+}
+
+/// Returns the [`Segmenter`] used to segment a text of the provided [`Script`] and [`Language`].
+///
+/// The segmenter is chosen following the same rules as the tokenization pipeline,
+/// see [`SEGMENTERS`] for more details.
+///
+/// # Example
+///
+/// ```
+/// use charabia::{segmenter_for, Language, Script};
+///
+/// let segmenter = segmenter_for(Script::Latin, Language::Eng);
+/// assert_eq!(segmenter.name(), "LatinSegmenter");
+/// ```
+pub fn segmenter_for(script: Script, language: Language) -> &'static dyn Segmenter {
+    static NO_SEGMENTERS: Lazy<SegmenterMap> = Lazy::new(HashMap::new);
+    single_script_segmenter(script, &NO_SEGMENTERS)
+        .unwrap_or_else(|| language_segmenter(script, language, &NO_SEGMENTERS))
+}
+
+/// Returns the list of [`Script`] and [`Language`] pairs having a specialized [`Segmenter`]
+/// in the current build, sorted by `Script` and `Language` names.
+///
+/// A pair with `Language::Other` means that the `Segmenter` is specialized for any `Language` using the `Script`.
+pub fn supported_languages() -> Vec<(Script, Language)> {
+    let mut supported: Vec<_> = registered_keys().copied().collect();
+    supported.sort_by_key(|(script, language)| (script.name(), language.name()));
+    supported.dedup();
+    supported
+}
+
+/// Returns the specialized [`Segmenter`]s used with the provided `segmenters` taking precedence over [`SEGMENTERS`],
+/// sorted by `Script` and `Language` names.
+pub(crate) fn effective_segmenters(
+    segmenters: &SegmenterMap,
+) -> Vec<((Script, Language), &dyn Segmenter)> {
+    let mut effective: Vec<_> = registered_keys()
+        .chain(segmenters.keys())
+        .map(|&(script, language)| {
+            ((script, language), language_segmenter(script, language, segmenters))
+        })
+        .collect();
+    effective.sort_by_key(|((script, language), _)| (script.name(), language.name()));
+    effective.dedup_by_key(|(key, _)| *key);
+    effective
+}
+
 /// Structure for providing options to a normalizer.
 #[derive(Debug, Clone, Default)]
 pub struct SegmenterOption<'tb> {
     pub aho: Option<AhoCorasick>,
     pub allow_list: Option<&'tb HashMap<Script, Vec<Language>>>,
+    /// Language assigned to any text of a `Script`, skipping the language detection.
+    pub default_languages: HashMap<Script, Language>,
+    /// Prefilter of the patterns of `aho`, see [`SeparatorPrefilter`],
+    /// the automaton being run on the whole text if `None`.
+    pub separator_prefilter: Option<SeparatorPrefilter>,
+    /// Automaton matching the terms that must never be splitted nor normalized,
+    /// only when they are bounded by non-alphanumeric characters or by the edges of the text.
+    pub protected_aho: Option<AhoCorasick>,
+    /// Patterns matching the terms that must never be splitted nor normalized, like the ticket IDs,
+    /// bounded like the ones of `protected_aho`.
+    #[cfg(feature = "protected-patterns")]
+    pub protected_patterns: Option<Regex>,
+    /// Segmenters used instead of the ones of [`SEGMENTERS`] for the same `Script` and `Language`.
+    pub segmenters: SegmenterMap,
+    /// Maximum number of bytes of the text to segment, the rest of the text is ignored.
+    pub max_input_bytes: Option<usize>,
+    /// Maximum number of tokens to create, the rest of the text is ignored.
+    pub max_tokens: Option<usize>,
+    /// Split the whole text on whitespaces with the [`WhitespaceSegmenter`],
+    /// skipping the script and language detection and the separators.
+    pub whitespace_only: bool,
+    /// Split the segmented tokens at the script transitions inside them, like "abc漢字",
+    /// which happens when a text of several scripts is segmented as a whole.
+    pub split_mixed_scripts: bool,
+    /// Cache of the languages detected in the short texts, shared between the tokenizations.
+    pub detection_cache: Option<Arc<DetectionCache>>,
+    /// Receiver of the statistics of each normalized text, see the [`metrics`](crate::metrics) module.
+    pub observer: Option<Arc<dyn TokenizerObserver>>,
+    /// Mark the tokens that weren't found in the dictionary of their segmenter, see [`Token::guessed`].
+    pub mark_guessed: bool,
+    /// Keep the cost of the tokens computed by their segmenter, see [`Token::cost`].
+    pub segment_costs: bool,
+}
+
+impl SegmenterOption<'_> {
+    /// Searches the separators of the provided set, sharing its compiled automaton.
+    pub fn separators(&mut self, separators: &SeparatorSet) -> &mut Self {
+        self.aho = Some(separators.automaton().clone());
+        self.separator_prefilter = separators.prefilter().cloned();
+        self
+    }
+
+    /// Keeps the Urdu words starting with `ال` whole, using the [`ArabicKeepArticleSegmenter`] for Urdu,
+    /// when Urdu is selected for the Arabic script by the allow list or the default language.
+    ///
+    /// The Urdu segmenter is not registered in [`SEGMENTERS`], so the Arabic script keeps a single segmenter
+    /// and its texts are not detected unless Urdu is selected.
+    pub(crate) fn select_urdu_segmenter(&mut self) {
+        let script = Script::Arabic;
+        let selected = match self.default_languages.get(&script) {
+            Some(language) => *language == Language::Urd,
+            None => self
+                .allow_list
+                .and_then(|allow_list| allow_list.get(&script))
+                .is_some_and(|languages| languages.contains(&Language::Urd)),
+        };
+        if !selected || self.segmenters.contains_key(&(script, Language::Urd)) {
+            return;
+        }
+
+        // the other languages, and the undetected texts, keep the segmenter of Arabic.
+        let arabic = match self.segmenters.get(&(script, Language::Ara)) {
+            Some(segmenter) => segmenter.clone(),
+            None => Arc::new(ArabicSegmenter),
+        };
+        self.segmenters.entry((script, Language::Other)).or_insert(arabic);
+        self.segmenters.insert((script, Language::Urd), Arc::new(ArabicKeepArticleSegmenter));
+    }
 }
 
+/// Map of [`Segmenter`]s shared between tokenizers, linked to their corresponding [`Script`] and [`Language`].
+pub type SegmenterMap = HashMap<(Script, Language), Arc<dyn Segmenter>>;
+
+/// Ordered list of [`Segmenter`]s linked to their corresponding [`Script`] and [`Language`], see [`SEGMENTERS`].
+pub type SegmenterList = Vec<((Script, Language), Box<dyn Segmenter>)>;
+
 /// Trait defining a segmenter.
 ///
 /// A segmenter should be at least a script specialized segmenter.
 pub trait Segmenter: Sync + Send {
     /// Segments the provided text creating an Iterator over `&str`.
     fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o>;
+
+    /// Segments the provided text like [`Segmenter::segment_str`], along with the [`SegmentOrigin`] of each segment.
+    ///
+    /// By default, the segments are returned with an empty origin, the segmenter not using any dictionary.
+    fn segment_str_with_origin<'o>(
+        &self,
+        s: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+        Box::new(self.segment_str(s).map(|segment| (segment, SegmentOrigin::default())))
+    }
+
+    /// Returns up to `n` segmentations of the provided text, the first one being the one of [`Segmenter::segment_str`],
+    /// followed by the alternatives from the most to the least likely,
+    /// so an ambiguous text can be indexed in several ways or segmented by another language model.
+    ///
+    /// By default, no alternative is returned, the Chinese, Thai, Khmer and Sinhala segmenters returning
+    /// the other segmentations into the words of their dictionary.
+    fn segment_str_alternatives<'o>(&self, s: &'o str, n: usize) -> Vec<Vec<&'o str>> {
+        match n {
+            0 => Vec::new(),
+            _ => vec![self.segment_str(s).collect()],
+        }
+    }
+
+    /// Returns the name of the segmenter, by default the name of the implementing type.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>().rsplit("::").next().unwrap_or_default()
+    }
+
+    /// Returns the dictionaries used by the segmenter, by default none.
+    fn dictionaries(&self) -> Vec<DictionaryInfo> {
+        Vec::new()
+    }
+
+    /// Returns true if the segmenter splits the separators itself,
+    /// the text is then segmented as a whole instead of being split on the separators first.
+    fn splits_separators(&self) -> bool {
+        false
+    }
+}
+
+/// How a segment was found by a [`Segmenter`], see [`Segmenter::segment_str_with_origin`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SegmentOrigin {
+    /// Whether the segment was found in the dictionary of the segmenter, `Some(false)` meaning that it was guessed,
+    /// like an unknown word of lindera or a char left alone by a dictionary encoded as an FST.
+    pub in_dictionary: Option<bool>,
+    /// Cost of the segment in the lattice of the segmenter, the lower the more likely, if the segmenter computes it.
+    pub cost: Option<i32>,
+}
+
+/// Provenance of a dictionary used by a [`Segmenter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DictionaryInfo {
+    /// Name of the dictionary, e.g. `"ko-dic"`.
+    pub name: &'static str,
+    /// Version of the dictionary, if known.
+    pub version: Option<&'static str>,
+    /// Origin of the dictionary, `"custom"` for a dictionary provided by the user.
+    pub source: &'static str,
+    /// Stable checksum of the content of the dictionary, if the content is accessible.
+    pub checksum: Option<u64>,
+}
+
+impl fmt::Debug for dyn Segmenter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
 }
 
 impl Segmenter for Box<dyn Segmenter> {
     fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
         (**self).segment_str(s)
     }
+
+    fn segment_str_with_origin<'o>(
+        &self,
+        s: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+        (**self).segment_str_with_origin(s)
+    }
+
+    fn segment_str_alternatives<'o>(&self, s: &'o str, n: usize) -> Vec<Vec<&'o str>> {
+        (**self).segment_str_alternatives(s, n)
+    }
+
+    fn name(&self) -> &'static str {
+        (**self).name()
+    }
+
+    fn dictionaries(&self) -> Vec<DictionaryInfo> {
+        (**self).dictionaries()
+    }
+
+    fn splits_separators(&self) -> bool {
+        (**self).splits_separators()
+    }
 }
 
 /// Trait defining methods to segment a text.
@@ -347,7 +1076,7 @@ pub trait Segment<'o> {
     /// assert_eq!(segments.next(), Some("quick"));
     /// ```
     fn segment_str(&self) -> SegmentedStrIter<'o, 'o> {
-        self.segment_str_with_option(&SegmenterOption { aho: None, allow_list: None })
+        self.segment_str_with_option(&DEFAULT_SEGMENTER_OPTION)
     }
 
     /// Segments the provided text creating an Iterator over `&str` where you can specify an allowed list of languages to be used with a script.
@@ -369,17 +1098,231 @@ impl<'o> Segment<'o> for &'o str {
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashMap;
+
+    use super::{
+        segmenter_for, supported_languages, AhoOption, AhoSegmentedStrIter, ScriptGroups,
+        SegmentedStrIter, SegmenterOption, SeparatorPrefilter, DEFAULT_SEPARATOR_AHO,
+        DEFAULT_SEPARATOR_PREFILTER,
+    };
+    use crate::{Language, Script};
+
+    fn aho_segments(text: &str, prefilter: Option<&SeparatorPrefilter>) -> Vec<String> {
+        AhoSegmentedStrIter::new(text, &DEFAULT_SEPARATOR_AHO, prefilter)
+            .map(|(s, _)| s.to_string())
+            .collect()
+    }
+
+    #[quickcheck]
+    fn prefilter_keeps_segments(text: String) -> bool {
+        aho_segments(&text, Some(&DEFAULT_SEPARATOR_PREFILTER)) == aho_segments(&text, None)
+    }
+
+    #[test]
+    fn prefilter() {
+        let text = format!("{} (the end)...", "a".repeat(1000));
+        assert_eq!(
+            aho_segments(&text, Some(&DEFAULT_SEPARATOR_PREFILTER)),
+            aho_segments(&text, None)
+        );
+
+        // few separators are searched with memchr.
+        let separators = [" ", "...", "€"];
+        let prefilter = SeparatorPrefilter::new(separators).unwrap();
+        assert_eq!(prefilter.find(b"abc... d"), Some(3));
+        assert_eq!(prefilter.find("prix: 3\u{20AC}".as_bytes()), Some(5));
+        assert_eq!(prefilter.find(b"abcd"), None);
+
+        let aho = AhoOption::default().build(separators);
+        let segments: Vec<_> =
+            AhoSegmentedStrIter::new("a b...3€", &aho, Some(&prefilter)).map(|(s, _)| s).collect();
+        assert_eq!(segments, ["a", " ", "b", "...", "3", "€"]);
+
+        // an empty pattern can be found anywhere.
+        assert!(SeparatorPrefilter::new(["", " "]).is_none());
+    }
+
+    #[test]
+    fn script_groups() {
+        let groups: Vec<_> = ScriptGroups("1. Hello 世界, привет! 42").collect();
+        assert_eq!(
+            groups,
+            [
+                ("1. Hello ", Script::Latin),
+                ("世界, ", Script::Cj),
+                ("привет! 42", Script::Cyrillic),
+            ]
+        );
+        assert_eq!(ScriptGroups("42 !").collect::<Vec<_>>(), [("42 !", Script::Other)]);
+        assert_eq!(ScriptGroups("").next(), None);
+    }
+
+    #[test]
+    fn single_allowed_language() {
+        let allow_list = HashMap::from([
+            (Script::Cj, vec![Language::Jpn]),
+            (Script::Latin, vec![Language::Fra]),
+        ]);
+        let options = SegmenterOption { allow_list: Some(&allow_list), ..Default::default() };
+
+        for (text, language) in [("漢字", Language::Jpn), ("The cat", Language::Fra)] {
+            let mut iter = SegmentedStrIter::new(text, &options);
+            iter.next();
+            assert_eq!(iter.language, Some(language));
+        }
+    }
+
+    #[test]
+    fn kana_text_is_japanese() {
+        let options = SegmenterOption::default();
+        let mut iter = SegmentedStrIter::new("すしを食べる", &options);
+        iter.next();
+        assert_eq!((iter.script, iter.language), (Script::Cj, Some(Language::Jpn)));
+
+        // the allow list still applies.
+        let allow_list = HashMap::from([(Script::Cj, vec![Language::Cmn])]);
+        let options = SegmenterOption { allow_list: Some(&allow_list), ..Default::default() };
+        let mut iter = SegmentedStrIter::new("すしを食べる", &options);
+        iter.next();
+        assert_ne!(iter.language, Some(Language::Jpn));
+    }
+
+    #[test]
+    fn segmenter_for_script_and_language() {
+        assert_eq!(segmenter_for(Script::Latin, Language::Eng).name(), "LatinSegmenter");
+        assert_eq!(segmenter_for(Script::Latin, Language::Other).name(), "LatinSegmenter");
+        assert_eq!(segmenter_for(Script::Arabic, Language::Other).name(), "ArabicSegmenter");
+        // scripts without specialized segmenter fallback on the default one.
+        assert_eq!(segmenter_for(Script::Cyrillic, Language::Rus).name(), "LatinSegmenter");
+        #[cfg(feature = "thai")]
+        assert_eq!(segmenter_for(Script::Thai, Language::Other).name(), "ThaiSegmenter");
+        #[cfg(all(feature = "chinese", feature = "japanese"))]
+        {
+            assert_eq!(segmenter_for(Script::Cj, Language::Cmn).name(), "ChineseSegmenter");
+            assert_eq!(segmenter_for(Script::Cj, Language::Jpn).name(), "JapaneseSegmenter");
+        }
+    }
+
+    #[test]
+    fn segmenter_precedence() {
+        use std::sync::Arc;
+
+        use super::{segmenter, MongolianSegmenter, SegmenterMap, WhitespaceSegmenter};
+        use crate::detection::Detect;
+
+        // the language segmenter, then the script segmenter, then the default one.
+        assert_eq!(segmenter_for(Script::Arabic, Language::Pes).name(), "ArabicSegmenter");
+        // the Urdu segmenter is only used when Urdu is selected, keeping a single Arabic segmenter.
+        assert_eq!(segmenter_for(Script::Arabic, Language::Urd).name(), "ArabicSegmenter");
+        assert_eq!(segmenter_for(Script::Mongolian, Language::Eng).name(), "MongolianSegmenter");
+        assert_eq!(segmenter_for(Script::Georgian, Language::Kat).name(), "LatinSegmenter");
+
+        // the provided segmenters take precedence, and a single segmenter of a script skips the detection.
+        let mut segmenters = SegmenterMap::new();
+        let mut detector = "السلام".detect(None);
+        assert_eq!(segmenter(&mut detector, &segmenters, None).name(), "ArabicSegmenter");
+        assert_eq!(detector.language, None);
+
+        segmenters.insert((Script::Mongolian, Language::Other), Arc::new(WhitespaceSegmenter));
+        let mut detector = "ᠮᠣᠩᠭᠣᠯ".detect(None);
+        assert_eq!(segmenter(&mut detector, &segmenters, None).name(), "WhitespaceSegmenter");
+        assert_eq!(detector.language, None);
+
+        segmenters.insert((Script::Mongolian, Language::Eng), Arc::new(MongolianSegmenter));
+        let mut detector = "ᠮᠣᠩᠭᠣᠯ".detect(None);
+        // the language is detected to choose between both, falling back on the script segmenter.
+        let name = segmenter(&mut detector, &segmenters, None).name();
+        assert_eq!(detector.language, Some(Language::Other));
+        assert_eq!(name, "WhitespaceSegmenter");
+    }
+
+    #[test]
+    fn language_routing() {
+        use std::sync::Arc;
+
+        use super::{
+            language_segmenter, single_script_segmenter, Segmenter, SegmenterMap,
+            WhitespaceSegmenter,
+        };
+        use crate::TokenizerBuilder;
+
+        /// Splits the German compounds of a tiny dictionary.
+        struct CompoundSegmenter;
+
+        impl Segmenter for CompoundSegmenter {
+            fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+                match s.strip_prefix("Haus") {
+                    Some(rest) if !rest.is_empty() => Box::new([&s[..4], rest].into_iter()),
+                    _ => Box::new(Some(s).into_iter()),
+                }
+            }
+        }
+
+        // several segmenters of the Latin script are picked by language, falling back on Language::Other.
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenter(Script::Latin, Language::Deu, Arc::new(CompoundSegmenter));
+        let tokenizer = builder.build();
+        // the segmented words don't depend on the normalizers, like the stemming.
+        let words = |text| -> Vec<&str> {
+            tokenizer.segment_str(text).filter(|s| !s.trim().is_empty()).collect()
+        };
+        assert_eq!(
+            words("Die Haustür ist offen und der Hund schläft im Garten"),
+            ["Die", "Haus", "tür", "ist", "offen", "und", "der", "Hund", "schläft", "im", "Garten"]
+        );
+        assert_eq!(
+            words("La Haustür est ouverte et le chien dort dans le jardin"),
+            [
+                "La", "Haustür", "est", "ouverte", "et", "le", "chien", "dort", "dans", "le",
+                "jardin"
+            ]
+        );
+
+        let tokens: Vec<_> = tokenizer.tokenize("Der Hund schläft im Garten").collect();
+        assert!(tokens.iter().all(|t| t.language == Some(Language::Deu)));
+
+        // the language segmenter, then the script segmenter, then the default one.
+        let mut segmenters = SegmenterMap::new();
+        segmenters.insert((Script::Latin, Language::Deu), Arc::new(CompoundSegmenter));
+        segmenters.insert((Script::Cyrillic, Language::Rus), Arc::new(WhitespaceSegmenter));
+        segmenters.insert((Script::Cyrillic, Language::Ukr), Arc::new(CompoundSegmenter));
+        let name = |script, language| language_segmenter(script, language, &segmenters).name();
+        assert_eq!(name(Script::Latin, Language::Deu), "CompoundSegmenter");
+        assert_eq!(name(Script::Latin, Language::Fra), "LatinSegmenter");
+        assert_eq!(name(Script::Latin, Language::Other), "LatinSegmenter");
+        assert_eq!(name(Script::Cyrillic, Language::Rus), "WhitespaceSegmenter");
+        assert_eq!(name(Script::Cyrillic, Language::Bul), "LatinSegmenter");
+        // the language is detected as soon as two segmenters are assigned to the script.
+        assert!(single_script_segmenter(Script::Latin, &segmenters).is_none());
+        assert!(single_script_segmenter(Script::Cyrillic, &segmenters).is_none());
+        assert_eq!(
+            single_script_segmenter(Script::Latin, &SegmenterMap::new()).unwrap().name(),
+            "LatinSegmenter"
+        );
+    }
+
+    #[test]
+    fn supported_languages_contains_segmenters() {
+        let supported = supported_languages();
+        assert!(supported.contains(&(Script::Latin, Language::Other)));
+        assert!(supported.contains(&(Script::Arabic, Language::Ara)));
+        #[cfg(feature = "chinese")]
+        assert!(supported.contains(&(Script::Cj, Language::Cmn)));
+        #[cfg(not(feature = "korean"))]
+        assert!(!supported.contains(&(Script::Hangul, Language::Kor)));
+    }
+
     macro_rules! test_segmenter {
     ($segmenter:expr, $text:expr, $segmented:expr, $tokenized:expr, $script:expr, $language:expr) => {
             use crate::{Token, Language, Script};
-            use crate::segmenter::{Segment, AhoSegmentedStrIter, MatchType, DEFAULT_SEPARATOR_AHO};
+            use crate::segmenter::{Segment, AhoSegmentedStrIter, MatchType, DEFAULT_SEPARATOR_AHO, DEFAULT_SEPARATOR_PREFILTER};
             use crate::tokenizer::Tokenize;
             use super::*;
 
             #[test]
             fn segmenter_segment_str() {
 
-                let segmented_text: Vec<_> = AhoSegmentedStrIter::new($text, &DEFAULT_SEPARATOR_AHO).flat_map(|m| match m {
+                let segmented_text: Vec<_> = AhoSegmentedStrIter::new($text, &DEFAULT_SEPARATOR_AHO, Some(&DEFAULT_SEPARATOR_PREFILTER)).flat_map(|m| match m {
                     (text, MatchType::Match) => Box::new(Some(text).into_iter()),
                     (text, MatchType::Interleave) => $segmenter.segment_str(text),
                 }).collect();
@@ -0,0 +1,61 @@
+use super::Segmenter;
+
+/// Narrow no-break space, joining the suffixes to the word stem in the traditional Mongolian script.
+const NNBSP: char = '\u{202F}';
+
+/// Mongolian specialized [`Segmenter`].
+///
+/// In the traditional Mongolian script, the case suffixes are written apart from the word stem
+/// and joined to it by a narrow no-break space (U+202F), like in `ᠮᠣᠩᠭᠣᠯ\u{202F}ᠤᠨ` (of Mongolia).
+/// This Segmenter splits the stem, the narrow no-break space and the suffix into separate tokens,
+/// so searching for `ᠮᠣᠩᠭᠣᠯ` (Mongolia) finds the inflected forms.
+pub struct MongolianSegmenter;
+
+impl Segmenter for MongolianSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        let mut start = 0;
+        let mut segments = Vec::new();
+        for (i, _) in to_segment.match_indices(NNBSP) {
+            let end = i + NNBSP.len_utf8();
+            segments.extend([&to_segment[start..i], &to_segment[i..end]]);
+            start = end;
+        }
+        segments.push(&to_segment[start..]);
+
+        Box::new(segments.into_iter().filter(|segment| !segment.is_empty()))
+    }
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use crate::segmenter::test::test_segmenter;
+
+    // Original version of the text.
+    const TEXT: &str = "ᠮᠣᠩᠭᠣᠯ\u{202F}ᠤᠨ ᠪᠢᠴᠢᠭ\u{180B}";
+
+    // Segmented version of the text.
+    const SEGMENTED: &[&str] = &["ᠮᠣᠩᠭᠣᠯ", "\u{202F}", "ᠤᠨ", " ", "ᠪᠢᠴᠢᠭ\u{180B}"];
+
+    // Segmented and normalized version of the text.
+    const TOKENIZED: &[&str] = &[
+        "ᠮᠣᠩᠭᠣᠯ",
+        " ",
+        "ᠤᠨ",
+        " ",
+        #[cfg(feature = "mongolian")]
+        "ᠪᠢᠴᠢᠭ",
+        #[cfg(not(feature = "mongolian"))]
+        "ᠪᠢᠴᠢᠭ\u{180B}",
+    ];
+
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(
+        MongolianSegmenter,
+        TEXT,
+        SEGMENTED,
+        TOKENIZED,
+        Script::Mongolian,
+        Language::Other
+    );
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use crate::segmenter::utils::{FstSegmenter, WordsFst};
+use crate::segmenter::{DictionaryInfo, SegmentOrigin, Segmenter};
+
+/// Sinhala specialized [`Segmenter`].
+///
+/// This Segmenter uses a dictionary encoded as an FST to segment the Sinhala texts written without spaces.
+/// No Sinhala dictionary is embedded, so the segmenter is created with [`SinhalaSegmenter::new`]
+/// and assigned to a `Tokenizer` using [`crate::TokenizerBuilder::segmenter`],
+/// the Sinhala texts being split on the separators only otherwise.
+///
+/// The words of the dictionary are matched as they are written in the text,
+/// the zero width joiners of the conjuncts, like in "ශ්‍රී", being removed later by the [`SinhalaNormalizer`](crate::normalizer::SinhalaNormalizer).
+#[derive(Clone)]
+pub struct SinhalaSegmenter {
+    fst_segmenter: FstSegmenter,
+}
+
+impl SinhalaSegmenter {
+    /// Creates a segmenter using the provided dictionary of words.
+    pub fn new(words_fst: Arc<WordsFst>) -> Self {
+        Self { fst_segmenter: FstSegmenter::new(words_fst, DICTIONARY_NAME, "custom") }
+    }
+
+    /// Limits the words matched in the dictionary to `max_word_len` chars, no limit being set by default.
+    ///
+    /// The text not matching any shorter word is segmented char by char.
+    pub fn max_word_len(mut self, max_word_len: Option<usize>) -> Self {
+        self.fst_segmenter.set_max_word_len(max_word_len);
+        self
+    }
+}
+
+const DICTIONARY_NAME: &str = "sinhala-words";
+
+impl Segmenter for SinhalaSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        self.fst_segmenter.segment_str(to_segment)
+    }
+
+    fn segment_str_with_origin<'o>(
+        &self,
+        to_segment: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+        self.fst_segmenter.segment_str_with_origin(to_segment)
+    }
+
+    fn segment_str_alternatives<'o>(&self, to_segment: &'o str, n: usize) -> Vec<Vec<&'o str>> {
+        self.fst_segmenter.segment_str_alternatives(to_segment, n)
+    }
+
+    fn dictionaries(&self) -> Vec<DictionaryInfo> {
+        vec![self.fst_segmenter.dictionary()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    use fst::raw::Fst;
+    use fst::Set;
+
+    use super::SinhalaSegmenter;
+    use crate::{Language, Script, TokenizerBuilder};
+
+    #[test]
+    fn sinhala() {
+        let words = Set::from_iter(["ලංකා", "ශ්\u{200D}රී"]).unwrap().into_fst().into_inner();
+        let segmenter = SinhalaSegmenter::new(Arc::new(Fst::new(Cow::Owned(words)).unwrap()));
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenter(Script::Sinhala, Language::Sin, Arc::new(segmenter));
+        let tokenizer = builder.build();
+
+        let text = "ශ්\u{200D}රීලංකා";
+        let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+        let words: Vec<_> =
+            tokens.iter().map(|t| (t.lemma(), &text[t.byte_start..t.byte_end])).collect();
+        assert_eq!(words, [("ශ්රී", "ශ්\u{200D}රී"), ("ලංකා", "ලංකා")]);
+        assert!(tokens.iter().all(|t| t.script == Script::Sinhala && t.is_word()));
+    }
+}
@@ -1,24 +1,83 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
 // Import `Segmenter` trait.
+use charabia_fst_dictionaries::THAI_WORDS_FST;
 use fst::raw::Fst;
 use once_cell::sync::Lazy;
 
-use crate::segmenter::utils::FstSegmenter;
-use crate::segmenter::Segmenter;
+use crate::segmenter::utils::{FstSegmenter, WordsFst};
+use crate::segmenter::{DictionaryInfo, SegmentOrigin, Segmenter};
 
 /// Thai specialized [`Segmenter`].
 ///
 /// This Segmenter uses a dictionary encoded as an FST to segment the provided text.
 /// Dictionary source: PyThaiNLP project on https://github.com/PyThaiNLP/nlpo3
-pub struct ThaiSegmenter;
+///
+/// By default, the segmenter shares the embedded dictionary,
+/// a segmenter using its own dictionary can be created with [`ThaiSegmenter::new`]
+/// and assigned to a `Tokenizer` using [`crate::TokenizerBuilder::segmenter`].
+#[derive(Clone)]
+pub struct ThaiSegmenter {
+    fst_segmenter: FstSegmenter,
+}
+
+impl ThaiSegmenter {
+    /// Creates a segmenter using the provided dictionary of words.
+    pub fn new(words_fst: Arc<WordsFst>) -> Self {
+        Self { fst_segmenter: FstSegmenter::new(words_fst, DICTIONARY_NAME, "custom") }
+    }
+
+    /// Limits the words matched in the dictionary to `max_word_len` chars, no limit being set by default.
+    ///
+    /// The text not matching any shorter word is segmented char by char.
+    pub fn max_word_len(mut self, max_word_len: Option<usize>) -> Self {
+        self.fst_segmenter.set_max_word_len(max_word_len);
+        self
+    }
 
-static WORDS_FST: Lazy<Fst<&[u8]>> =
-    Lazy::new(|| Fst::new(&include_bytes!("../../dictionaries/fst/thai/words.fst")[..]).unwrap());
+    /// Matches the shortest word of the dictionary starting the text instead of the longest one.
+    ///
+    /// The longest word is matched by default,
+    /// which may produce surprisingly long words for some ambiguous sequences.
+    pub fn prefer_shorter(mut self, prefer_shorter: bool) -> Self {
+        self.fst_segmenter.set_prefer_shorter(prefer_shorter);
+        self
+    }
+}
 
-static FST_SEGMENTER: Lazy<FstSegmenter> = Lazy::new(|| FstSegmenter::new(&WORDS_FST));
+impl Default for ThaiSegmenter {
+    fn default() -> Self {
+        let fst_segmenter =
+            FstSegmenter::new(WORDS_FST.clone(), DICTIONARY_NAME, DICTIONARY_SOURCE);
+        Self { fst_segmenter }
+    }
+}
+
+const DICTIONARY_NAME: &str = "thai-words";
+const DICTIONARY_SOURCE: &str = "https://github.com/PyThaiNLP/nlpo3";
+
+static WORDS_FST: Lazy<Arc<WordsFst>> =
+    Lazy::new(|| Arc::new(Fst::new(Cow::Borrowed(THAI_WORDS_FST)).unwrap()));
 
 impl Segmenter for ThaiSegmenter {
     fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
-        FST_SEGMENTER.segment_str(to_segment)
+        self.fst_segmenter.segment_str(to_segment)
+    }
+
+    fn segment_str_with_origin<'o>(
+        &self,
+        to_segment: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+        self.fst_segmenter.segment_str_with_origin(to_segment)
+    }
+
+    fn segment_str_alternatives<'o>(&self, to_segment: &'o str, n: usize) -> Vec<Vec<&'o str>> {
+        self.fst_segmenter.segment_str_alternatives(to_segment, n)
+    }
+
+    fn dictionaries(&self) -> Vec<DictionaryInfo> {
+        vec![self.fst_segmenter.dictionary()]
     }
 }
 
@@ -68,6 +127,48 @@ mod test {
         "นายา",
         "สระผม",
     ];
+    #[test]
+    fn max_word_len_and_prefer_shorter() {
+        let segmenter = ThaiSegmenter::default().max_word_len(Some(4));
+        let segmented: Vec<_> = segmenter.segment_str("ภาษาไทยง่าย").collect();
+        assert_eq!(segmented, ["ภาษา", "ไทย", "ง่าย"]);
+
+        let segmenter = ThaiSegmenter::default().prefer_shorter(true);
+        let segmented: Vec<_> = segmenter.segment_str("สระน้ำ").collect();
+        assert_eq!(segmented, ["สร", "ะ", "น้ำ"]);
+    }
+
+    #[test]
+    fn segment_str_with_origin() {
+        let segmenter = ThaiSegmenter::default().prefer_shorter(true);
+        let segmented: Vec<_> = segmenter
+            .segment_str_with_origin("สระน้ำ")
+            .map(|(segment, origin)| (segment, origin.in_dictionary))
+            .collect();
+        // the char left alone isn't a word of the dictionary.
+        assert_eq!(segmented, [("สร", Some(true)), ("ะ", Some(false)), ("น้ำ", Some(true))]);
+    }
+
+    #[test]
+    fn segment_str_alternatives() {
+        let segmenter = ThaiSegmenter::default();
+        let alternatives = segmenter.segment_str_alternatives("สระน้ำ", 3);
+        assert_eq!(alternatives, [vec!["สระน้ำ"], vec!["สระ", "น้ำ"], vec!["สร", "ะ", "น้ำ"]]);
+
+        // the default segmentation is always the first one.
+        let segmenter = ThaiSegmenter::default().prefer_shorter(true);
+        let alternatives = segmenter.segment_str_alternatives("สระน้ำ", 2);
+        assert_eq!(alternatives, [vec!["สร", "ะ", "น้ำ"], vec!["สระน้ำ"]]);
+        assert!(segmenter.segment_str_alternatives("สระน้ำ", 0).is_empty());
+    }
+
     // Macro that run several tests on the Segmenter.
-    test_segmenter!(ThaiSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Thai, Language::Tha);
+    test_segmenter!(
+        ThaiSegmenter::default(),
+        TEXT,
+        SEGMENTED,
+        TOKENIZED,
+        Script::Thai,
+        Language::Tha
+    );
 }
@@ -1,29 +1,100 @@
-use fst::raw::{Fst, Output};
+use std::borrow::Cow;
+#[cfg(any(feature = "chinese", feature = "thai", feature = "khmer", feature = "sinhala"))]
+use std::cmp::Reverse;
+#[cfg(any(feature = "thai", feature = "khmer", feature = "sinhala"))]
+use std::sync::Arc;
+
+use fst::raw::Fst;
+#[cfg(any(feature = "thai", feature = "khmer", feature = "sinhala"))]
+use fst::raw::Output;
+#[cfg(any(feature = "thai", feature = "khmer", feature = "sinhala"))]
+use once_cell::sync::OnceCell;
+
+#[cfg(any(feature = "thai", feature = "khmer", feature = "sinhala"))]
+use super::{DictionaryInfo, SegmentOrigin};
+#[cfg(any(feature = "thai", feature = "khmer", feature = "sinhala"))]
+use crate::fingerprint::checksum;
+
+/// Dictionary of words encoded as an FST, either embedded in the binary or loaded at runtime.
+pub type WordsFst = Fst<Cow<'static, [u8]>>;
 
 /// Final-state-transducer (FST) Segmenter
-pub(crate) struct FstSegmenter<'fst> {
-    words_fst: &'fst Fst<&'fst [u8]>,
+#[cfg(any(feature = "thai", feature = "khmer", feature = "sinhala"))]
+#[derive(Clone)]
+pub(crate) struct FstSegmenter {
+    words_fst: Arc<WordsFst>,
+    name: &'static str,
+    source: &'static str,
+    /// checksum of the dictionary, only computed when requested.
+    checksum: OnceCell<u64>,
+    /// maximum number of chars of a matched word.
+    max_word_len: Option<usize>,
+    /// match the shortest word of the dictionary instead of the longest one.
+    prefer_shorter: bool,
 }
 
-impl<'fst> FstSegmenter<'fst> {
-    pub(crate) fn new(words_fst: &'fst Fst<&'fst [u8]>) -> Self {
-        Self { words_fst }
+#[cfg(any(feature = "thai", feature = "khmer", feature = "sinhala"))]
+impl FstSegmenter {
+    pub(crate) fn new(words_fst: Arc<WordsFst>, name: &'static str, source: &'static str) -> Self {
+        Self {
+            words_fst,
+            name,
+            source,
+            checksum: OnceCell::new(),
+            max_word_len: None,
+            prefer_shorter: false,
+        }
+    }
+
+    #[cfg(any(feature = "thai", feature = "sinhala"))]
+    pub(crate) fn set_max_word_len(&mut self, max_word_len: Option<usize>) {
+        self.max_word_len = max_word_len;
     }
 
-    pub fn segment_str<'o>(
-        &'fst self,
+    #[cfg(feature = "thai")]
+    pub(crate) fn set_prefer_shorter(&mut self, prefer_shorter: bool) {
+        self.prefer_shorter = prefer_shorter;
+    }
+
+    pub(crate) fn dictionary(&self) -> DictionaryInfo {
+        let checksum = *self.checksum.get_or_init(|| checksum(self.words_fst.as_bytes()));
+        DictionaryInfo {
+            name: self.name,
+            version: None,
+            source: self.source,
+            checksum: Some(checksum),
+        }
+    }
+
+    pub fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        Box::new(self.segment_str_with_origin(to_segment).map(|(segment, _)| segment))
+    }
+
+    /// Segments the provided text, telling for each segment if it was found in the dictionary.
+    pub fn segment_str_with_origin<'o>(
+        &self,
         mut to_segment: &'o str,
-    ) -> Box<dyn Iterator<Item = &'o str> + 'o>
-    where
-        'fst: 'o,
-    {
+    ) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+        let words_fst = self.words_fst.clone();
+        let (max_word_len, prefer_shorter) = (self.max_word_len, self.prefer_shorter);
         let iter = std::iter::from_fn(move || {
             // if we reach the end of the text, we return None.
             if to_segment.is_empty() {
                 return None;
             }
 
-            let length = match find_longest_prefix(self.words_fst, to_segment.as_bytes()) {
+            // the candidates are limited to the bytes of the first `max_word_len` chars.
+            let max_bytes = max_word_len
+                .and_then(|max| to_segment.char_indices().nth(max))
+                .map_or(to_segment.len(), |(index, _)| index);
+            let candidates = &to_segment.as_bytes()[..max_bytes];
+            let prefix = if prefer_shorter {
+                find_shortest_prefix(&words_fst, candidates)
+            } else {
+                find_longest_prefix(&words_fst, candidates)
+            };
+
+            let length = match prefix {
                 Some((_, length)) => length,
                 None => {
                     // if no sequence matches, we return the next character as a lemma.
@@ -34,11 +105,90 @@ impl<'fst> FstSegmenter<'fst> {
 
             let (left, right) = to_segment.split_at(length);
             to_segment = right;
-            Some(left)
+            Some((left, SegmentOrigin { in_dictionary: Some(prefix.is_some()), cost: None }))
         });
 
         Box::new(iter)
     }
+
+    /// Returns up to `n` segmentations of the provided text,
+    /// the first one being the one of [`Self::segment_str`] followed by the best alternatives.
+    pub fn segment_str_alternatives<'o>(&self, to_segment: &'o str, n: usize) -> Vec<Vec<&'o str>> {
+        let default = self.segment_str(to_segment).collect();
+        n_best_segmentations(to_segment, n, default, |start| {
+            let max_bytes = self
+                .max_word_len
+                .and_then(|max| to_segment[start..].char_indices().nth(max))
+                .map_or(to_segment.len() - start, |(index, _)| index);
+            let candidates = &to_segment.as_bytes()[start..start + max_bytes];
+            find_all_prefixes(&self.words_fst, candidates)
+                .into_iter()
+                .map(|length| start + length)
+                .collect()
+        })
+    }
+}
+
+/// Returns up to `n` segmentations of the provided text, starting with the `default` one,
+/// followed by the other paths of the graph of the words from the most to the least likely.
+///
+/// `words_ending` returns the byte indexes of the end of the words of the dictionary starting at the provided byte index,
+/// a char which doesn't start any word being left alone.
+/// The segmentations having less chars left alone are preferred, then the ones having less segments.
+#[cfg(any(feature = "chinese", feature = "thai", feature = "khmer", feature = "sinhala"))]
+pub(crate) fn n_best_segmentations<'o>(
+    text: &'o str,
+    n: usize,
+    default: Vec<&'o str>,
+    mut words_ending: impl FnMut(usize) -> Vec<usize>,
+) -> Vec<Vec<&'o str>> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // the best paths from each char boundary to the end of the text, as their cost,
+    // the end of their first segment and the rank of the following path in the paths from this end.
+    let mut best = vec![Vec::new(); text.len() + 1];
+    best[text.len()].push(((0, 0), text.len(), 0));
+    for (start, c) in text.char_indices().rev() {
+        let mut ends = words_ending(start);
+        let guessed = ends.is_empty();
+        if guessed {
+            ends.push(start + c.len_utf8());
+        }
+
+        let mut paths: Vec<_> = ends
+            .into_iter()
+            .flat_map(|end| {
+                best[end].iter().enumerate().map(move |(rank, ((left_alone, segments), ..))| {
+                    ((left_alone + guessed as usize, segments + 1), end, rank)
+                })
+            })
+            .collect();
+        // the paths of the same cost starting with a longer word are preferred.
+        paths.sort_unstable_by_key(|&(cost, end, rank)| (cost, Reverse(end), rank));
+        paths.truncate(n);
+        best[start] = paths;
+    }
+
+    let mut segmentations = vec![default];
+    for rank in 0..best[0].len() {
+        if segmentations.len() == n {
+            break;
+        }
+
+        let (mut start, mut rank, mut segmentation) = (0, rank, Vec::new());
+        while start < text.len() {
+            let (_, end, next_rank) = best[start][rank];
+            segmentation.push(&text[start..end]);
+            (start, rank) = (end, next_rank);
+        }
+        if segmentation != segmentations[0] {
+            segmentations.push(segmentation);
+        }
+    }
+
+    segmentations
 }
 
 /// Thanks to @llogiq for this function
@@ -51,8 +201,9 @@ impl<'fst> FstSegmenter<'fst> {
 /// length of the found key. Otherwise `None` is returned.
 ///
 /// This can be used to e.g. build tokenizing functions.
+#[cfg(any(feature = "thai", feature = "khmer", feature = "sinhala"))]
 #[inline]
-fn find_longest_prefix(fst: &Fst<&[u8]>, value: &[u8]) -> Option<(u64, usize)> {
+fn find_longest_prefix<D: AsRef<[u8]>>(fst: &Fst<D>, value: &[u8]) -> Option<(u64, usize)> {
     let mut node = fst.root();
     let mut out = Output::zero();
     let mut last_match = None;
@@ -70,3 +221,38 @@ fn find_longest_prefix(fst: &Fst<&[u8]>, value: &[u8]) -> Option<(u64, usize)> {
     }
     last_match
 }
+
+/// find the shortest non-empty key that is prefix of the given value,
+/// see [`find_longest_prefix`].
+#[cfg(any(feature = "thai", feature = "khmer", feature = "sinhala"))]
+#[inline]
+fn find_shortest_prefix<D: AsRef<[u8]>>(fst: &Fst<D>, value: &[u8]) -> Option<(u64, usize)> {
+    let mut node = fst.root();
+    let mut out = Output::zero();
+    for (i, &b) in value.iter().enumerate() {
+        let trans_index = node.find_input(b)?;
+        let t = node.transition(trans_index);
+        node = fst.node(t.addr);
+        out = out.cat(t.out);
+        if node.is_final() {
+            return Some((out.cat(node.final_output()).value(), i + 1));
+        }
+    }
+    None
+}
+
+/// find the length of all the non-empty keys that are prefix of the given value,
+/// from the shortest to the longest, see [`find_longest_prefix`].
+#[cfg(any(feature = "thai", feature = "khmer", feature = "sinhala"))]
+fn find_all_prefixes<D: AsRef<[u8]>>(fst: &Fst<D>, value: &[u8]) -> Vec<usize> {
+    let mut node = fst.root();
+    let mut lengths = Vec::new();
+    for (i, &b) in value.iter().enumerate() {
+        let Some(trans_index) = node.find_input(b) else { break };
+        node = fst.node(node.transition(trans_index).addr);
+        if node.is_final() {
+            lengths.push(i + 1);
+        }
+    }
+    lengths
+}
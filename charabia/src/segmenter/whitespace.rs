@@ -0,0 +1,37 @@
+use slice_group_by::StrGroupBy;
+
+use crate::segmenter::Segmenter;
+
+/// [`Segmenter`] only splitting the text on whitespaces.
+///
+/// Each whitespace is a segment, to be classified as a separator, and the runs of other characters are the words.
+/// This segmenter is used for the whole text when the whitespace segmentation is enabled,
+/// see [`crate::TokenizerBuilder::whitespace_segmentation`].
+pub struct WhitespaceSegmenter;
+
+impl Segmenter for WhitespaceSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        let segments = to_segment.linear_group_by(|a, b| !a.is_whitespace() && !b.is_whitespace());
+        Box::new(segments)
+    }
+
+    fn splits_separators(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::WhitespaceSegmenter;
+    use crate::segmenter::Segmenter;
+
+    #[test]
+    fn segment() {
+        let segments: Vec<_> =
+            WhitespaceSegmenter.segment_str("The quick\t(\"brown\")  fox,\ncan't").collect();
+        assert_eq!(
+            segments,
+            ["The", " ", "quick", "\t", "(\"brown\")", " ", " ", "fox,", "\n", "can't"]
+        );
+    }
+}
@@ -1,3 +1,12 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use aho_corasick::AhoCorasick;
+use once_cell::sync::Lazy;
+
+use crate::segmenter::{AhoOption, SeparatorPrefilter};
+use crate::SeparatorKind;
+
 /// Default characters categorized as separators.
 ///
 /// This list contains all the characters in below unicode categories:
@@ -152,3 +161,147 @@ pub const CONTEXT_SEPARATORS: &[&str] = &[
     "𝪇", "𝪈", "𝪉", "𝪊", // Signwriting
     "𞥞", "𞥟", // Adlam
 ];
+
+/// Compiled set of separators classified as soft or hard,
+/// see [`TokenizerBuilder::separator_set`](crate::TokenizerBuilder::separator_set).
+///
+/// The automaton searching the separators is compiled once when the set is created,
+/// and cloning the set is cheap, so a single set can be shared between many tokenizers
+/// or [`SegmenterOption`](crate::segmenter::SegmenterOption)s, see [`SegmenterOption::separators`](crate::segmenter::SegmenterOption::separators).
+///
+/// # Example
+///
+/// ```
+/// use charabia::separators::SeparatorSet;
+/// use charabia::{SeparatorKind, TokenizerBuilder};
+///
+/// let separators = SeparatorSet::new(&[" ", "-"], &["|"]);
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.separator_set(separators.clone());
+/// let tokenizer = builder.build();
+///
+/// let kinds: Vec<_> = tokenizer.tokenize("left-side | right").filter_map(|t| t.separator_kind()).collect();
+/// assert_eq!(kinds, [SeparatorKind::Soft, SeparatorKind::Soft, SeparatorKind::Hard, SeparatorKind::Soft]);
+///
+/// // the same compiled set is used by another tokenizer.
+/// let mut builder = TokenizerBuilder::default();
+/// builder.separator_set(separators).lossy_normalization(false);
+/// assert_eq!(builder.build().tokenize("left-side").count(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SeparatorSet(Arc<SeparatorSetInner>);
+
+/// The [`DEFAULT_SEPARATORS`] classified like by the default tokenizer, see [`SeparatorSet::default`].
+static DEFAULT_SEPARATOR_SET: Lazy<SeparatorSet> =
+    Lazy::new(|| SeparatorSet::classified(DEFAULT_SEPARATORS, AhoOption::default()));
+
+#[derive(Debug)]
+struct SeparatorSetInner {
+    soft: Vec<String>,
+    hard: Vec<String>,
+    kinds: HashMap<String, SeparatorKind>,
+    option: AhoOption,
+    aho: AhoCorasick,
+    prefilter: Option<SeparatorPrefilter>,
+}
+
+impl SeparatorSet {
+    /// Creates the set of the provided soft and hard separators,
+    /// the hard ones winning if a separator is in both lists.
+    pub fn new(soft: &[&str], hard: &[&str]) -> Self {
+        Self::with_option(soft, hard, AhoOption::default())
+    }
+
+    /// Same as [`SeparatorSet::new`] with custom build options of the automaton.
+    pub fn with_option(soft: &[&str], hard: &[&str], option: AhoOption) -> Self {
+        let mut kinds = HashMap::with_capacity(soft.len() + hard.len());
+        kinds.extend(soft.iter().map(|s| (s.to_string(), SeparatorKind::Soft)));
+        kinds.extend(hard.iter().map(|s| (s.to_string(), SeparatorKind::Hard)));
+
+        let patterns = soft.iter().chain(hard);
+        Self(Arc::new(SeparatorSetInner {
+            soft: soft.iter().map(|s| s.to_string()).collect(),
+            hard: hard.iter().map(|s| s.to_string()).collect(),
+            aho: option.build(patterns.clone()),
+            prefilter: SeparatorPrefilter::new(patterns),
+            kinds,
+            option,
+        }))
+    }
+
+    /// Creates the set of the provided separators, the [`CONTEXT_SEPARATORS`] being hard and the other ones soft,
+    /// like the separators set with [`TokenizerBuilder::separators`](crate::TokenizerBuilder::separators).
+    pub fn classified(separators: &[&str], option: AhoOption) -> Self {
+        let (hard, soft): (Vec<_>, Vec<_>) =
+            separators.iter().partition(|separator| CONTEXT_SEPARATORS.contains(separator));
+        Self::with_option(&soft, &hard, option)
+    }
+
+    /// Returns a new set with the provided separators added as `kind`,
+    /// replacing their kind if they are already in the set.
+    pub fn with_separators(&self, separators: &[&str], kind: SeparatorKind) -> Self {
+        let mut soft = kept(&self.0.soft, separators);
+        let mut hard = kept(&self.0.hard, separators);
+        match kind {
+            SeparatorKind::Soft => soft.extend_from_slice(separators),
+            SeparatorKind::Hard => hard.extend_from_slice(separators),
+        }
+        Self::with_option(&soft, &hard, self.0.option)
+    }
+
+    /// Returns a new set without the provided separators.
+    pub fn without_separators(&self, separators: &[&str]) -> Self {
+        let soft = kept(&self.0.soft, separators);
+        let hard = kept(&self.0.hard, separators);
+        Self::with_option(&soft, &hard, self.0.option)
+    }
+
+    /// Returns the kind of the provided separator, `None` if it is not in the set.
+    pub fn kind(&self, separator: &str) -> Option<SeparatorKind> {
+        self.0.kinds.get(separator).copied()
+    }
+
+    /// Returns the soft separators of the set.
+    pub fn soft(&self) -> &[String] {
+        &self.0.soft
+    }
+
+    /// Returns the hard separators of the set.
+    pub fn hard(&self) -> &[String] {
+        &self.0.hard
+    }
+
+    /// Returns the build options of the automaton.
+    pub fn option(&self) -> AhoOption {
+        self.0.option
+    }
+
+    /// Returns the automaton searching the separators.
+    pub fn automaton(&self) -> &AhoCorasick {
+        &self.0.aho
+    }
+
+    /// Returns the prefilter of the separators, `None` if a separator is empty.
+    pub fn prefilter(&self) -> Option<&SeparatorPrefilter> {
+        self.0.prefilter.as_ref()
+    }
+
+    /// Returns an iterator over the separators, soft then hard.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.soft.iter().chain(&self.0.hard).map(String::as_str)
+    }
+}
+
+/// Returns the provided separators which are not in `removed`.
+fn kept<'s>(separators: &'s [String], removed: &[&str]) -> Vec<&'s str> {
+    separators.iter().map(String::as_str).filter(|s| !removed.contains(s)).collect()
+}
+
+impl Default for SeparatorSet {
+    /// Returns the [`DEFAULT_SEPARATORS`], the [`CONTEXT_SEPARATORS`] being hard,
+    /// the set being compiled once and shared.
+    fn default() -> Self {
+        DEFAULT_SEPARATOR_SET.clone()
+    }
+}
@@ -1,9 +1,11 @@
 use std::borrow::Cow;
+use std::fmt;
 
 #[cfg(test)]
 use quickcheck::{Arbitrary, Gen};
 
-use crate::detection::{Language, Script};
+use crate::detection::{CjScript, Language, Script};
+use crate::normalizer::{Normalize, DEFAULT_NORMALIZER_OPTION};
 
 /// Define the kind of a [`TokenKind::Separator`].
 ///
@@ -16,6 +18,16 @@ pub enum SeparatorKind {
     Soft,
 }
 
+/// Define the end of a text unit marked on a separator [`Token`],
+/// see [`TokenFilter::mark_boundaries`](crate::filter::TokenFilter::mark_boundaries).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// the separator ends a sentence.
+    Sentence,
+    /// the separator ends a paragraph, and so the sentence it contains.
+    Paragraph,
+}
+
 /// Define the kind of a [`Token`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TokenKind {
@@ -26,6 +38,24 @@ pub enum TokenKind {
     /// the token is a separator,
     /// meaning that it shouldn't be indexed but used to determine word proximity
     Separator(SeparatorKind),
+    /// the token is a date or a time, like "2024-05-01" or "3pm",
+    /// only recognized by [`TokenFilter::recognize_date_times`](crate::filter::TokenFilter::recognize_date_times)
+    DateTime,
+    /// the token is a currency symbol, like "$" or "€", or a price if attached to its number,
+    /// only recognized by [`TokenFilter::classify_units`](crate::filter::TokenFilter::classify_units)
+    Currency,
+    /// the token is a unit symbol following a number, like "%" or "km", or a quantity if attached to its number,
+    /// only recognized by [`TokenFilter::classify_units`](crate::filter::TokenFilter::classify_units)
+    Unit,
+    /// the token is an emoji or a sequence of emoji, like "👍🏽", "👩‍💻" or "🇫🇷",
+    /// so it can be kept, dropped or boosted independently of the words
+    Emoji,
+    /// the token is a hashtag, like "#HelloWorld",
+    /// only recognized by [`TokenFilter::recognize_social_tags`](crate::filter::TokenFilter::recognize_social_tags)
+    Hashtag,
+    /// the token is a mention, like "@john_doe",
+    /// only recognized by [`TokenFilter::recognize_social_tags`](crate::filter::TokenFilter::recognize_social_tags)
+    Mention,
     Unknown,
 }
 
@@ -48,6 +78,54 @@ impl Default for TokenKind {
     }
 }
 
+/// Inconsistency between the offsets of a [`Token`] and the original text,
+/// returned by [`Token::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OffsetError {
+    /// `byte_start` is greater than `byte_end` or `byte_end` is out of the original text.
+    ByteRange { byte_start: usize, byte_end: usize, len: usize },
+    /// the byte offset doesn't lie on a char boundary of the original text.
+    CharBoundary { byte_offset: usize },
+    /// the char offsets don't match the chars of the original text located by the byte offsets.
+    CharRange { expected: (usize, usize), found: (usize, usize) },
+    /// the char_map doesn't have one entry per char of the original lemma.
+    CharMapLength { expected: usize, found: usize },
+    /// the sum of the original lengths of the char_map doesn't match the length of the original lemma.
+    CharMapOriginalBytes { expected: usize, found: usize },
+    /// the sum of the normalized lengths of the char_map doesn't match the length of the lemma.
+    CharMapNormalizedBytes { expected: usize, found: usize },
+}
+
+impl fmt::Display for OffsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ByteRange { byte_start, byte_end, len } => write!(
+                f,
+                "byte range {byte_start}..{byte_end} is invalid for an original text of {len} bytes"
+            ),
+            Self::CharBoundary { byte_offset } => {
+                write!(f, "byte offset {byte_offset} is not on a char boundary")
+            }
+            Self::CharRange { expected, found } => write!(
+                f,
+                "char range {}..{} doesn't match the original text, expected {}..{}",
+                found.0, found.1, expected.0, expected.1
+            ),
+            Self::CharMapLength { expected, found } => {
+                write!(f, "char_map has {found} entries, expected {expected}")
+            }
+            Self::CharMapOriginalBytes { expected, found } => {
+                write!(f, "char_map covers {found} original bytes, expected {expected}")
+            }
+            Self::CharMapNormalizedBytes { expected, found } => {
+                write!(f, "char_map covers {found} normalized bytes, expected {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OffsetError {}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Token<'o> {
     /// kind of the Token assigned by the classifier
@@ -66,6 +144,47 @@ pub struct Token<'o> {
     pub script: Script,
     /// language of the Token
     pub language: Option<Language>,
+    /// the token is the last word of a query and may be the prefix of a longer word,
+    /// only set when the query prefix mode is enabled
+    pub is_prefix: bool,
+    /// the token wasn't found in the dictionary of its segmenter and was guessed,
+    /// like an unknown word of lindera or a char left alone by a dictionary encoded as an FST,
+    /// only set when the guessed tokens are marked, see [`TokenizerBuilder::mark_guessed`](crate::TokenizerBuilder::mark_guessed)
+    pub guessed: bool,
+    /// cost of the token in the lattice of its segmenter, the lower the more likely,
+    /// only set when the costs are kept, see [`TokenizerBuilder::segment_costs`](crate::TokenizerBuilder::segment_costs)
+    pub cost: Option<i32>,
+    /// index of the double-quoted phrase containing the token in a query,
+    /// only set when the query phrase mode is enabled
+    pub phrase: Option<usize>,
+    /// secondary forms of the lemma computed by the opt-in normalizers, see [`TokenForms`],
+    /// `None` when none of them is computed
+    pub forms: Option<Box<TokenForms>>,
+    /// index of the word in its synonym and number of words of the synonym,
+    /// only set on the synonyms emitted by [`TokenFilter::synonyms`](crate::filter::TokenFilter::synonyms)
+    pub synonym: Option<(usize, usize)>,
+    /// end of sentence or paragraph marked on the separator,
+    /// only set by [`TokenFilter::mark_boundaries`](crate::filter::TokenFilter::mark_boundaries)
+    pub boundary: Option<Boundary>,
+    /// original lemma of the token before its normalization,
+    /// only set when the original lemmas are kept, see [`TokenizerBuilder::keep_original`](crate::TokenizerBuilder::keep_original)
+    pub original: Option<Cow<'o, str>>,
+}
+
+/// Secondary forms of the lemma of a [`Token`], only computed when their normalizer is enabled.
+///
+/// They are boxed together in [`Token::forms`] so the tokens don't grow with each optional form.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenForms {
+    /// coarse form of the lemma for fuzzy matching, only computed when the skeleton is enabled
+    pub skeleton: Option<String>,
+    /// transliteration of the Greek, Cyrillic or Hangul lemma to the Latin alphabet, only computed when the romanization is enabled
+    pub romanized: Option<String>,
+    /// lemma with its CJK numerals converted to ASCII digits, like "第3章" for "第三章",
+    /// only computed when the numeral conversion is enabled
+    pub numeric: Option<String>,
+    /// phonetic keys of the lemma, the primary one first, only computed when a phonetic algorithm is configured
+    pub phonetic_keys: Vec<String>,
 }
 
 impl Token<'_> {
@@ -74,6 +193,37 @@ impl Token<'_> {
         self.lemma.as_ref()
     }
 
+    /// Returns the original lemma of the token before its normalization, if it has been kept.
+    pub fn original_lemma(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+
+    /// Returns the skeleton of the lemma, if it has been computed, see [`TokenForms::skeleton`].
+    pub fn skeleton(&self) -> Option<&str> {
+        self.forms.as_ref()?.skeleton.as_deref()
+    }
+
+    /// Returns the romanization of the lemma, if it has been computed, see [`TokenForms::romanized`].
+    pub fn romanized(&self) -> Option<&str> {
+        self.forms.as_ref()?.romanized.as_deref()
+    }
+
+    /// Returns the lemma with its CJK numerals converted, if it has been computed, see [`TokenForms::numeric`].
+    pub fn numeric(&self) -> Option<&str> {
+        self.forms.as_ref()?.numeric.as_deref()
+    }
+
+    /// Returns the phonetic keys of the lemma, empty if they haven't been computed,
+    /// see [`TokenForms::phonetic_keys`].
+    pub fn phonetic_keys(&self) -> &[String] {
+        self.forms.as_ref().map_or(&[], |forms| &forms.phonetic_keys)
+    }
+
+    /// Returns the secondary forms of the token, allocating them if none has been computed yet.
+    pub fn forms_mut(&mut self) -> &mut TokenForms {
+        self.forms.get_or_insert_with(Box::default)
+    }
+
     /// Returns the length in bytes of the normalized lemma.
     pub fn byte_len(&self) -> usize {
         self.lemma.len()
@@ -94,6 +244,16 @@ impl Token<'_> {
         self.char_end - self.char_start
     }
 
+    /// Returns the [`CjScript`] of the lemma if the token is of [`Script::Cj`],
+    /// the kana taking precedence over the Han characters, see [`CjScript::detect`].
+    pub fn cj_script(&self) -> Option<CjScript> {
+        if self.script == Script::Cj {
+            CjScript::detect(&self.lemma)
+        } else {
+            None
+        }
+    }
+
     /// Returns the [`TokenKind`] of the current token.
     pub fn kind(&self) -> TokenKind {
         self.kind
@@ -111,6 +271,31 @@ impl Token<'_> {
         self.kind == TokenKind::StopWord
     }
 
+    /// Returns true if the current token is a date or a time.
+    pub fn is_date_time(&self) -> bool {
+        self.kind == TokenKind::DateTime
+    }
+
+    /// Returns true if the current token is a currency symbol or a price.
+    pub fn is_currency(&self) -> bool {
+        self.kind == TokenKind::Currency
+    }
+
+    /// Returns true if the current token is a unit symbol or a quantity.
+    pub fn is_unit(&self) -> bool {
+        self.kind == TokenKind::Unit
+    }
+
+    /// Returns true if the current token is an emoji or a sequence of emoji.
+    pub fn is_emoji(&self) -> bool {
+        self.kind == TokenKind::Emoji
+    }
+
+    /// Returns true if the current token is a hashtag or a mention.
+    pub fn is_social_tag(&self) -> bool {
+        matches!(self.kind, TokenKind::Hashtag | TokenKind::Mention)
+    }
+
     /// Returns true if the current token is a separator.
     pub fn is_separator(&self) -> bool {
         self.separator_kind().map_or(false, |_| true)
@@ -176,6 +361,73 @@ impl Token<'_> {
             }
         }
     }
+
+    /// Returns true if the provided lemma, normalized like the token with the default options,
+    /// is equal to the lemma of the token.
+    ///
+    /// The query lemma is normalized as a single word of the token [`Script`] and [`Language`],
+    /// use [`crate::normalized_eq`] to compare whole texts.
+    pub fn matches(&self, query_lemma: &str) -> bool {
+        let query = Token {
+            lemma: Cow::Borrowed(query_lemma),
+            script: self.script,
+            language: self.language,
+            ..Default::default()
+        };
+        query.normalize(&DEFAULT_NORMALIZER_OPTION).lemma == self.lemma
+    }
+
+    /// Checks that the offsets and the char_map of the token are consistent with the `original` text it comes from.
+    ///
+    /// This is a debugging utility, it counts the chars of the original text preceding the token
+    /// and should not be called on every token in production.
+    pub fn validate(&self, original: &str) -> Result<(), OffsetError> {
+        let Self { byte_start, byte_end, char_start, char_end, .. } = *self;
+        if byte_start > byte_end || byte_end > original.len() {
+            return Err(OffsetError::ByteRange { byte_start, byte_end, len: original.len() });
+        }
+        if let Some(&byte_offset) =
+            [byte_start, byte_end].iter().find(|offset| !original.is_char_boundary(**offset))
+        {
+            return Err(OffsetError::CharBoundary { byte_offset });
+        }
+
+        let expected_start = original[..byte_start].chars().count();
+        let char_count = original[byte_start..byte_end].chars().count();
+        let expected = (expected_start, expected_start + char_count);
+        if (char_start, char_end) != expected {
+            return Err(OffsetError::CharRange { expected, found: (char_start, char_end) });
+        }
+
+        if let Some(char_map) = &self.char_map {
+            if char_map.len() != char_count {
+                return Err(OffsetError::CharMapLength {
+                    expected: char_count,
+                    found: char_map.len(),
+                });
+            }
+
+            let original_bytes: usize =
+                char_map.iter().map(|(original, _)| *original as usize).sum();
+            if original_bytes != byte_end - byte_start {
+                return Err(OffsetError::CharMapOriginalBytes {
+                    expected: byte_end - byte_start,
+                    found: original_bytes,
+                });
+            }
+
+            let normalized_bytes: usize =
+                char_map.iter().map(|(_, normalized)| *normalized as usize).sum();
+            if normalized_bytes != self.lemma.len() {
+                return Err(OffsetError::CharMapNormalizedBytes {
+                    expected: self.lemma.len(),
+                    found: normalized_bytes,
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -205,6 +457,61 @@ impl Arbitrary for Token<'static> {
             char_map: None,
             script: Script::arbitrary(g),
             language: Option::arbitrary(g),
+            is_prefix: bool::arbitrary(g),
+            guessed: bool::arbitrary(g),
+            cost: Option::arbitrary(g),
+            phrase: Option::arbitrary(g),
+            forms: None,
+            synonym: None,
+            boundary: None,
+            original: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::OffsetError;
+    use crate::{Tokenize, TokenizerBuilder};
+
+    #[test]
+    fn matches() {
+        let tokens: Vec<_> = "Thé Ελληνικά".tokenize().collect();
+        assert!(tokens[0].matches("THE"));
+        assert!(tokens[0].matches("thé"));
+        assert!(!tokens[0].matches("then"));
+        assert!(tokens[2].matches("ελληνικα"));
+    }
+
+    #[test]
+    fn validate() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "Thé quick (\"brown\") fox, ΠΑΝΑΓΙΑ 北京!";
+        for token in tokenizer.tokenize(text) {
+            assert_eq!(token.validate(text), Ok(()), "{token:?}");
         }
+
+        let mut token = tokenizer.tokenize(text).next().unwrap();
+        assert_eq!(
+            token.validate("Th"),
+            Err(OffsetError::ByteRange { byte_start: 0, byte_end: 4, len: 2 })
+        );
+        assert_eq!(token.validate("Th€"), Err(OffsetError::CharBoundary { byte_offset: 4 }));
+
+        token.char_end = 4;
+        assert_eq!(
+            token.validate(text),
+            Err(OffsetError::CharRange { expected: (0, 3), found: (0, 4) })
+        );
+
+        token.char_end = 3;
+        token.char_map = Some(vec![(1, 1), (1, 1), (2, 2)]);
+        assert_eq!(
+            token.validate(text),
+            Err(OffsetError::CharMapNormalizedBytes { expected: 3, found: 4 })
+        );
     }
 }
@@ -1,14 +1,21 @@
-use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 
 use aho_corasick::{AhoCorasick, MatchKind};
 use fst::Set;
 
-use crate::detection::{Language, Script};
-use crate::normalizer::{NormalizedTokenIter, NormalizerOption};
-use crate::segmenter::{Segment, SegmentedStrIter, SegmentedTokenIter, SegmenterOption};
-use crate::separators::DEFAULT_SEPARATORS;
-use crate::Token;
+use crate::binary::{self, DecodeError};
+use crate::detection::{DetectionCache, Language, Script};
+use crate::fingerprint::Fingerprinter;
+use crate::metrics::TokenizerObserver;
+use crate::normalizer::{BlankInput, NormalizedTokenIter, NormalizerOption, ProlongedSoundMark};
+use crate::segmenter::{
+    effective_segmenters, AhoOption, DictionaryInfo, Segment, SegmentedStrIter, SegmentedTokenIter,
+    Segmenter, SegmenterOption, SeparatorPrefilter, DEFAULT_SEPARATOR_PREFILTER,
+};
+use crate::separators::{SeparatorSet, DEFAULT_SEPARATORS};
+use crate::{SeparatorKind, Token};
 
 /// Iterator over tuples of [`&str`] (part of the original text) and [`Token`].
 pub struct ReconstructedTokenIter<'o, 'tb> {
@@ -95,13 +102,70 @@ impl Tokenize<'_> for &str {
     }
 }
 
+/// Tokens of a query returned by [`Tokenizer::query_consistency`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryConsistency<'o> {
+    /// tokens of the query as produced for the indexed documents.
+    pub index: Vec<Token<'o>>,
+    /// tokens of the query as produced with the query modes enabled.
+    pub query: Vec<Token<'o>>,
+}
+
+impl<'o> QueryConsistency<'o> {
+    /// Returns the pairs of index and query tokens at the same position which differ
+    /// by more than the fields only set by the query modes, [`Token::is_prefix`] and [`Token::phrase`],
+    /// a token missing from one of the streams being `None`.
+    pub fn mismatches(&self) -> Vec<(Option<&Token<'o>>, Option<&Token<'o>>)> {
+        let without_query_fields =
+            |token: &Token<'o>| Token { is_prefix: false, phrase: None, ..token.clone() };
+        (0..self.index.len().max(self.query.len()))
+            .map(|i| (self.index.get(i), self.query.get(i)))
+            .filter(|(index, query)| match (index, query) {
+                (Some(index), Some(query)) => {
+                    without_query_fields(index) != without_query_fields(query)
+                }
+                _ => true,
+            })
+            .collect()
+    }
+}
+
 /// Structure used to tokenize a text with custom configurations.
 ///
 /// See [`TokenizerBuilder`] to know how to build a [`Tokenizer`].
-#[derive(Debug)]
+///
+/// The configuration is shared behind an [`Arc`], cloning a `Tokenizer` is cheap
+/// and a `Tokenizer` is `Send + Sync`, so a single configured instance can be shared between threads,
+/// for instance between the request handlers of a web server.
+///
+/// # Example
+///
+/// ```
+/// use charabia::TokenizerBuilder;
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.lossy_normalization(true);
+/// let tokenizer = builder.into_tokenizer();
+///
+/// let handles: Vec<_> = ["Café", "Straße"]
+///     .into_iter()
+///     .map(|text| {
+///         let tokenizer = tokenizer.clone();
+///         std::thread::spawn(move || tokenizer.tokenize(text).count())
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), 1);
+/// }
+/// ```
+#[derive(Debug, Clone)]
 pub struct Tokenizer<'tb> {
-    segmenter_option: Cow<'tb, SegmenterOption<'tb>>,
-    normalizer_option: Cow<'tb, NormalizerOption<'tb>>,
+    segmenter_option: Arc<SegmenterOption<'tb>>,
+    normalizer_option: Arc<NormalizerOption<'tb>>,
+    /// normalizer option only applying the lossless normalizers, see [`Tokenizer::tokenize_lossless`].
+    lossless_normalizer_option: Arc<NormalizerOption<'tb>>,
+    fingerprint: u64,
 }
 
 impl<'tb> Tokenizer<'tb> {
@@ -113,6 +177,32 @@ impl<'tb> Tokenizer<'tb> {
         original.segment_with_option(&self.segmenter_option).normalize(&self.normalizer_option)
     }
 
+    /// Same as [`Tokenizer::tokenize`] but only applies the lossless normalizers, see [`Normalizer::is_lossy`],
+    /// whatever the lossy normalization configured with [`TokenizerBuilder::lossy_normalization`].
+    ///
+    /// A single tokenizer can then index the "exact" fields with this method and the "tolerant" fields with [`Tokenizer::tokenize`].
+    ///
+    /// [`Normalizer::is_lossy`]: crate::normalizer::Normalizer::is_lossy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let tokenizer = TokenizerBuilder::default().into_tokenizer();
+    ///
+    /// let tolerant: Vec<_> = tokenizer.tokenize("The Oﬃce").map(|t| t.lemma).collect();
+    /// assert_eq!(tolerant, ["the", " ", "office"]);
+    /// // the compatibility decomposition of the ligature is lossless, unlike the lowercasing.
+    /// let exact: Vec<_> = tokenizer.tokenize_lossless("The Oﬃce").map(|t| t.lemma).collect();
+    /// assert_eq!(exact, ["The", " ", "Office"]);
+    /// ```
+    pub fn tokenize_lossless<'t, 'o>(&'t self, original: &'o str) -> NormalizedTokenIter<'o, 't> {
+        original
+            .segment_with_option(&self.segmenter_option)
+            .normalize(&self.lossless_normalizer_option)
+    }
+
     /// Same as [`tokenize`] but attaches each [`Token`] to its corresponding portion of the original text.
     pub fn reconstruct<'t, 'o>(&'t self, original: &'o str) -> ReconstructedTokenIter<'o, 't> {
         ReconstructedTokenIter { original, token_iter: self.tokenize(original) }
@@ -127,6 +217,189 @@ impl<'tb> Tokenizer<'tb> {
     pub fn segment_str<'t, 'o>(&'t self, original: &'o str) -> SegmentedStrIter<'o, 't> {
         original.segment_str_with_option(&self.segmenter_option)
     }
+
+    /// Tokenizes the provided query both as the indexed documents, with the query modes disabled,
+    /// and as a search query, with the query prefix and phrase modes enabled,
+    /// so the tests of an index can check that its queries are tokenized like its documents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let tokenizer = TokenizerBuilder::default().into_tokenizer();
+    ///
+    /// let tokens = tokenizer.query_consistency("\"New York\" piz");
+    /// assert!(tokens.mismatches().is_empty());
+    /// assert!(tokens.query.last().unwrap().is_prefix);
+    /// assert!(!tokens.index.last().unwrap().is_prefix);
+    /// ```
+    pub fn query_consistency<'o>(&self, query: &'o str) -> QueryConsistency<'o> {
+        let index_option = NormalizerOption {
+            query_prefix: false,
+            query_phrase: false,
+            ..(*self.normalizer_option).clone()
+        };
+        let query_option = NormalizerOption {
+            query_prefix: true,
+            query_phrase: true,
+            ..(*self.normalizer_option).clone()
+        };
+
+        QueryConsistency {
+            index: query
+                .segment_with_option(&self.segmenter_option)
+                .normalize(&index_option)
+                .collect(),
+            query: query
+                .segment_with_option(&self.segmenter_option)
+                .normalize(&query_option)
+                .collect(),
+        }
+    }
+
+    /// Returns a hash of the configuration of the tokenizer.
+    ///
+    /// The fingerprint covers the version and the enabled features of charabia,
+    /// the separators, words dictionary, protected terms, stop words, the normalizer and segmenter options,
+    /// and the segmenters with their dictionaries, see [`Tokenizer::dictionaries`].
+    /// It is stable across processes and platforms, so it can be stored alongside an index
+    /// to detect that the index was built with a different tokenizer configuration and must be rebuilt.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let default = TokenizerBuilder::default().build().fingerprint();
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.lossy_normalization(false);
+    /// assert_ne!(builder.build().fingerprint(), default);
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// Encodes the tokens produced by this tokenizer to cache them, see the [`binary`](crate::binary) module.
+    pub fn encode_tokens(&self, tokens: &[Token]) -> Vec<u8> {
+        binary::encode(tokens, self.fingerprint)
+    }
+
+    /// Decodes the tokens encoded by [`Tokenizer::encode_tokens`],
+    /// failing if they have been produced by a tokenizer with another [`Tokenizer::fingerprint`].
+    pub fn decode_tokens(&self, bytes: &[u8]) -> Result<Vec<Token<'static>>, DecodeError> {
+        binary::decode(bytes, self.fingerprint)
+    }
+
+    /// Returns the dictionaries used by the segmenters of the tokenizer,
+    /// along with the [`Script`] and [`Language`] segmented by each of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let tokenizer = TokenizerBuilder::default().into_tokenizer();
+    /// for (script, language, dictionary) in tokenizer.dictionaries() {
+    ///     println!("{script:?}/{language:?}: {} {:?} from {}", dictionary.name, dictionary.version, dictionary.source);
+    /// }
+    /// ```
+    pub fn dictionaries(&self) -> Vec<(Script, Language, DictionaryInfo)> {
+        effective_segmenters(&self.segmenter_option.segmenters)
+            .into_iter()
+            .flat_map(|((script, language), segmenter)| {
+                segmenter.dictionaries().into_iter().map(move |d| (script, language, d))
+            })
+            .collect()
+    }
+}
+
+/// Error returned by [`TokenizerBuilder::try_build`] when the configuration can't be honored at tokenize time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// both a [`SeparatorSet`] and a list of separators are configured, the list being ignored.
+    ConflictingSeparators,
+    /// an option is ignored by the whitespace segmentation, like `"segmenter"` or `"words_dict"`.
+    IgnoredByWhitespaceSegmentation(&'static str),
+    /// the allow list doesn't allow any language for the script.
+    EmptyAllowList(Script),
+    /// a language is configured for a script it is not written in.
+    LanguageNotInScript { script: Script, language: Language },
+    /// a configured language needs a feature disabled in this build.
+    DisabledFeature { language: Language, feature: &'static str },
+    /// a language selected by the allow list or a default language loads its dictionary at runtime,
+    /// but no segmenter has been provided for it.
+    MissingDictionary { script: Script, language: Language },
+    /// a protected pattern is not a valid regular expression.
+    #[cfg(feature = "protected-patterns")]
+    InvalidProtectedPattern(String),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConflictingSeparators => {
+                write!(f, "both a separator set and a list of separators are configured")
+            }
+            Self::IgnoredByWhitespaceSegmentation(option) => {
+                write!(f, "the {option} option is ignored by the whitespace segmentation")
+            }
+            Self::EmptyAllowList(script) => {
+                write!(f, "the allow list doesn't allow any language for {script:?}")
+            }
+            Self::LanguageNotInScript { script, language } => {
+                write!(f, "{language:?} is not written in {script:?}")
+            }
+            Self::DisabledFeature { language, feature } => {
+                write!(f, "{language:?} needs the `{feature}` feature")
+            }
+            Self::MissingDictionary { script, language } => write!(
+                f,
+                "no segmenter has been provided for {script:?}/{language:?}, whose dictionary is loaded at runtime"
+            ),
+            #[cfg(feature = "protected-patterns")]
+            Self::InvalidProtectedPattern(pattern) => {
+                write!(f, "the protected pattern `{pattern}` is not a valid regular expression")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Returns the feature needed to tokenize the provided language if it is disabled in this build.
+fn disabled_feature(language: Language) -> Option<&'static str> {
+    match language {
+        #[cfg(not(feature = "chinese"))]
+        Language::Cmn => Some("chinese"),
+        #[cfg(not(any(feature = "japanese", feature = "japanese-segmentation-external")))]
+        Language::Jpn => Some("japanese"),
+        #[cfg(not(any(feature = "korean", feature = "korean-segmentation-external")))]
+        Language::Kor => Some("korean"),
+        #[cfg(not(any(feature = "thai", feature = "icu")))]
+        Language::Tha => Some("thai"),
+        #[cfg(not(any(feature = "khmer", feature = "icu")))]
+        Language::Khm => Some("khmer"),
+        #[cfg(not(feature = "icu"))]
+        Language::Mya => Some("icu"),
+        #[cfg(not(feature = "sinhala"))]
+        Language::Sin => Some("sinhala"),
+        _ => None,
+    }
+}
+
+/// Returns the scripts and languages whose segmenter is built without any dictionary,
+/// the dictionary being loaded at runtime and the segmenter provided with [`TokenizerBuilder::segmenter`].
+fn runtime_dictionaries() -> Vec<(Script, Language)> {
+    vec![
+        #[cfg(all(feature = "japanese-segmentation-external", not(feature = "japanese")))]
+        (Script::Cj, Language::Jpn),
+        #[cfg(all(feature = "korean-segmentation-external", not(feature = "korean")))]
+        (Script::Hangul, Language::Kor),
+        #[cfg(feature = "sinhala")]
+        (Script::Sinhala, Language::Sin),
+    ]
 }
 
 /// Structure to build a tokenizer with custom settings.
@@ -159,6 +432,10 @@ impl<'tb> Tokenizer<'tb> {
 pub struct TokenizerBuilder<'tb, A> {
     stop_words: Option<&'tb Set<A>>,
     words_dict: Option<&'tb [&'tb str]>,
+    protected_terms: Option<&'tb [&'tb str]>,
+    #[cfg(feature = "protected-patterns")]
+    protected_patterns: Option<&'tb [&'tb str]>,
+    aho_option: Option<AhoOption>,
     normalizer_option: NormalizerOption<'tb>,
     segmenter_option: SegmenterOption<'tb>,
 }
@@ -173,6 +450,10 @@ impl<'tb, A> TokenizerBuilder<'tb, A> {
             segmenter_option: SegmenterOption::default(),
             stop_words: None,
             words_dict: None,
+            protected_terms: None,
+            #[cfg(feature = "protected-patterns")]
+            protected_patterns: None,
+            aho_option: None,
         }
     }
 }
@@ -273,8 +554,197 @@ impl<'tb, A: AsRef<[u8]>> TokenizerBuilder<'tb, A> {
         self
     }
 
+    /// Configure the separators with their own soft and hard classification,
+    /// replacing the separators set with [`TokenizerBuilder::separators`].
+    ///
+    /// The automaton of the set is compiled when the set is created and shared by all the tokenizers using it,
+    /// so it is not compiled again by each build, unless [`TokenizerBuilder::words_dict`] is set
+    /// and the words must be searched along with the separators.
+    /// The build options of the set are used instead of the ones of [`TokenizerBuilder::separator_automaton`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::separators::SeparatorSet;
+    /// use charabia::{SeparatorKind, TokenizerBuilder};
+    ///
+    /// // the "/" ends the context, unlike the default separators.
+    /// let separators = SeparatorSet::new(&[" "], &["/"]);
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.separator_set(separators);
+    /// let tokenizer = builder.build();
+    ///
+    /// let kinds: Vec<_> = tokenizer.tokenize("red / blue").filter_map(|t| t.separator_kind()).collect();
+    /// assert_eq!(kinds, [SeparatorKind::Soft, SeparatorKind::Hard, SeparatorKind::Soft]);
+    /// ```
+    pub fn separator_set(&mut self, separators: SeparatorSet) -> &mut Self {
+        self.normalizer_option.classifier.separator_set = Some(separators);
+        self
+    }
+
+    /// Add the provided separators as `kind` to the configured separators, the default ones if none are configured,
+    /// instead of replacing the whole list with [`TokenizerBuilder::separators`].
+    ///
+    /// The separators are turned into a [`SeparatorSet`], so the options of [`TokenizerBuilder::separator_automaton`]
+    /// must be configured before.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{SeparatorKind, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.add_separators(&["/"], SeparatorKind::Hard);
+    /// let tokenizer = builder.build();
+    ///
+    /// let lemmas: Vec<_> = tokenizer.tokenize("red/blue green").map(|t| t.lemma().to_string()).collect();
+    /// assert_eq!(lemmas, ["red", "/", "blue", " ", "green"]);
+    /// ```
+    pub fn add_separators(&mut self, separators: &[&str], kind: SeparatorKind) -> &mut Self {
+        let separator_set = self.take_separator_set().with_separators(separators, kind);
+        self.separator_set(separator_set)
+    }
+
+    /// Remove the provided separators from the configured separators, the default ones if none are configured,
+    /// see [`TokenizerBuilder::add_separators`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.remove_separators(&["_", "-"]);
+    /// let tokenizer = builder.build();
+    ///
+    /// let lemmas: Vec<_> = tokenizer.tokenize("snake_case kebab-case").map(|t| t.lemma().to_string()).collect();
+    /// assert_eq!(lemmas, ["snake_case", " ", "kebab-case"]);
+    /// ```
+    pub fn remove_separators(&mut self, separators: &[&str]) -> &mut Self {
+        let separator_set = self.take_separator_set().without_separators(separators);
+        self.separator_set(separator_set)
+    }
+
+    /// Takes the configured separators as a [`SeparatorSet`], the default one if none are configured.
+    fn take_separator_set(&mut self) -> SeparatorSet {
+        let classifier = &mut self.normalizer_option.classifier;
+        match (classifier.separator_set.take(), classifier.separators.take(), self.aho_option) {
+            (Some(separator_set), ..) => separator_set,
+            (None, Some(separators), aho_option) => {
+                SeparatorSet::classified(separators, aho_option.unwrap_or_default())
+            }
+            (None, None, Some(aho_option)) => {
+                SeparatorSet::classified(DEFAULT_SEPARATORS, aho_option)
+            }
+            (None, None, None) => SeparatorSet::default(),
+        }
+    }
+
+    /// Configure the build options of the Aho-Corasick automaton searching the separators,
+    /// and the words of [`TokenizerBuilder::words_dict`] if any.
+    ///
+    /// The options apply to the default separators as well as to the ones set with [`TokenizerBuilder::separators`].
+    /// Changing the match kind changes the segmentation of the overlapping separators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aho_corasick::AhoCorasickKind;
+    /// use charabia::segmenter::AhoOption;
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.separator_automaton(AhoOption { kind: Some(AhoCorasickKind::DFA), ..Default::default() });
+    /// let tokenizer = builder.build();
+    ///
+    /// let output: Vec<_> = tokenizer.segment_str("The quick, brown fox").collect();
+    /// assert_eq!(output, ["The", " ", "quick", ", ", "brown", " ", "fox"]);
+    /// ```
+    pub fn separator_automaton(&mut self, option: AhoOption) -> &mut Self {
+        self.aho_option = Some(option);
+        self
+    }
+
+    /// Configure the terms that must never be splitted nor normalized.
+    ///
+    /// The occurences of these terms are found before any other segmentation,
+    /// even before splitting the text by script,
+    /// and are kept as is in a single token classified as `TokenKind::Word`.
+    /// An occurence is only kept when it is not surrounded by alphanumeric characters,
+    /// so the term "in" is not found in "main", nor in the texts written without spaces.
+    /// Unlike [`TokenizerBuilder::words_dict`], the protected terms are not normalized
+    /// and are not classified as stop words or separators.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - a slice of the terms to protect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    ///
+    /// let terms = ["C++", "i18n", "JIRA-123"];
+    /// builder.protected_terms(&terms);
+    ///
+    /// let tokenizer = builder.build();
+    ///
+    /// let words: Vec<_> = tokenizer
+    ///     .tokenize("Fixing JIRA-123: C++ i18n")
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(words, ["fixing", "JIRA-123", "C++", "i18n"]);
+    /// ```
+    pub fn protected_terms(&mut self, terms: &'tb [&'tb str]) -> &mut Self {
+        self.protected_terms = Some(terms);
+        self
+    }
+
+    /// Configure the regular expressions matching the terms that must never be splitted nor normalized,
+    /// like the ticket IDs.
+    ///
+    /// The matches are protected like the terms of [`TokenizerBuilder::protected_terms`],
+    /// the longest one being kept when a term and a pattern match at the same position.
+    /// The patterns must be valid regular expressions, see [`TokenizerBuilder::try_build`].
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - a slice of the regular expressions matching the terms to protect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    ///
+    /// let patterns = [r"[A-Z]+-\d+"];
+    /// builder.protected_patterns(&patterns);
+    ///
+    /// let tokenizer = builder.build();
+    ///
+    /// let words: Vec<_> = tokenizer
+    ///     .tokenize("Fixing JIRA-123 and CORE-42")
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(words, ["fixing", "JIRA-123", "and", "CORE-42"]);
+    /// ```
+    #[cfg(feature = "protected-patterns")]
+    pub fn protected_patterns(&mut self, patterns: &'tb [&'tb str]) -> &mut Self {
+        self.protected_patterns = Some(patterns);
+        self
+    }
+
     /// Enable or disable the creation of `char_map`.
     ///
+    /// The `char_map` is only needed to map the normalized lemmas back to the original text, for instance to highlight it,
+    /// it is disabled by default and no normalizer computes it in this case.
+    ///
     /// # Arguments
     ///
     /// * `create_char_map` - a `bool` that indicates whether a `char_map` should be created.
@@ -296,79 +766,1055 @@ impl<'tb, A: AsRef<[u8]>> TokenizerBuilder<'tb, A> {
         self
     }
 
-    /// Configure which languages can be used for which script
+    /// Enable or disable the query prefix mode.
+    ///
+    /// In this mode, the last word of the tokenized text is flagged as a potential prefix using [`Token::is_prefix`],
+    /// allowing search-as-you-type consumers to match it against longer words.
+    /// A text ending with a separator is considered complete and none of its tokens is flagged.
+    /// For CJK, a trailing single-character token is merged with the preceding word
+    /// because the segmentation of an incomplete word is not reliable.
     ///
     /// # Arguments
     ///
-    /// * `allow_list` - a `HashMap` of the selection of languages associated with a script to limit during autodetection.
-    pub fn allow_list(&mut self, allow_list: &'tb HashMap<Script, Vec<Language>>) -> &mut Self {
-        self.segmenter_option.allow_list = Some(allow_list);
+    /// * `query_prefix` - a `bool` that enable or disable the query prefix mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.query_prefix(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let prefixes: Vec<_> =
+    ///     tokenizer.tokenize("the quick bro").filter(|t| t.is_prefix).map(|t| t.lemma).collect();
+    /// assert_eq!(prefixes, ["bro"]);
+    /// ```
+    pub fn query_prefix(&mut self, query_prefix: bool) -> &mut Self {
+        self.normalizer_option.query_prefix = query_prefix;
         self
     }
 
-    /// Build the configurated `Tokenizer`.
-    pub fn build(&mut self) -> Tokenizer {
-        // If a custom list of separators or/and a custom list of words have been given,
-        // then an Aho-Corasick automaton is created to pre-segment the text during the tokenization process
-        // TODO: avoid recreating the automaton if nothing changed
-        match (self.normalizer_option.classifier.separators, self.words_dict) {
-            (Some(separators), None) => {
-                let aho = AhoCorasick::builder()
-                    .match_kind(MatchKind::LeftmostLongest)
-                    .build(separators)
-                    .unwrap();
-
-                self.segmenter_option.aho = Some(aho);
-            }
-            (separators, Some(words)) => {
-                // use the default separators' list if a custom words' list is given but no custom separators' list.
-                let separators = separators.unwrap_or(DEFAULT_SEPARATORS);
-                // merge both lists together and create the Aho-Corasick automaton.
-                let mut vec = Vec::with_capacity(separators.len() + words.len());
-                vec.extend_from_slice(words);
-                vec.extend_from_slice(separators);
-                let aho = AhoCorasick::builder()
-                    .match_kind(MatchKind::LeftmostLongest)
-                    .build(vec)
-                    .unwrap();
-
-                self.segmenter_option.aho = Some(aho);
-            }
-            // reset the state in case the builder is reused.
-            (None, None) => self.segmenter_option.aho = None,
-        }
-
-        Tokenizer {
-            normalizer_option: Cow::Borrowed(&self.normalizer_option),
-            segmenter_option: Cow::Borrowed(&self.segmenter_option),
-        }
-    }
-
-    /// Build the configurated `Tokenizer` consumming self.
+    /// Enable or disable the query phrase mode.
     ///
-    /// This method allows to drop the tokenizer builder without having to drop the Tokenizer itself.
-    pub fn into_tokenizer(mut self) -> Tokenizer<'tb> {
-        drop(self.build());
-
-        Tokenizer {
-            normalizer_option: Cow::Owned(self.normalizer_option),
-            segmenter_option: Cow::Owned(self.segmenter_option),
-        }
+    /// In this mode, the words and stop words inside double-quoted spans are grouped in phrases
+    /// by setting [`Token::phrase`] to the index of the phrase in the text,
+    /// the text inside the quotes is still segmented and normalized as usual.
+    /// Only quotes classified as separators delimit phrases, and an unclosed quote spans up to the end of the text.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_phrase` - a `bool` that enable or disable the query phrase mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.query_phrase(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let words: Vec<_> = tokenizer
+    ///     .tokenize("\"new york\" pizza")
+    ///     .filter(|t| t.is_word())
+    ///     .map(|t| (t.lemma, t.phrase))
+    ///     .collect();
+    /// assert_eq!(words, [("new".into(), Some(0)), ("york".into(), Some(0)), ("pizza".into(), None)]);
+    /// ```
+    pub fn query_phrase(&mut self, query_phrase: bool) -> &mut Self {
+        self.normalizer_option.query_phrase = query_phrase;
+        self
     }
-}
 
-impl Default for TokenizerBuilder<'_, Vec<u8>> {
-    fn default() -> Self {
-        Self::new()
+    /// Enable or disable the keeping of the Hungarian umlauts, "ö" and "ü" staying distinct from "o" and "u".
+    ///
+    /// The long vowels are always folded into their short counterparts,
+    /// so "ő" becomes "ö" when the umlauts are kept and "o" otherwise,
+    /// see [`HungarianNormalizer`](crate::normalizer::HungarianNormalizer).
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - a `bool` that indicates whether "ö" and "ü" should be kept distinct from "o" and "u".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{AllowList, Language, TokenizerBuilder};
+    ///
+    /// let allow_list = AllowList::new().languages([Language::Hun]);
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.allow_list(&allow_list).hungarian_keep_umlauts(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let words: Vec<_> = tokenizer.tokenize("tőr tór").map(|t| t.lemma).collect();
+    /// assert_eq!(words, ["tör", " ", "tor"]);
+    /// ```
+    #[cfg(feature = "hungarian")]
+    pub fn hungarian_keep_umlauts(&mut self, enabled: bool) -> &mut Self {
+        self.normalizer_option.hungarian_keep_umlauts = enabled;
+        self
     }
-}
-
-#[cfg(test)]
-mod test {
-    use fst::Set;
-    use quickcheck::quickcheck;
 
-    use crate::{Tokenize, TokenizerBuilder};
+    /// Configure the phonetic algorithm used to compute the phonetic keys of the words.
+    ///
+    /// The keys are returned by `Token::phonetic_keys`,
+    /// and replace the lemma if `replace_lemma` is set in the provided option.
+    /// The phonetic keys are only computed when the lossy normalization is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `phonetic` - a `PhoneticOption` containing the algorithm to use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{PhoneticAlgorithm, PhoneticOption, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.phonetic(PhoneticOption { algorithm: PhoneticAlgorithm::Soundex, replace_lemma: true });
+    /// let tokenizer = builder.build();
+    ///
+    /// let keys: Vec<_> = tokenizer.tokenize("Robert Rupert").map(|t| t.lemma).collect();
+    /// assert_eq!(keys, ["R163", " ", "R163"]);
+    /// ```
+    #[cfg(feature = "phonetic")]
+    pub fn phonetic(&mut self, phonetic: crate::normalizer::PhoneticOption) -> &mut Self {
+        self.normalizer_option.phonetic = Some(phonetic);
+        self
+    }
+
+    /// Enable or disable the stemming of the words, disabled by default.
+    ///
+    /// Only the words of the languages enabled by the `stemming-*` features are stemmed,
+    /// so enabling one of these features in a dependency doesn't change the tokens of the other tokenizers,
+    /// see `StemmingNormalizer` for more details.
+    /// The words are only stemmed when the lossy normalization is enabled and their language is known.
+    ///
+    /// # Arguments
+    ///
+    /// * `stemming` - a `bool` that indicates whether the words should be stemmed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{Language, Script, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.default_language(Script::Latin, Language::Eng).stemming(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let token = tokenizer.tokenize("running").next().unwrap();
+    /// # #[cfg(feature = "stemming-english")]
+    /// assert_eq!(token.lemma(), "run");
+    /// ```
+    pub fn stemming(&mut self, stemming: bool) -> &mut Self {
+        self.normalizer_option.stemming = stemming;
+        self
+    }
+
+    /// Enable or disable the folding of the Esperanto h-system, writing 'ĉ' as "ch", disabled by default.
+    ///
+    /// The h-system is ambiguous, an 'h' following 'c', 'g', 'h', 'j' or 's' also starts
+    /// the next root of the compound words like "flughaveno",
+    /// so it should only be folded when the indexed texts are known to use it,
+    /// see `EsperantoNormalizer` for more details.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - a `bool` that indicates whether the h-system should be folded into the x-system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{AllowList, Language, TokenizerBuilder};
+    ///
+    /// let allow_list = AllowList::new().languages([Language::Epo]);
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.allow_list(&allow_list).esperanto_h_system(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let words: Vec<_> = tokenizer.tokenize("ĉu chu").map(|t| t.lemma).collect();
+    /// # #[cfg(feature = "esperanto")]
+    /// assert_eq!(words, ["cxu", " ", "cxu"]);
+    /// ```
+    pub fn esperanto_h_system(&mut self, enabled: bool) -> &mut Self {
+        self.normalizer_option.esperanto_h_system = enabled;
+        self
+    }
+
+    /// Enable or disable the computation of the skeleton of the words.
+    ///
+    /// The skeleton is returned by `Token::skeleton` and doesn't replace the lemma,
+    /// see [`SkeletonNormalizer`](crate::normalizer::SkeletonNormalizer) for more details.
+    /// The skeleton is only computed when the lossy normalization is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `skeleton` - a `bool` that indicates whether the skeleton should be computed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.skeleton(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let skeletons: Vec<_> =
+    ///     tokenizer.tokenize("Philippe Filipe").filter_map(|t| t.skeleton().map(str::to_string)).collect();
+    /// assert_eq!(skeletons, ["filipe", "filipe"]);
+    /// ```
+    pub fn skeleton(&mut self, skeleton: bool) -> &mut Self {
+        self.normalizer_option.skeleton = skeleton;
+        self
+    }
+
+    /// Enable or disable the romanization of the Greek, Cyrillic and Hangul words.
+    ///
+    /// The romanization is returned by `Token::romanized` and doesn't replace the lemma,
+    /// see [`RomanizationNormalizer`](crate::normalizer::RomanizationNormalizer) for the used transliterations.
+    /// The romanization is only computed when the lossy normalization is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `romanize` - a `bool` that indicates whether the romanization should be computed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.romanize(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let romanized: Vec<_> = tokenizer.tokenize("Αθήνα Москва").filter_map(|t| t.romanized().map(str::to_string)).collect();
+    /// assert_eq!(romanized, ["athina", "moskva"]);
+    /// ```
+    pub fn romanize(&mut self, romanize: bool) -> &mut Self {
+        self.normalizer_option.romanize = romanize;
+        self
+    }
+
+    /// Enable or disable the replacement of the emoji by their name.
+    ///
+    /// The skin tone and ZWJ variants are folded to their base emoji before being named,
+    /// see [`EmojiNormalizer`](crate::normalizer::EmojiNormalizer) for more details.
+    /// The emoji are only named when the lossy normalization is enabled,
+    /// the tokens only made of emoji keep the [`TokenKind::Emoji`](crate::TokenKind::Emoji) kind once named.
+    ///
+    /// # Arguments
+    ///
+    /// * `emoji_names` - a `bool` that indicates whether the emoji should be replaced by their name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.emoji_names(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let words: Vec<_> = tokenizer
+    ///     .tokenize("I ☕ 👍🏽")
+    ///     .filter(|t| t.is_word() || t.is_emoji())
+    ///     .map(|t| t.lemma)
+    ///     .collect();
+    /// assert_eq!(words, ["i", "coffee", "+1"]);
+    /// ```
+    pub fn emoji_names(&mut self, emoji_names: bool) -> &mut Self {
+        self.normalizer_option.emoji_names = emoji_names;
+        self
+    }
+
+    /// Enable or disable the conversion of the CJK numerals to ASCII digits.
+    ///
+    /// The converted lemma is returned by `Token::numeric` and doesn't replace the lemma,
+    /// so that "第三章" can be matched with "第3章",
+    /// see [`CjkNumeralNormalizer`](crate::normalizer::CjkNumeralNormalizer) for the converted numerals.
+    /// The numerals are only converted when the lossy normalization is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `cjk_numerals` - a `bool` that indicates whether the CJK numerals should be converted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.cjk_numerals(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let numeric: Vec<_> = tokenizer.tokenize("二十").filter_map(|t| t.numeric().map(str::to_string)).collect();
+    /// assert_eq!(numeric, ["20"]);
+    /// ```
+    pub fn cjk_numerals(&mut self, cjk_numerals: bool) -> &mut Self {
+        self.normalizer_option.cjk_numerals = cjk_numerals;
+        self
+    }
+
+    /// Configure the handling of the Japanese prolonged sound mark 'ー', kept by default.
+    ///
+    /// The mark can be stripped, so "コンピューター" matches "コンピュータ",
+    /// or folded to the vowel of the preceding kana, so "コーヒー" matches "コオヒイ",
+    /// see [`JapaneseMarksNormalizer`](crate::normalizer::JapaneseMarksNormalizer).
+    /// The mark is only handled when the lossy normalization is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `prolonged_sound_mark` - the [`ProlongedSoundMark`](crate::ProlongedSoundMark) handling of the mark.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{ProlongedSoundMark, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.prolonged_sound_mark(ProlongedSoundMark::Strip);
+    /// let tokenizer = builder.build();
+    ///
+    /// let lemmas = |text| tokenizer.tokenize(text).map(|t| t.lemma).collect::<String>();
+    /// assert_eq!(lemmas("コンピューター"), lemmas("コンピュータ"));
+    /// ```
+    pub fn prolonged_sound_mark(&mut self, prolonged_sound_mark: ProlongedSoundMark) -> &mut Self {
+        self.normalizer_option.prolonged_sound_mark = prolonged_sound_mark;
+        self
+    }
+
+    /// Enable or disable the keeping of the original lemma of the tokens in [`Token::original`](crate::Token::original).
+    ///
+    /// The original lemma is the surface form of the token before its normalization,
+    /// allowing to display or exact-match it without slicing the original text again.
+    /// It is borrowed from the original text, so keeping it doesn't allocate for most tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep_original` - a `bool` that indicates whether the original lemma should be kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.keep_original(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let token = tokenizer.tokenize("Thé").next().unwrap();
+    /// assert_eq!((token.lemma(), token.original_lemma()), ("the", Some("Thé")));
+    /// ```
+    pub fn keep_original(&mut self, keep_original: bool) -> &mut Self {
+        self.normalizer_option.keep_original = keep_original;
+        self
+    }
+
+    /// Configure the tokens emitted for a text without any word,
+    /// only made of separators and whitespaces, like "  " or " - ", see [`BlankInput`].
+    ///
+    /// By default, the tokens of such a text are emitted as for any other text.
+    /// An empty text never gives any token.
+    ///
+    /// # Arguments
+    ///
+    /// * `blank_input` - a [`BlankInput`] that indicates which tokens are emitted for a blank text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{BlankInput, SeparatorKind, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.blank_input(BlankInput::SingleSeparator);
+    /// let tokenizer = builder.build();
+    ///
+    /// let tokens: Vec<_> = tokenizer.tokenize(" - . ").collect();
+    /// assert_eq!(tokens.len(), 1);
+    /// assert_eq!((tokens[0].lemma(), tokens[0].byte_end), (" - . ", 5));
+    /// assert_eq!(tokens[0].separator_kind(), Some(SeparatorKind::Hard));
+    ///
+    /// // the texts containing a word are not changed.
+    /// assert_eq!(tokenizer.tokenize(" - a").count(), 4);
+    ///
+    /// builder.blank_input(BlankInput::Skip);
+    /// assert_eq!(builder.build().tokenize(" \t ").count(), 0);
+    /// ```
+    pub fn blank_input(&mut self, blank_input: BlankInput) -> &mut Self {
+        self.normalizer_option.blank_input = blank_input;
+        self
+    }
+
+    /// Configure which languages can be used for which script
+    ///
+    /// When a script is limited to a single language, the language detection is skipped for this script
+    /// and its texts are directly assigned to this language, like with [`Self::default_language`].
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_list` - a `HashMap` of the selection of languages associated with a script to limit during autodetection.
+    pub fn allow_list(&mut self, allow_list: &'tb HashMap<Script, Vec<Language>>) -> &mut Self {
+        self.segmenter_option.allow_list = Some(allow_list);
+        self
+    }
+
+    /// Configure the language assigned to any text of the provided script.
+    ///
+    /// The language detection is skipped for this script and the segmenter and normalizers of the provided language are used,
+    /// for instance, treating any `Script::Cj` text as Japanese for a Japan-only deployment.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - the `Script` to assign a language to.
+    /// * `language` - the `Language` used for any text of this script.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{Language, Script, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.default_language(Script::Latin, Language::Fra);
+    /// let tokenizer = builder.build();
+    ///
+    /// let token = tokenizer.tokenize("Bonjour").next().unwrap();
+    /// assert_eq!(token.language, Some(Language::Fra));
+    /// ```
+    pub fn default_language(&mut self, script: Script, language: Language) -> &mut Self {
+        self.segmenter_option.default_languages.insert(script, language);
+        self
+    }
+
+    /// Configure the segmenter used for the provided script and language instead of the default one.
+    ///
+    /// This allows several tokenizers of the same process to use different dictionaries,
+    /// the segmenter is shared between the tokenizers built by this builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - the `Script` segmented by the segmenter.
+    /// * `language` - the `Language` segmented by the segmenter, `Language::Other` meaning any language of the script.
+    /// * `segmenter` - the `Segmenter` to use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use charabia::segmenter::LatinSegmenter;
+    /// use charabia::{Language, Script, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.segmenter(Script::Latin, Language::Other, Arc::new(LatinSegmenter));
+    /// let tokenizer = builder.build();
+    ///
+    /// let lemmas: Vec<_> = tokenizer.tokenize("Hello world").map(|t| t.lemma().to_string()).collect();
+    /// assert_eq!(lemmas, ["hello", " ", "world"]);
+    /// ```
+    pub fn segmenter(
+        &mut self,
+        script: Script,
+        language: Language,
+        segmenter: Arc<dyn Segmenter>,
+    ) -> &mut Self {
+        self.segmenter_option.segmenters.insert((script, language), segmenter);
+        self
+    }
+
+    /// Enable or disable the segmentation of the Latin script on the Unicode word boundaries,
+    /// using [`LatinWordBoundarySegmenter`](crate::segmenter::LatinWordBoundarySegmenter) instead of splitting the text on the separators.
+    ///
+    /// This keeps the punctuation inside words, like "can't", or inside numbers, like "32.3", in a single token.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - a `bool` that indicates whether the Unicode word boundaries should be used for the Latin script.
+    #[cfg(feature = "latin-word-boundaries")]
+    pub fn latin_word_boundaries(&mut self, enabled: bool) -> &mut Self {
+        let key = (Script::Latin, Language::Other);
+        if enabled {
+            let segmenter = Arc::new(crate::segmenter::LatinWordBoundarySegmenter);
+            self.segmenter_option.segmenters.insert(key, segmenter);
+        } else if self
+            .segmenter_option
+            .segmenters
+            .get(&key)
+            .is_some_and(|segmenter| segmenter.name() == "LatinWordBoundarySegmenter")
+        {
+            self.segmenter_option.segmenters.remove(&key);
+        }
+        self
+    }
+
+    /// Enable or disable the splitting of the `ال` article from the Arabic words, enabled by default.
+    ///
+    /// The article is split so that `الشجرة` (the tree) is found when searching `شجرة` (tree),
+    /// but the heuristic also splits the proper nouns starting with `ال`, like `البانيا` (Albania).
+    /// When disabled, the [`ArabicKeepArticleSegmenter`](crate::segmenter::ArabicKeepArticleSegmenter) is used
+    /// instead of the [`ArabicSegmenter`](crate::segmenter::ArabicSegmenter).
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - a `bool` that indicates whether the article should be split from the Arabic words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{AllowList, Language, TokenizerBuilder};
+    ///
+    /// let allow_list = AllowList::new().languages([Language::Ara]);
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.allow_list(&allow_list).arabic_article_splitting(false);
+    /// let tokenizer = builder.build();
+    ///
+    /// assert_eq!(tokenizer.segment_str("البانيا").collect::<Vec<_>>(), ["البانيا"]);
+    /// ```
+    pub fn arabic_article_splitting(&mut self, enabled: bool) -> &mut Self {
+        let key = (Script::Arabic, Language::Ara);
+        if !enabled {
+            let segmenter = Arc::new(crate::segmenter::ArabicKeepArticleSegmenter);
+            self.segmenter_option.segmenters.insert(key, segmenter);
+        } else if self
+            .segmenter_option
+            .segmenters
+            .get(&key)
+            .is_some_and(|segmenter| segmenter.name() == "ArabicKeepArticleSegmenter")
+        {
+            self.segmenter_option.segmenters.remove(&key);
+        }
+        self
+    }
+
+    /// Enable or disable the classification of the tokens.
+    ///
+    /// When the classification is skipped, the tokens keep the [`TokenKind::Unknown`](crate::TokenKind::Unknown) kind,
+    /// saving the separator and stop word lookups for consumers doing their own classification.
+    /// The features relying on the kind of the tokens, like the query prefix and phrase modes,
+    /// the stemming, and the phonetic keys, then consider every token as a non-word.
+    ///
+    /// # Arguments
+    ///
+    /// * `skip_classification` - a `bool` that indicates whether the classification should be skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{TokenKind, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.skip_classification(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// assert!(tokenizer.tokenize("Hello, world").all(|t| t.kind == TokenKind::Unknown));
+    /// ```
+    pub fn skip_classification(&mut self, skip_classification: bool) -> &mut Self {
+        self.normalizer_option.skip_classification = skip_classification;
+        self
+    }
+
+    /// Enable or disable the segmentation on whitespaces only.
+    ///
+    /// The text is split on whitespaces by the [`WhitespaceSegmenter`](crate::segmenter::WhitespaceSegmenter),
+    /// skipping the script and language detection and the search of the separators for maximum throughput,
+    /// the tokens are then assigned to [`Script::Other`] without language,
+    /// so the normalizers specialized for a script, like the lowercasing, are not applied.
+    /// This fits pipelines that only need the words and their offsets, like log search or code tokens.
+    /// The protected terms are still never split.
+    ///
+    /// # Arguments
+    ///
+    /// * `whitespace_only` - a `bool` that indicates whether the text should only be split on whitespaces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.whitespace_segmentation(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let words: Vec<_> =
+    ///     tokenizer.tokenize("GET /index.html 200").filter(|t| t.is_word()).map(|t| t.lemma).collect();
+    /// assert_eq!(words, ["GET", "/index.html", "200"]);
+    /// ```
+    pub fn whitespace_segmentation(&mut self, whitespace_only: bool) -> &mut Self {
+        self.segmenter_option.whitespace_only = whitespace_only;
+        self
+    }
+
+    /// Enable or disable the splitting of the tokens at the script transitions inside them.
+    ///
+    /// The text is already split by script before being segmented,
+    /// but a token can still mix several scripts when the text is segmented as a whole,
+    /// like with [`Self::whitespace_segmentation`] or a custom segmenter.
+    /// Each part of a split token is assigned to its own [`Script`], without language if it differs from the segmented text,
+    /// the chars without script, like the digits, staying with the preceding chars.
+    /// The protected terms are never split.
+    ///
+    /// # Arguments
+    ///
+    /// * `split_mixed_scripts` - a `bool` that indicates whether the tokens should be split at the script transitions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.whitespace_segmentation(true).split_mixed_scripts(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let words: Vec<_> = tokenizer.tokenize("iPhone15漢字").map(|t| t.lemma).collect();
+    /// assert_eq!(words, ["iphone15", "漢字"]);
+    /// ```
+    pub fn split_mixed_scripts(&mut self, split_mixed_scripts: bool) -> &mut Self {
+        self.segmenter_option.split_mixed_scripts = split_mixed_scripts;
+        self
+    }
+
+    /// Mark the tokens that weren't found in the dictionary of their segmenter using [`Token::guessed`],
+    /// like the unknown words of lindera or the chars left alone by the Chinese, Thai or Khmer dictionaries,
+    /// so the ranking can trust them less than the dictionary words.
+    ///
+    /// The segmenters not using a dictionary never mark their tokens.
+    /// Looking for the Chinese words in the dictionary slows down their segmentation.
+    ///
+    /// # Arguments
+    ///
+    /// * `mark_guessed` - a `bool` that indicates whether the guessed tokens should be marked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.mark_guessed(true);
+    /// let tokenizer = builder.build();
+    ///
+    /// let guessed: Vec<_> = tokenizer.tokenize("hello 人工智能").map(|t| t.guessed).collect();
+    /// assert_eq!(guessed, [false, false, false]);
+    /// ```
+    pub fn mark_guessed(&mut self, mark_guessed: bool) -> &mut Self {
+        self.segmenter_option.mark_guessed = mark_guessed;
+        self
+    }
+
+    /// Keep the cost computed by the segmenter of each token in [`Token::cost`],
+    /// so the dubious segmentations can be filtered by their confidence.
+    ///
+    /// Only the segmenters computing a lattice of the text, through [`Segmenter::segment_str_with_origin`],
+    /// provide a cost, the lindera tokens currently not exposing theirs.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment_costs` - a `bool` that indicates whether the costs of the tokens should be kept.
+    pub fn segment_costs(&mut self, segment_costs: bool) -> &mut Self {
+        self.segmenter_option.segment_costs = segment_costs;
+        self
+    }
+
+    /// Configure a cache of the languages detected in the short texts, shared by all the tokenizations.
+    ///
+    /// The language detection dominates the cost of tokenizing short texts, like tags or SKUs,
+    /// which are often repeated when indexing millions of fields.
+    /// The cache keeps approximately the `capacity` most recently used texts, see [`DetectionCache`].
+    /// A `capacity` of 0 disables the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{Tokenize, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.detection_cache(1024);
+    /// let tokenizer = builder.build();
+    ///
+    /// // the cache doesn't change the tokenization.
+    /// for tag in ["東京の塔", "東京の塔", "渋谷"] {
+    ///     let cached: Vec<_> = tokenizer.tokenize(tag).collect();
+    ///     let tokens: Vec<_> = tag.tokenize().collect();
+    ///     assert_eq!(cached, tokens);
+    /// }
+    /// ```
+    pub fn detection_cache(&mut self, capacity: usize) -> &mut Self {
+        self.segmenter_option.detection_cache =
+            (capacity > 0).then(|| Arc::new(DetectionCache::new(capacity)));
+        self
+    }
+
+    /// Configure the observer receiving the statistics of each tokenized text,
+    /// like its number of words or the time spent in the segmentation, see the [`metrics`](crate::metrics) module.
+    ///
+    /// The observer is called when the iterator returned by [`Tokenizer::tokenize`] is dropped.
+    /// The statistics are only measured when an observer is set.
+    pub fn observer(&mut self, observer: Arc<dyn TokenizerObserver>) -> &mut Self {
+        self.segmenter_option.observer = Some(observer);
+        self
+    }
+
+    /// Configure the maximum number of bytes of a text to tokenize.
+    ///
+    /// The bytes after the limit are ignored, the limit is lowered to the previous char boundary
+    /// which is not followed by a combining mark, so a vowel sign is never separated from its consonant,
+    /// nor surrounded by alphanumeric characters, so a word cut by the limit is dropped instead of being indexed
+    /// as a shorter one, and [`NormalizedTokenIter::is_truncated`] returns true once the iterator is exhausted.
+    /// This bounds the work done on adversarial inputs, like megabyte-long texts without separators.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_input_bytes` - the maximum number of bytes to tokenize.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.max_input_bytes(9);
+    /// let tokenizer = builder.build();
+    ///
+    /// let mut tokens = tokenizer.tokenize("The quick brown fox");
+    /// let lemmas: Vec<_> = tokens.by_ref().map(|t| t.lemma().to_string()).collect();
+    /// assert_eq!(lemmas, ["the", " ", "quick"]);
+    /// assert!(tokens.is_truncated());
+    /// ```
+    pub fn max_input_bytes(&mut self, max_input_bytes: usize) -> &mut Self {
+        self.segmenter_option.max_input_bytes = Some(max_input_bytes);
+        self
+    }
+
+    /// Configure the maximum number of tokens to create from a text.
+    ///
+    /// The tokenization stops once the limit is reached
+    /// and [`NormalizedTokenIter::is_truncated`] returns true if some text was left.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_tokens` - the maximum number of tokens, including the separators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.max_tokens(3);
+    /// let tokenizer = builder.build();
+    ///
+    /// let mut tokens = tokenizer.tokenize("The quick brown fox");
+    /// assert_eq!(tokens.by_ref().count(), 3);
+    /// assert!(tokens.is_truncated());
+    /// ```
+    pub fn max_tokens(&mut self, max_tokens: usize) -> &mut Self {
+        self.segmenter_option.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Build the configurated `Tokenizer`, checking that the configuration can be honored at tokenize time
+    /// instead of silently ignoring the options that can't be used, like [`Self::build`] does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::separators::SeparatorSet;
+    /// use charabia::{BuildError, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.separator_set(SeparatorSet::new(&[" "], &["."])).separators(&[" ", "."]);
+    /// assert_eq!(builder.try_build().err(), Some(BuildError::ConflictingSeparators));
+    /// ```
+    pub fn try_build(&mut self) -> Result<Tokenizer<'tb>, BuildError> {
+        self.validate()?;
+        Ok(self.build())
+    }
+
+    /// Checks the current configuration, see [`Self::try_build`].
+    fn validate(&self) -> Result<(), BuildError> {
+        let classifier = &self.normalizer_option.classifier;
+        if classifier.separator_set.is_some() && classifier.separators.is_some() {
+            return Err(BuildError::ConflictingSeparators);
+        }
+
+        #[cfg(feature = "protected-patterns")]
+        if let Some(pattern) =
+            self.protected_patterns.into_iter().flatten().find(|p| regex::Regex::new(p).is_err())
+        {
+            return Err(BuildError::InvalidProtectedPattern(pattern.to_string()));
+        }
+
+        let segmenter_option = &self.segmenter_option;
+        if segmenter_option.whitespace_only {
+            let ignored = [
+                ("segmenter", !segmenter_option.segmenters.is_empty()),
+                ("words_dict", self.words_dict.is_some()),
+                ("allow_list", segmenter_option.allow_list.is_some()),
+                ("default_language", !segmenter_option.default_languages.is_empty()),
+            ];
+            if let Some((option, _)) = ignored.into_iter().find(|(_, set)| *set) {
+                return Err(BuildError::IgnoredByWhitespaceSegmentation(option));
+            }
+        }
+
+        // the configured languages are checked in a deterministic order.
+        let mut allow_list: Vec<_> =
+            segmenter_option.allow_list.iter().flat_map(|a| a.iter()).collect();
+        allow_list.sort_by_key(|(script, _)| script.name());
+        if let Some((script, _)) = allow_list.iter().find(|(_, languages)| languages.is_empty()) {
+            return Err(BuildError::EmptyAllowList(**script));
+        }
+        let mut configured: Vec<_> = allow_list
+            .iter()
+            .flat_map(|(script, languages)| languages.iter().map(|language| (**script, *language)))
+            .chain(segmenter_option.default_languages.iter().map(|(s, l)| (*s, *l)))
+            .collect();
+        configured.sort_by_key(|(script, language)| (script.name(), language.name()));
+        for (script, language) in configured {
+            if language != Language::Other && !language.scripts().contains(&script) {
+                return Err(BuildError::LanguageNotInScript { script, language });
+            }
+            if let Some(feature) = disabled_feature(language) {
+                return Err(BuildError::DisabledFeature { language, feature });
+            }
+        }
+
+        for (script, language) in runtime_dictionaries() {
+            let segmenters = &segmenter_option.segmenters;
+            let provided = segmenters.contains_key(&(script, language))
+                || segmenters.contains_key(&(script, Language::Other));
+            // the segmenter is only needed when the language is assigned to or allowed for the script.
+            let selected = segmenter_option.default_languages.get(&script) == Some(&language)
+                || segmenter_option
+                    .allow_list
+                    .and_then(|allow_list| allow_list.get(&script))
+                    .is_some_and(|languages| languages.contains(&language));
+            if !provided && selected {
+                return Err(BuildError::MissingDictionary { script, language });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build the configurated `Tokenizer`.
+    pub fn build(&mut self) -> Tokenizer<'tb> {
+        // If a custom list of separators or/and a custom list of words have been given,
+        // then an Aho-Corasick automaton is created to pre-segment the text during the tokenization process
+        // TODO: avoid recreating the automaton if nothing changed
+        let aho_option = self.aho_option.unwrap_or_default();
+        let separator_set = self.normalizer_option.classifier.separator_set.as_ref();
+        match (separator_set, self.normalizer_option.classifier.separators, self.words_dict) {
+            // the compiled automaton of the set is shared.
+            (Some(separator_set), _, None) => {
+                self.segmenter_option.separators(separator_set);
+            }
+            (None, Some(separators), None) => {
+                self.segmenter_option.aho = Some(aho_option.build(separators));
+                self.segmenter_option.separator_prefilter = SeparatorPrefilter::new(separators);
+            }
+            (separator_set, separators, Some(words)) => {
+                // use the default separators' list if a custom words' list is given but no custom separators' list.
+                let separators: Vec<_> = match separator_set {
+                    Some(separator_set) => separator_set.iter().collect(),
+                    None => separators.unwrap_or(DEFAULT_SEPARATORS).to_vec(),
+                };
+                let aho_option = separator_set.map_or(aho_option, SeparatorSet::option);
+                // merge both lists together and create the Aho-Corasick automaton.
+                let mut vec = Vec::with_capacity(separators.len() + words.len());
+                vec.extend_from_slice(words);
+                vec.extend_from_slice(&separators);
+                self.segmenter_option.aho = Some(aho_option.build(&vec));
+                self.segmenter_option.separator_prefilter = SeparatorPrefilter::new(vec);
+            }
+            // the default separators have their own automaton if they are not searched with the default options.
+            (None, None, None) => {
+                self.segmenter_option.aho =
+                    self.aho_option.map(|option| option.build(DEFAULT_SEPARATORS));
+                self.segmenter_option.separator_prefilter =
+                    self.aho_option.map(|_| DEFAULT_SEPARATOR_PREFILTER.clone());
+            }
+        }
+
+        self.segmenter_option.protected_aho = self.protected_terms.map(|terms| {
+            AhoCorasick::builder().match_kind(MatchKind::LeftmostLongest).build(terms).unwrap()
+        });
+        #[cfg(feature = "protected-patterns")]
+        {
+            self.segmenter_option.protected_patterns = self.protected_patterns.map(|patterns| {
+                let alternatives: Vec<_> = patterns.iter().map(|p| format!("(?:{p})")).collect();
+                regex::Regex::new(&alternatives.join("|")).expect("invalid protected pattern")
+            });
+        }
+
+        let mut segmenter_option = self.segmenter_option.clone();
+        segmenter_option.select_urdu_segmenter();
+
+        Tokenizer {
+            normalizer_option: Arc::new(self.normalizer_option.clone()),
+            lossless_normalizer_option: Arc::new(NormalizerOption {
+                lossy: false,
+                ..self.normalizer_option.clone()
+            }),
+            segmenter_option: Arc::new(segmenter_option),
+            fingerprint: self.fingerprint(),
+        }
+    }
+
+    /// Computes the fingerprint of the current configuration, see [`Tokenizer::fingerprint`].
+    fn fingerprint(&self) -> u64 {
+        let mut fingerprinter = Fingerprinter::new();
+
+        let normalizer_option = &self.normalizer_option;
+        fingerprinter
+            .write_strs(normalizer_option.classifier.separators)
+            .write_bool(normalizer_option.classifier.separator_set.is_some());
+        if let Some(separator_set) = &normalizer_option.classifier.separator_set {
+            let soft: Vec<_> = separator_set.soft().iter().map(String::as_str).collect();
+            let hard: Vec<_> = separator_set.hard().iter().map(String::as_str).collect();
+            fingerprinter
+                .write_strs(Some(&soft))
+                .write_strs(Some(&hard))
+                .write_str(match_kind_name(separator_set.option().match_kind));
+        }
+        fingerprinter
+            .write_strs(self.words_dict)
+            .write_strs(self.protected_terms)
+            .write_bytes(self.stop_words.map_or(&[], |sw| sw.as_fst().as_bytes()))
+            .write_bool(normalizer_option.create_char_map)
+            .write_bool(normalizer_option.lossy)
+            .write_bool(normalizer_option.query_prefix)
+            .write_bool(normalizer_option.query_phrase)
+            .write_bool(normalizer_option.skip_classification)
+            .write_bool(normalizer_option.skeleton)
+            .write_bool(normalizer_option.romanize)
+            .write_bool(normalizer_option.emoji_names)
+            .write_bool(normalizer_option.cjk_numerals)
+            .write_str(match normalizer_option.prolonged_sound_mark {
+                ProlongedSoundMark::Keep => "keep",
+                ProlongedSoundMark::Strip => "strip",
+                ProlongedSoundMark::Fold => "fold",
+            })
+            .write_bool(normalizer_option.keep_original)
+            .write_str(match normalizer_option.blank_input {
+                BlankInput::Keep => "keep",
+                BlankInput::Skip => "skip",
+                BlankInput::SingleSeparator => "single-separator",
+            })
+            .write_bool(normalizer_option.stemming)
+            .write_bool(normalizer_option.esperanto_h_system)
+            .write_str(match_kind_name(self.aho_option.unwrap_or_default().match_kind));
+        #[cfg(feature = "phonetic")]
+        {
+            use crate::normalizer::PhoneticAlgorithm;
+
+            let phonetic = normalizer_option.phonetic;
+            fingerprinter.write_bool(phonetic.is_some());
+            if let Some(phonetic) = phonetic {
+                fingerprinter
+                    .write_str(match phonetic.algorithm {
+                        PhoneticAlgorithm::Soundex => "soundex",
+                        PhoneticAlgorithm::DoubleMetaphone => "double-metaphone",
+                        PhoneticAlgorithm::Cologne => "cologne",
+                    })
+                    .write_bool(phonetic.replace_lemma);
+            }
+        }
+        #[cfg(feature = "hungarian")]
+        fingerprinter.write_bool(normalizer_option.hungarian_keep_umlauts);
+        #[cfg(feature = "protected-patterns")]
+        fingerprinter.write_strs(self.protected_patterns);
+
+        let segmenter_option = &self.segmenter_option;
+        for limit in [segmenter_option.max_input_bytes, segmenter_option.max_tokens] {
+            fingerprinter
+                .write_bool(limit.is_some())
+                .write_bytes(&(limit.unwrap_or(0) as u64).to_le_bytes());
+        }
+        fingerprinter
+            .write_bool(segmenter_option.whitespace_only)
+            .write_bool(segmenter_option.split_mixed_scripts)
+            .write_bool(segmenter_option.mark_guessed)
+            .write_bool(segmenter_option.segment_costs);
+
+        let mut default_languages: Vec<_> = segmenter_option
+            .default_languages
+            .iter()
+            .map(|(script, language)| (script.name(), language.name()))
+            .collect();
+        default_languages.sort_unstable();
+        for (script, language) in default_languages {
+            fingerprinter.write_str(script).write_str(language);
+        }
+
+        let mut allow_list: Vec<_> = segmenter_option
+            .allow_list
+            .iter()
+            .flat_map(|allow_list| allow_list.iter())
+            .map(|(script, languages)| {
+                let mut languages: Vec<_> = languages.iter().map(Language::name).collect();
+                languages.sort_unstable();
+                (script.name(), languages)
+            })
+            .collect();
+        allow_list.sort_unstable();
+        fingerprinter.write_bool(segmenter_option.allow_list.is_some());
+        for (script, languages) in allow_list {
+            fingerprinter.write_str(script).write_strs(Some(&languages));
+        }
+
+        for ((script, language), segmenter) in effective_segmenters(&segmenter_option.segmenters) {
+            fingerprinter
+                .write_str(script.name())
+                .write_str(language.name())
+                .write_str(segmenter.name());
+            for dictionary in segmenter.dictionaries() {
+                fingerprinter
+                    .write_str(dictionary.name)
+                    .write_str(dictionary.version.unwrap_or_default())
+                    .write_str(dictionary.source)
+                    .write_bytes(&dictionary.checksum.unwrap_or_default().to_le_bytes());
+            }
+        }
+
+        fingerprinter.finish()
+    }
+
+    /// Build the configurated `Tokenizer` consumming self.
+    ///
+    /// This method allows to drop the tokenizer builder without having to drop the Tokenizer itself.
+    pub fn into_tokenizer(mut self) -> Tokenizer<'tb> {
+        self.build()
+    }
+}
+
+impl Default for TokenizerBuilder<'_, Vec<u8>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the name of the match kind hashed in the fingerprint,
+/// the `Debug` output of the types not being stable across versions.
+fn match_kind_name(match_kind: MatchKind) -> &'static str {
+    match match_kind {
+        MatchKind::Standard => "standard",
+        MatchKind::LeftmostFirst => "leftmost-first",
+        MatchKind::LeftmostLongest => "leftmost-longest",
+        _ => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use fst::Set;
+    use quickcheck::quickcheck;
+
+    use crate::{Language, Script, Tokenize, TokenizerBuilder};
 
     #[test]
     fn check_lifetimes() {
@@ -400,6 +1846,576 @@ mod test {
         assert_eq!(tokens.iter().last().map(|t| t.lemma()), Some("."));
     }
 
+    #[test]
+    fn default_language() {
+        let mut builder = TokenizerBuilder::default();
+        builder.default_language(Script::Cj, Language::Jpn);
+        let tokenizer = builder.build();
+
+        let tokens: Vec<_> = tokenizer.tokenize("尊嚴").collect();
+        assert!(tokens.iter().all(|t| t.script == Script::Cj && t.language == Some(Language::Jpn)));
+        // the chinese normalizer is not applied on japanese text.
+        assert_eq!(tokens.iter().map(|t| t.lemma()).collect::<String>(), "尊嚴");
+
+        let tokens: Vec<_> = tokenizer.tokenize("Hello").collect();
+        assert_eq!(tokens[0].language, None);
+    }
+
+    #[test]
+    fn query_prefix() {
+        let mut builder = TokenizerBuilder::default();
+        builder.query_prefix(true);
+        let tokenizer = builder.build();
+
+        let tokens: Vec<_> = tokenizer.tokenize("The quick bro").collect();
+        let prefixes: Vec<_> = tokens.iter().filter(|t| t.is_prefix).map(|t| t.lemma()).collect();
+        assert_eq!(prefixes, ["bro"]);
+        assert_eq!(tokens.len(), 5);
+
+        // a text ending with a separator is complete.
+        assert!(tokenizer.tokenize("The quick brown ").all(|t| !t.is_prefix));
+        assert!(tokenizer.tokenize("").next().is_none());
+
+        // the prefix mode is disabled by default.
+        assert!(TokenizerBuilder::default()
+            .build()
+            .tokenize("The quick bro")
+            .all(|t| !t.is_prefix));
+    }
+
+    #[test]
+    fn query_phrase() {
+        let mut builder = TokenizerBuilder::default();
+        builder.query_phrase(true).query_prefix(true);
+        let tokenizer = builder.build();
+
+        let text = "“big apple” \"new york\" pizza \"hot do";
+        let words: Vec<_> =
+            tokenizer.tokenize(text).filter(|t| !t.is_separator()).map(|t| t.phrase).collect();
+        assert_eq!(words, [Some(0), Some(0), Some(1), Some(1), None, Some(2), Some(2)]);
+        assert!(tokenizer.tokenize(text).last().unwrap().is_prefix);
+        assert_eq!(tokenizer.tokenize(text).map(|t| t.byte_end).last(), Some(text.len()));
+
+        // the phrase mode is disabled by default.
+        assert!(TokenizerBuilder::default().build().tokenize(text).all(|t| t.phrase.is_none()));
+    }
+
+    #[test]
+    fn protected_terms() {
+        let terms = ["C++", "i18n", "Ωmega-3"];
+        let mut builder = TokenizerBuilder::default();
+        builder.protected_terms(&terms).create_char_map(true);
+        let tokenizer = builder.build();
+
+        let text = "I code in C++, not in c++: Ωmega-3 i18n's";
+        let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+        let lemmas: Vec<_> = tokens.iter().map(|t| t.lemma()).collect();
+        assert_eq!(
+            lemmas,
+            [
+                "i", " ", "code", " ", "in", " ", "C++", ", ", "not", " ", "in", " ", "c++", ":",
+                " ", "Ωmega-3", " ", "i18n", "'", "s"
+            ]
+        );
+
+        let omega = tokens.iter().find(|t| t.lemma() == "Ωmega-3").unwrap();
+        assert!(omega.is_word());
+        assert_eq!(omega.char_map, None);
+        assert_eq!(&text[omega.byte_start..omega.byte_end], "Ωmega-3");
+        assert!(tokens.windows(2).all(|w| w[0].byte_end == w[1].byte_start));
+        assert_eq!(tokens.last().unwrap().byte_end, text.len());
+
+        // the terms surrounded by alphanumeric characters are not protected.
+        let terms = ["in", "C++"];
+        let mut builder = TokenizerBuilder::default();
+        builder.protected_terms(&terms);
+        let tokenizer = builder.build();
+        let segments: Vec<_> = tokenizer.segment_str("main thing in C++x").collect();
+        assert_eq!(segments, ["main", " ", "thing", " ", "in", " ", "C++", "x"]);
+    }
+
+    #[cfg(feature = "protected-patterns")]
+    #[test]
+    fn protected_patterns() {
+        use crate::BuildError;
+
+        let terms = ["JIRA"];
+        let patterns = [r"[A-Z]+-\d+", r"v\d+\.\d+"];
+        let mut builder = TokenizerBuilder::default();
+        builder.protected_terms(&terms).protected_patterns(&patterns);
+        let tokenizer = builder.try_build().unwrap();
+
+        // the longest match is kept, and the matches surrounded by alphanumeric characters are rejected.
+        let segments: Vec<_> = tokenizer.segment_str("JIRA-12 in v1.2 or xv1.2 JIRA").collect();
+        assert_eq!(
+            segments,
+            ["JIRA-12", " ", "in", " ", "v1.2", " ", "or", " ", "xv1", ".", "2", " ", "JIRA"]
+        );
+        let token = tokenizer.tokenize("JIRA-12").next().unwrap();
+        assert_eq!(token.lemma(), "JIRA-12");
+        assert!(token.is_word());
+
+        let patterns = ["(unclosed"];
+        let mut builder = TokenizerBuilder::default();
+        builder.protected_patterns(&patterns);
+        assert_eq!(
+            builder.try_build().err(),
+            Some(BuildError::InvalidProtectedPattern("(unclosed".to_string()))
+        );
+    }
+
+    #[test]
+    fn split_mixed_scripts() {
+        let mut builder = TokenizerBuilder::default();
+        builder.whitespace_segmentation(true).split_mixed_scripts(true);
+        let tokenizer = builder.build();
+
+        let text = "abc漢字 ΑΒΓ15xyz";
+        let tokens: Vec<_> = tokenizer
+            .tokenize(text)
+            .map(|t| (t.lemma.into_owned(), t.script, t.byte_start, t.byte_end))
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                ("abc".to_string(), Script::Latin, 0, 3),
+                ("漢字".to_string(), Script::Cj, 3, 9),
+                (" ".to_string(), Script::Other, 9, 10),
+                ("αβγ15".to_string(), Script::Greek, 10, 18),
+                ("xyz".to_string(), Script::Latin, 18, 21),
+            ]
+        );
+    }
+
+    #[cfg(feature = "chinese")]
+    #[test]
+    fn query_prefix_cj() {
+        let mut builder = TokenizerBuilder::default();
+        builder.query_prefix(true).create_char_map(true).keep_original(true);
+        let tokenizer = builder.build();
+
+        let text = "我们一起去北京大";
+        let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+        let last = tokens.last().unwrap();
+        assert!(last.is_prefix);
+        assert_eq!(last.lemma(), "北京大");
+        assert_eq!(&text[last.byte_start..last.byte_end], "北京大");
+        assert_eq!(last.char_map.as_ref().map(|m| m.len()), Some(3));
+        assert_eq!(last.original_lemma(), Some("北京大"));
+        assert_eq!(tokens.iter().map(|t| t.lemma()).collect::<String>(), text);
+        assert!(tokens[..tokens.len() - 1].iter().all(|t| !t.is_prefix));
+    }
+
+    #[cfg(feature = "thai")]
+    #[test]
+    fn segmenter() {
+        use std::borrow::Cow;
+        use std::sync::Arc;
+
+        use fst::raw::Fst;
+
+        use crate::segmenter::{Segment, ThaiSegmenter};
+
+        let words = Set::from_iter(["ภาษา", "ไทย"]).unwrap();
+        let words_fst = Fst::new(Cow::Owned(words.as_fst().as_bytes().to_vec())).unwrap();
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenter(
+            Script::Thai,
+            Language::Tha,
+            Arc::new(ThaiSegmenter::new(Arc::new(words_fst))),
+        );
+        let tokenizer = builder.build();
+
+        let text = "ภาษาไทยง่าย";
+        let lemmas: Vec<_> = tokenizer.segment_str(text).collect();
+        assert_eq!(lemmas, ["ภาษา", "ไทย", "ง", "่", "า", "ย"]);
+
+        // other tokenizers keep using the default dictionary.
+        let lemmas: Vec<_> = text.segment_str().collect();
+        assert_eq!(lemmas, ["ภาษาไทย", "ง่าย"]);
+    }
+
+    #[cfg(feature = "thai")]
+    #[test]
+    fn dictionaries() {
+        use std::borrow::Cow;
+        use std::sync::Arc;
+
+        use fst::raw::Fst;
+
+        use crate::segmenter::ThaiSegmenter;
+
+        let thai_dictionary = |tokenizer: &crate::Tokenizer| {
+            let dictionaries = tokenizer.dictionaries();
+            dictionaries.into_iter().find(|(script, ..)| *script == Script::Thai).unwrap().2
+        };
+
+        let default = TokenizerBuilder::default().into_tokenizer();
+        let dictionary = thai_dictionary(&default);
+        assert_eq!(dictionary.name, "thai-words");
+        assert_eq!(dictionary.source, "https://github.com/PyThaiNLP/nlpo3");
+        assert!(dictionary.checksum.is_some());
+
+        let words = Set::from_iter(["ภาษา", "ไทย"]).unwrap();
+        let words_fst = Fst::new(Cow::Owned(words.as_fst().as_bytes().to_vec())).unwrap();
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenter(
+            Script::Thai,
+            Language::Tha,
+            Arc::new(ThaiSegmenter::new(Arc::new(words_fst))),
+        );
+        let custom = builder.into_tokenizer();
+        let custom_dictionary = thai_dictionary(&custom);
+        assert_eq!(custom_dictionary.source, "custom");
+        assert_ne!(custom_dictionary.checksum, dictionary.checksum);
+        assert_ne!(custom.fingerprint(), default.fingerprint());
+    }
+
+    #[test]
+    fn query_consistency() {
+        let tokenizer = TokenizerBuilder::default().into_tokenizer();
+
+        let tokens = tokenizer.query_consistency("\"new york\" piz");
+        assert_eq!(tokens.index.len(), tokens.query.len());
+        assert!(tokens.mismatches().is_empty());
+        assert_eq!(tokens.query[1].phrase, Some(0));
+
+        // the trailing CJK char is merged with the preceding word in a query.
+        #[cfg(feature = "chinese")]
+        {
+            let tokens = tokenizer.query_consistency("北京大");
+            let mismatches: Vec<_> = tokens
+                .mismatches()
+                .into_iter()
+                .map(|(index, query)| (index.map(|t| t.lemma()), query.map(|t| t.lemma())))
+                .collect();
+            assert_eq!(mismatches, [(Some("北京"), Some("北京大")), (Some("大"), None)]);
+        }
+    }
+
+    #[test]
+    fn segment_costs() {
+        use std::sync::Arc;
+
+        use crate::segmenter::{SegmentOrigin, Segmenter};
+
+        /// Segmenter of the words costing their number of bytes, the words not starting with "w" being guessed.
+        struct CostSegmenter;
+
+        impl Segmenter for CostSegmenter {
+            fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+                Box::new(self.segment_str_with_origin(s).map(|(segment, _)| segment))
+            }
+
+            fn segment_str_with_origin<'o>(
+                &self,
+                s: &'o str,
+            ) -> Box<dyn Iterator<Item = (&'o str, SegmentOrigin)> + 'o> {
+                Box::new(Some(s).into_iter().map(|word| {
+                    let origin = SegmentOrigin {
+                        in_dictionary: Some(word.starts_with('w')),
+                        cost: Some(word.len() as i32),
+                    };
+                    (word, origin)
+                }))
+            }
+        }
+
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenter(Script::Latin, Language::Other, Arc::new(CostSegmenter));
+        builder.segment_costs(true);
+        let tokenizer = builder.build();
+
+        let tokens: Vec<_> =
+            tokenizer.tokenize("hello world").map(|t| (t.cost, t.guessed)).collect();
+        assert_eq!(tokens, [(Some(5), false), (None, false), (Some(5), false)]);
+
+        // the guessed tokens are marked independently of the costs.
+        builder.segment_costs(false).mark_guessed(true);
+        let tokenizer = builder.build();
+        let tokens: Vec<_> =
+            tokenizer.tokenize("hello world").map(|t| (t.cost, t.guessed)).collect();
+        assert_eq!(tokens, [(None, true), (None, false), (None, false)]);
+    }
+
+    #[test]
+    fn limits() {
+        let text = "Café quick brown";
+
+        let mut builder = TokenizerBuilder::default();
+        // the limit is in the middle of "quick", which is dropped.
+        builder.max_input_bytes(7);
+        let tokenizer = builder.build();
+        let mut tokens = tokenizer.tokenize(text);
+        let lemmas: Vec<_> = tokens.by_ref().map(|t| t.lemma.into_owned()).collect();
+        assert_eq!(lemmas, ["cafe", " "]);
+        assert!(tokens.is_truncated());
+
+        // the limit is in the middle of "é", the first word being dropped.
+        let mut builder = TokenizerBuilder::default();
+        builder.max_input_bytes(4);
+        let tokenizer = builder.build();
+        let mut tokens = tokenizer.tokenize(text);
+        assert_eq!(tokens.next(), None);
+        assert!(tokens.is_truncated());
+
+        // the limit is between "ம" and its vowel sign.
+        let mut builder = TokenizerBuilder::default();
+        builder.max_input_bytes(19);
+        let tokenizer = builder.build();
+        let lemmas: Vec<_> = tokenizer.tokenize("நான் தமிழ்").map(|t| t.lemma.into_owned()).collect();
+        assert_eq!(lemmas, ["நான்", " "]);
+
+        let mut builder = TokenizerBuilder::default();
+        builder.max_input_bytes(text.len()).max_tokens(5);
+        let tokenizer = builder.build();
+        let mut tokens = tokenizer.tokenize(text);
+        assert_eq!(tokens.by_ref().count(), 5);
+        assert!(!tokens.is_truncated());
+
+        let mut builder = TokenizerBuilder::default();
+        builder.max_tokens(4);
+        let tokenizer = builder.build();
+        let mut tokens = tokenizer.tokenize(text);
+        let last = tokens.by_ref().last().unwrap();
+        assert_eq!(last.lemma(), " ");
+        assert!(tokens.is_truncated());
+    }
+
+    #[test]
+    fn fingerprint() {
+        let default = TokenizerBuilder::default().build().fingerprint();
+        assert_eq!(TokenizerBuilder::default().into_tokenizer().fingerprint(), default);
+
+        let mut builder = TokenizerBuilder::default();
+        builder.separators(&[" "]);
+        let separators = builder.build().fingerprint();
+        assert_ne!(separators, default);
+
+        // the order of the configuration doesn't change the fingerprint.
+        let mut builder = TokenizerBuilder::default();
+        builder.default_language(Script::Latin, Language::Fra);
+        builder.default_language(Script::Cj, Language::Jpn);
+        let languages = builder.build().fingerprint();
+        let mut builder = TokenizerBuilder::default();
+        builder.default_language(Script::Cj, Language::Jpn);
+        builder.default_language(Script::Latin, Language::Fra);
+        assert_eq!(builder.build().fingerprint(), languages);
+        assert_ne!(languages, default);
+
+        let stop_words = Set::from_iter(["the"]).unwrap();
+        let mut builder = TokenizerBuilder::new();
+        builder.stop_words(&stop_words);
+        assert_ne!(builder.build().fingerprint(), default);
+    }
+
+    #[test]
+    fn separator_automaton() {
+        use aho_corasick::{AhoCorasickKind, MatchKind};
+
+        use crate::segmenter::AhoOption;
+
+        let text = "The quick... brown fox";
+        let default: Vec<_> = text.tokenize().map(|t| t.lemma.into_owned()).collect();
+
+        // the kind of automaton and the prefilter don't change the segmentation.
+        let mut builder = TokenizerBuilder::default();
+        let option =
+            AhoOption { kind: Some(AhoCorasickKind::DFA), prefilter: false, ..Default::default() };
+        builder.separator_automaton(option);
+        let tokenizer = builder.build();
+        let tokens: Vec<_> = tokenizer.tokenize(text).map(|t| t.lemma.into_owned()).collect();
+        assert_eq!(tokens, default);
+        assert_eq!(tokenizer.fingerprint(), TokenizerBuilder::default().build().fingerprint());
+
+        // the match kind does.
+        let mut builder = TokenizerBuilder::default();
+        builder.separator_automaton(AhoOption {
+            match_kind: MatchKind::Standard,
+            ..Default::default()
+        });
+        let tokenizer = builder.build();
+        let tokens: Vec<_> = tokenizer.tokenize(text).map(|t| t.lemma.into_owned()).collect();
+        assert_ne!(tokens, default);
+        assert_ne!(tokenizer.fingerprint(), TokenizerBuilder::default().build().fingerprint());
+    }
+
+    #[test]
+    fn separator_set() {
+        use crate::separators::SeparatorSet;
+        use crate::SeparatorKind;
+
+        let separators = SeparatorSet::new(&[" ", ":"], &["::"]);
+        let words = ["a::b"];
+        let mut builder = TokenizerBuilder::default();
+        builder.separator_set(separators.clone()).words_dict(&words);
+        let tokenizer = builder.build();
+        let tokens: Vec<_> = tokenizer
+            .tokenize("a::b c::d")
+            .map(|t| (t.lemma().to_string(), t.separator_kind()))
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                ("a::b".to_string(), None),
+                (" ".to_string(), Some(SeparatorKind::Soft)),
+                ("c".to_string(), None),
+                ("::".to_string(), Some(SeparatorKind::Hard)),
+                ("d".to_string(), None),
+            ]
+        );
+
+        // the sets with different classifications have different fingerprints.
+        let mut builder = TokenizerBuilder::default();
+        builder.separator_set(separators);
+        let fingerprint = builder.build().fingerprint();
+        let mut builder = TokenizerBuilder::default();
+        builder.separator_set(SeparatorSet::new(&[" ", ":", "::"], &[]));
+        assert_ne!(builder.build().fingerprint(), fingerprint);
+    }
+
+    #[test]
+    fn add_remove_separators() {
+        use crate::SeparatorKind;
+
+        let text = "snake_case, kebab-case/x";
+        let classified = |builder: &mut TokenizerBuilder<'_, Vec<u8>>| -> Vec<_> {
+            builder
+                .build()
+                .tokenize(text)
+                .map(|t| (t.lemma().to_string(), t.separator_kind()))
+                .collect()
+        };
+
+        // the default separators keep their classification.
+        let mut builder = TokenizerBuilder::default();
+        builder.remove_separators(&["_"]).add_separators(&["/", "-"], SeparatorKind::Hard);
+        assert_eq!(
+            classified(&mut builder),
+            [
+                ("snake_case".to_string(), None),
+                (", ".to_string(), Some(SeparatorKind::Hard)),
+                ("kebab".to_string(), None),
+                ("-".to_string(), Some(SeparatorKind::Hard)),
+                ("case".to_string(), None),
+                ("/".to_string(), Some(SeparatorKind::Hard)),
+                ("x".to_string(), None),
+            ]
+        );
+
+        // the custom list of separators is edited instead of the default one.
+        let mut builder = TokenizerBuilder::default();
+        builder.separators(&[" ", "_", "/"]).remove_separators(&["/"]);
+        assert!(builder.try_build().is_ok());
+        assert_eq!(
+            classified(&mut builder),
+            [
+                ("snake".to_string(), None),
+                ("_".to_string(), Some(SeparatorKind::Soft)),
+                ("case,".to_string(), None),
+                (" ".to_string(), Some(SeparatorKind::Soft)),
+                ("kebab-case/x".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn blank_input() {
+        use crate::BlankInput;
+
+        for blank_input in [BlankInput::Keep, BlankInput::Skip, BlankInput::SingleSeparator] {
+            let mut builder = TokenizerBuilder::default();
+            builder.blank_input(blank_input).create_char_map(true).query_prefix(true);
+            let tokenizer = builder.build();
+            assert_eq!(tokenizer.tokenize("").count(), 0);
+
+            // the leading separators are kept in front of the first word.
+            let text = " \u{3000}- Cat";
+            let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+            assert_eq!(tokens.len(), 5);
+            assert!(tokens.last().unwrap().is_prefix);
+
+            let text = "\t\u{3000}, ";
+            let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+            match blank_input {
+                BlankInput::Keep => assert_eq!(tokens.len(), 3),
+                BlankInput::Skip => assert!(tokens.is_empty()),
+                BlankInput::SingleSeparator => {
+                    assert_eq!(tokens.len(), 1);
+                    assert_eq!(tokens[0].lemma(), "\t , ");
+                    assert!(tokens[0].is_separator());
+                    assert_eq!(tokens[0].validate(text), Ok(()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn try_build() {
+        use std::collections::HashMap;
+
+        use crate::separators::SeparatorSet;
+        use crate::BuildError;
+
+        let mut builder = TokenizerBuilder::default();
+        builder.default_language(Script::Latin, Language::Fra);
+        assert!(builder.try_build().is_ok());
+
+        builder.whitespace_segmentation(true);
+        assert_eq!(
+            builder.try_build().err(),
+            Some(BuildError::IgnoredByWhitespaceSegmentation("default_language"))
+        );
+
+        let mut builder = TokenizerBuilder::default();
+        builder.default_language(Script::Latin, Language::Rus);
+        assert_eq!(
+            builder.try_build().err(),
+            Some(BuildError::LanguageNotInScript {
+                script: Script::Latin,
+                language: Language::Rus
+            })
+        );
+
+        let allow_list = HashMap::from([(Script::Cyrillic, vec![])]);
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list);
+        assert_eq!(builder.try_build().err(), Some(BuildError::EmptyAllowList(Script::Cyrillic)));
+
+        #[cfg(not(feature = "icu"))]
+        {
+            let allow_list = HashMap::from([(Script::Myanmar, vec![Language::Mya])]);
+            let mut builder = TokenizerBuilder::default();
+            builder.allow_list(&allow_list);
+            assert_eq!(
+                builder.try_build().err(),
+                Some(BuildError::DisabledFeature { language: Language::Mya, feature: "icu" })
+            );
+        }
+
+        // the segmenter of a dictionary loaded at runtime is only needed when its language is selected.
+        #[cfg(feature = "sinhala")]
+        {
+            assert!(TokenizerBuilder::default().try_build().is_ok());
+
+            let mut builder = TokenizerBuilder::default();
+            builder.default_language(Script::Sinhala, Language::Sin);
+            assert_eq!(
+                builder.try_build().err(),
+                Some(BuildError::MissingDictionary {
+                    script: Script::Sinhala,
+                    language: Language::Sin
+                })
+            );
+        }
+
+        // the separator set takes precedence over the list of separators in `build`.
+        let mut builder = TokenizerBuilder::default();
+        builder.separator_set(SeparatorSet::new(&[" "], &[])).separators(&[" "]);
+        assert_eq!(builder.try_build().err(), Some(BuildError::ConflictingSeparators));
+        assert!(builder.build().tokenize("a b").count() == 3);
+    }
+
     #[quickcheck]
     fn shorten_after_tokenized(text: String) -> bool {
         let text = text.as_str();
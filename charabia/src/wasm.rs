@@ -0,0 +1,107 @@
+//! JavaScript bindings of charabia built with `wasm-bindgen`,
+//! allowing browsers to highlight the texts with exactly the same tokenization as the backend.
+//!
+//! ```js
+//! import { tokenize } from "charabia";
+//!
+//! for (const token of tokenize("Thé quick fox")) {
+//!     console.log(token.kind, token.lemma, text.slice(token.start, token.end));
+//! }
+//! ```
+
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use crate::{SeparatorKind, Token, TokenKind, Tokenize};
+
+/// Converts the byte offsets of the tokens into the UTF-16 offsets used by JavaScript strings.
+///
+/// The byte offsets must be increasing, like the ones of the tokens produced by the tokenizer.
+struct Utf16Offsets<'o> {
+    text: &'o str,
+    /// last converted byte offset and its UTF-16 counterpart.
+    byte: usize,
+    utf16: usize,
+}
+
+impl<'o> Utf16Offsets<'o> {
+    fn new(text: &'o str) -> Self {
+        Self { text, byte: 0, utf16: 0 }
+    }
+
+    fn convert(&mut self, byte: usize) -> usize {
+        if byte < self.byte {
+            // unexpected backward offset, restart from the beginning of the text.
+            self.byte = 0;
+            self.utf16 = 0;
+        }
+        self.utf16 += self.text[self.byte..byte].encode_utf16().count();
+        self.byte = byte;
+        self.utf16
+    }
+}
+
+fn kind_name(kind: TokenKind) -> &'static str {
+    match kind {
+        TokenKind::Word => "word",
+        TokenKind::StopWord => "stopWord",
+        TokenKind::Separator(SeparatorKind::Soft) => "softSeparator",
+        TokenKind::Separator(SeparatorKind::Hard) => "hardSeparator",
+        TokenKind::DateTime => "dateTime",
+        TokenKind::Currency => "currency",
+        TokenKind::Unit => "unit",
+        TokenKind::Emoji => "emoji",
+        TokenKind::Hashtag => "hashtag",
+        TokenKind::Mention => "mention",
+        TokenKind::Unknown => "unknown",
+    }
+}
+
+fn token_object(token: &Token, offsets: &mut Utf16Offsets) -> Result<JsValue, JsValue> {
+    let object = Object::new();
+    let start = offsets.convert(token.byte_start);
+    let end = offsets.convert(token.byte_end);
+    Reflect::set(&object, &"kind".into(), &kind_name(token.kind).into())?;
+    Reflect::set(&object, &"lemma".into(), &token.lemma().into())?;
+    Reflect::set(&object, &"start".into(), &(start as u32).into())?;
+    Reflect::set(&object, &"end".into(), &(end as u32).into())?;
+    Reflect::set(&object, &"script".into(), &token.script.name().into())?;
+    let language = token.language.map_or(JsValue::NULL, |language| language.name().into());
+    Reflect::set(&object, &"language".into(), &language)?;
+    Ok(object.into())
+}
+
+/// Tokenizes the text with the default tokenizer, returning an array of token objects.
+///
+/// The `start` and `end` offsets of the tokens are UTF-16 offsets,
+/// so they can directly be used to slice the JavaScript string.
+#[wasm_bindgen]
+pub fn tokenize(text: &str) -> Result<JsValue, JsValue> {
+    let mut offsets = Utf16Offsets::new(text);
+    let tokens = Array::new();
+    for token in text.tokenize() {
+        tokens.push(&token_object(&token, &mut offsets)?);
+    }
+    Ok(tokens.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::Utf16Offsets;
+    use crate::Tokenize;
+
+    #[test]
+    fn utf16_offsets() {
+        let text = "Thé 😀 fox";
+        let mut offsets = Utf16Offsets::new(text);
+        let converted: Vec<_> = text
+            .tokenize()
+            .map(|t| (offsets.convert(t.byte_start), offsets.convert(t.byte_end)))
+            .collect();
+        // the emoji is made of 2 UTF-16 code units.
+        assert_eq!(converted, [(0, 3), (3, 4), (4, 6), (6, 7), (7, 10)]);
+
+        // a backward offset is still converted.
+        assert_eq!(offsets.convert(4), 3);
+    }
+}